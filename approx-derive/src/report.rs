@@ -0,0 +1,156 @@
+use crate::args_parsing::*;
+use crate::base_types::*;
+use crate::AbsDiffEqParser;
+
+impl AbsDiffEqParser {
+    /// Generates the `*_report` and `*_report_with` methods of the given trait.
+    ///
+    /// The report of every compared field is nested under the name of the field, or its position
+    /// for tuple fields. Fields which are compared with `==`, mapped or iterated over are reported
+    /// from the outcome of `eq_field`, without further details. Two different variants of an enum
+    /// are reported as a single variant mismatch.
+    pub fn generate_report_methods(
+        &self,
+        trait_name: &str,
+        method: &str,
+        params: proc_macro2::TokenStream,
+        args: proc_macro2::TokenStream,
+        field_args: impl Fn(&FieldFormatted) -> proc_macro2::TokenStream,
+        eq_field: impl Fn(syn::Ident, syn::Ident, &FieldWithArgs) -> Option<proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        let trait_name = quote::format_ident!("{trait_name}");
+        let method_with = quote::format_ident!("{method}_with");
+        let method = quote::format_ident!("{method}");
+        let report_field = |field: &FieldFormatted| {
+            let FieldFormatted {
+                base_type,
+                own_field,
+                other_field,
+                ..
+            } = field;
+            let field_args = field_args(field);
+            quote::quote!(
+                <#base_type as #ApproxName::#trait_name>::#method_with(
+                    #own_field,
+                    #other_field,
+                    #field_args,
+                    policy
+                )
+            )
+        };
+
+        let (arms, other_variants) = match &self.base_type {
+            BaseType::Struct {
+                fields_with_args, ..
+            } => (
+                vec![self.get_report_arm(
+                    quote::quote!(Self),
+                    fields_with_args,
+                    report_field,
+                    &eq_field,
+                )],
+                quote::quote!(),
+            ),
+            BaseType::Enum {
+                variants_with_args, ..
+            } => (
+                variants_with_args
+                    .iter()
+                    .map(|variant_with_args| {
+                        let variant = &variant_with_args.ident;
+                        self.get_report_arm(
+                            quote::quote!(Self::#variant),
+                            &variant_with_args.fields_with_args,
+                            report_field,
+                            &eq_field,
+                        )
+                    })
+                    .collect(),
+                quote::quote!(
+                    #[allow(unreachable_patterns)]
+                    _ => report.push(
+                        #ApproxName::Mismatch::new(#ApproxName::MismatchKind::Variant)
+                    ),
+                ),
+            ),
+        };
+
+        quote::quote!(
+            #ApproxName::__derive_report! {
+                fn #method(&self, other: &Self, #params) -> #ApproxName::Report {
+                    let policy = #ApproxName::Policy::default();
+                    #ApproxName::#trait_name::#method_with(self, other, #args, policy)
+                }
+
+                fn #method_with(
+                    &self,
+                    other: &Self,
+                    #params,
+                    policy: #ApproxName::Policy,
+                ) -> #ApproxName::Report {
+                    let mut report = #ApproxName::Report::new();
+                    match (self, other) {
+                        #(#arms)*
+                        #other_variants
+                    }
+                    report
+                }
+            }
+        )
+    }
+
+    fn get_report_arm(
+        &self,
+        path: proc_macro2::TokenStream,
+        fields_with_args: &[FieldWithArgs],
+        report_field: impl Fn(&FieldFormatted) -> proc_macro2::TokenStream,
+        eq_field: impl Fn(syn::Ident, syn::Ident, &FieldWithArgs) -> Option<proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        use syn::ext::IdentExt;
+
+        let mut members = vec![];
+        let mut field_names1 = vec![];
+        let mut field_names2 = vec![];
+        let mut nested = vec![];
+        for (n, field_with_args) in fields_with_args.iter().enumerate() {
+            let xi = quote::format_ident!("x{n}");
+            let yi = quote::format_ident!("y{n}");
+            let Some(field) =
+                self.format_nth_field(0, field_with_args, Some((xi.clone(), yi.clone())))
+            else {
+                continue;
+            };
+            let segment = match &field_with_args.ident {
+                Some(id) => {
+                    members.push(quote::quote!(#id));
+                    let name = id.unraw().to_string();
+                    quote::quote!(#ApproxName::PathSegment::Field(#name))
+                }
+                None => {
+                    let index = syn::Index::from(n);
+                    members.push(quote::quote!(#index));
+                    let position = proc_macro2::Literal::usize_unsuffixed(n);
+                    quote::quote!(#ApproxName::PathSegment::Tuple(#position))
+                }
+            };
+            let report = if field.set_equal || field.mapping.is_some() || field.use_iterator {
+                let eq = eq_field(xi.clone(), yi.clone(), field_with_args);
+                quote::quote!(#ApproxName::Report::from_eq(#eq))
+            } else {
+                report_field(&field)
+            };
+            nested.push(quote::quote!(report.nest(#segment, #report);));
+            field_names1.push(xi);
+            field_names2.push(yi);
+        }
+
+        quote::quote!(
+            (
+                #path { #(#members: #field_names1,)* .. },
+                #path { #(#members: #field_names2,)* .. }
+            ) => {
+                #(#nested)*
+            }
+        )
+    }
+}
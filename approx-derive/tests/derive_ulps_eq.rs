@@ -0,0 +1,338 @@
+//! Tests for the `UlpsEq` derive macro
+#[macro_use]
+extern crate approxim;
+
+use approxim::{AbsDiffEq, RelativeEq, UlpsEq};
+
+mod test_f32 {
+    use super::*;
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    struct Value {
+        x: f32,
+    }
+
+    fn v(x: f32) -> Value {
+        Value { x }
+    }
+
+    #[test]
+    fn test_basic() {
+        assert_ulps_eq!(v(1.0), v(1.0));
+        assert_ulps_ne!(v(1.0), v(2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_ulps_eq!(v(1.0), v(2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_ulps_ne!(v(1.0), v(1.0));
+    }
+
+    #[test]
+    fn test_big() {
+        assert_ulps_eq!(v(100000000.0), v(100000001.0));
+        assert_ulps_ne!(v(10000.0), v(10001.0));
+    }
+
+    #[test]
+    fn test_mid() {
+        assert_ulps_eq!(v(1.0000001), v(1.0000002));
+        assert_ulps_ne!(v(1.000001), v(1.000002));
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_ulps_eq!(v(0.0), v(-0.0));
+        assert_ulps_ne!(v(0.000001), v(0.0));
+    }
+
+    #[test]
+    fn test_epsilon() {
+        assert_ulps_eq!(v(0.0), v(1e-40), epsilon = 1e-40f32);
+        assert_ulps_ne!(v(1e-40), v(0.0), epsilon = 1e-41f32);
+    }
+
+    #[test]
+    fn test_max_ulps() {
+        let a = v(1.0);
+        let b = v(f32::from_bits(1.0f32.to_bits() + 6));
+        assert_ulps_ne!(a, b);
+        assert_ulps_eq!(a, b, max_ulps = 6);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_ulps_ne!(v(f32::NAN), v(f32::NAN));
+        assert_ulps_ne!(v(f32::NAN), v(0.0));
+    }
+
+    #[test]
+    fn test_opposite_signs() {
+        assert_ulps_ne!(v(1.000000001), v(-1.0));
+        assert_ulps_eq!(v(10.0 * f32::MIN_POSITIVE), v(10.0 * -f32::MIN_POSITIVE));
+    }
+}
+
+mod test_f64 {
+    use super::*;
+
+    #[derive(RelativeEq, UlpsEq, PartialEq, Debug)]
+    struct Point(f64, f64);
+
+    #[test]
+    fn test_basic() {
+        assert_ulps_eq!(Point(1.0, 2.0), Point(1.0, 2.0));
+        assert_ulps_ne!(Point(1.0, 2.0), Point(1.0, 3.0));
+        assert_ulps_eq!(Point(1.0, 2.0), Point(1. + 1e-16, 2.0));
+    }
+
+    #[test]
+    fn test_big() {
+        assert_ulps_eq!(
+            Point(10000000000000000.0, 0.0),
+            Point(10000000000000001.0, 0.0)
+        );
+        assert_ulps_ne!(Point(10000.0, 0.0), Point(10001.0, 0.0));
+    }
+
+    #[test]
+    fn test_default_max_ulps() {
+        assert_eq!(Point::default_max_ulps(), <f64 as UlpsEq>::default_max_ulps());
+    }
+}
+
+mod test_generics {
+    use super::*;
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    struct Position<F> {
+        x: F,
+        y: F,
+    }
+
+    #[test]
+    fn test_basic() {
+        let p1 = Position { x: 1.0f32, y: 2.0 };
+        let p2 = Position {
+            x: 1.0f32,
+            y: 2.0000002,
+        };
+        assert_ulps_eq!(p1, p2);
+        assert_ulps_ne!(p1, p2, max_ulps = 0);
+    }
+}
+
+mod test_skip {
+    use super::*;
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    struct Player {
+        #[approx(skip)]
+        id: usize,
+        hit_points: f32,
+    }
+
+    #[test]
+    fn test_skip() {
+        let p1 = Player {
+            id: 1,
+            hit_points: 100.0,
+        };
+        let p2 = Player {
+            id: 2,
+            hit_points: 100.00001,
+        };
+        assert_ulps_eq!(p1, p2);
+    }
+}
+
+mod test_equal {
+    use super::*;
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    #[approx(epsilon_type = f64)]
+    struct Prediction {
+        #[approx(equal)]
+        category: &'static str,
+        confidence: f64,
+    }
+
+    #[test]
+    fn test_equal() {
+        let p1 = Prediction {
+            category: "horses",
+            confidence: 0.5,
+        };
+        let p2 = Prediction {
+            category: "horses",
+            confidence: 0.5 + f64::EPSILON / 4.0,
+        };
+        let p3 = Prediction {
+            category: "cows",
+            confidence: 0.5,
+        };
+        assert_ulps_eq!(p1, p2);
+        assert_ulps_ne!(p1, p3);
+    }
+}
+
+mod test_cast {
+    use super::*;
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    struct Sample {
+        value: f32,
+        #[approx(cast_field)]
+        weight: f64,
+    }
+
+    #[test]
+    fn test_cast_field() {
+        let s1 = Sample {
+            value: 1.0,
+            weight: 3.0,
+        };
+        let s2 = Sample {
+            value: 1.0,
+            weight: 3.0 + 1e-12,
+        };
+        assert_ulps_eq!(s1, s2, max_ulps = 0);
+    }
+}
+
+mod test_attributes {
+    use super::*;
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    #[approx(default_max_ulps = 10)]
+    struct Loose {
+        x: f64,
+        #[approx(static_max_ulps = 0)]
+        y: f64,
+    }
+
+    #[test]
+    fn test_default_max_ulps() {
+        assert_eq!(Loose::default_max_ulps(), 10);
+
+        let a = Loose { x: 1.0, y: 1.0 };
+        let b = Loose {
+            x: f64::from_bits(1.0f64.to_bits() + 10),
+            y: 1.0,
+        };
+        assert_ulps_eq!(a, b);
+        assert_ulps_ne!(a, b, max_ulps = 9);
+    }
+
+    #[test]
+    fn test_static_max_ulps() {
+        let a = Loose { x: 1.0, y: 1.0 };
+        let b = Loose {
+            x: 1.0,
+            y: f64::from_bits(1.0f64.to_bits() + 1),
+        };
+        assert_ulps_ne!(a, b, epsilon = 0.0, max_ulps = 100);
+    }
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    struct Mapped {
+        #[approx(map = |x: &f32| Some(x.abs()))]
+        x: f32,
+    }
+
+    #[test]
+    fn test_map() {
+        assert_ulps_eq!(Mapped { x: 1.0 }, Mapped { x: -1.0 });
+    }
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    #[approx(epsilon_type = f64)]
+    struct Polynomial {
+        #[approx(into_iter)]
+        coefficients: [f64; 3],
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let p1 = Polynomial {
+            coefficients: [1.0, 0.5, 0.25],
+        };
+        let p2 = Polynomial {
+            coefficients: [1.0, 0.5, 0.25 + f64::EPSILON / 8.0],
+        };
+        let p3 = Polynomial {
+            coefficients: [1.0, 0.5, 0.3],
+        };
+        assert_ulps_eq!(p1, p2);
+        assert_ulps_ne!(p1, p3);
+    }
+}
+
+mod test_enum {
+    use super::*;
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    enum Shape {
+        Circle { radius: f64 },
+        Rectangle(f64, f64),
+        Empty,
+    }
+
+    #[test]
+    fn test_variants() {
+        assert_ulps_eq!(
+            Shape::Circle { radius: 1.0 },
+            Shape::Circle {
+                radius: 1.0 + f64::EPSILON
+            }
+        );
+        assert_ulps_eq!(Shape::Rectangle(1.0, 2.0), Shape::Rectangle(1.0, 2.0));
+        assert_ulps_ne!(Shape::Rectangle(1.0, 2.0), Shape::Rectangle(1.0, 2.5));
+        assert_ulps_eq!(Shape::Empty, Shape::Empty);
+        assert_ulps_ne!(Shape::Empty, Shape::Circle { radius: 0.0 });
+    }
+}
+
+mod test_policy {
+    use super::*;
+    use approxim::{Policy, UlpsMode};
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    struct Value {
+        x: f32,
+    }
+
+    #[derive(AbsDiffEq, UlpsEq, PartialEq, Debug)]
+    enum Shape {
+        Circle { radius: f32 },
+        Rectangle(f32, f32),
+    }
+
+    #[test]
+    fn test_ulps_mode() {
+        let (a, b) = (Value { x: 1e-45 }, Value { x: -1e-45 });
+        let policy = Policy::new().ulps_mode(UlpsMode::Monotonic);
+        assert!(a.ulps_eq_with(&b, 0.0, 2, policy));
+        assert!(a.ulps_ne(&b, 0.0, 2));
+        assert_ulps_eq!(a, b, epsilon = 0.0, ulps_mode = UlpsMode::Monotonic);
+    }
+
+    #[test]
+    fn test_enum() {
+        let (a, b) = (Shape::Rectangle(1.0, 1e-45), Shape::Rectangle(1.0, -1e-45));
+        assert_ulps_eq!(a, b, epsilon = 0.0, ulps_mode = UlpsMode::Monotonic);
+        assert_ulps_ne!(a, b, epsilon = 0.0);
+        let (c, d) = (
+            Shape::Circle { radius: 0.0 },
+            Shape::Circle { radius: -0.0 },
+        );
+        assert_ulps_ne!(c, d, distinct_zeros = true);
+        assert_ulps_eq!(c, d);
+    }
+}
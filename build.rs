@@ -0,0 +1,56 @@
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+// The `nightly_float` feature implements the traits for the unstable `f16` and `f128` types.
+// Only nightly compilers accept them, so the feature is probed and enabled through the
+// `approxim_nightly_float` cfg. On other compilers the feature has no effect, which keeps
+// `--all-features` building on every toolchain.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(approxim_nightly_float)");
+
+    if env::var_os("CARGO_FEATURE_NIGHTLY_FLOAT").is_some() && probe_nightly_float() {
+        println!("cargo:rustc-cfg=approxim_nightly_float");
+    }
+}
+
+/// Whether the compiler accepts the unstable `f16` and `f128` types.
+fn probe_nightly_float() -> bool {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo"));
+    let probe = out_dir.join("probe_nightly_float.rs");
+    let source =
+        "#![feature(f16, f128)]\npub fn probe(x: f16, y: f128) -> (f16, f128) { (x, y) }\n";
+    if fs::write(&probe, source).is_err() {
+        return false;
+    }
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| OsString::from("rustc"));
+    let mut command = match env::var_os("RUSTC_WRAPPER").filter(|wrapper| !wrapper.is_empty()) {
+        Some(wrapper) => {
+            let mut command = Command::new(wrapper);
+            command.arg(rustc);
+            command
+        }
+        None => Command::new(rustc),
+    };
+    command
+        .args([
+            "--edition=2021",
+            "--crate-name=probe_nightly_float",
+            "--crate-type=lib",
+        ])
+        .arg("--emit=metadata")
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg(&probe)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    if let Some(target) = env::var_os("TARGET") {
+        command.arg("--target").arg(target);
+    }
+
+    command.status().is_ok_and(|status| status.success())
+}
@@ -1,8 +1,44 @@
+#[cfg(feature = "alloc")]
+use crate::distance::AsF64;
+#[cfg(all(
+    feature = "alloc",
+    any(
+        feature = "tuple_impl",
+        feature = "num-complex",
+        feature = "indexmap_impl",
+        feature = "btree_map_impl",
+        feature = "hash_map_impl"
+    )
+))]
+use crate::report::PathSegment;
+#[cfg(feature = "alloc")]
+use crate::report::{Applied, Report};
+#[cfg(feature = "std")]
+use crate::sync::with_locked;
+use crate::{Mixed, Policy};
+#[cfg(feature = "btree_map_impl")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "linked_list_impl")]
+use alloc::collections::LinkedList;
+#[cfg(feature = "vec_deque_impl")]
+use alloc::collections::VecDeque;
 #[cfg(feature = "vec_impl")]
 use alloc::vec::Vec;
-use core::cell;
-#[cfg(feature = "indexmap_impl")]
+use core::cell::{self, OnceCell};
+#[cfg(any(
+    feature = "btree_map_impl",
+    feature = "hash_map_impl",
+    feature = "indexmap_impl"
+))]
+use core::fmt::Debug;
+#[cfg(any(feature = "indexmap_impl", feature = "hash_map_impl"))]
 use core::hash::{BuildHasher, Hash};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+#[cfg(feature = "half")]
+use half::{bf16, f16 as half_f16};
 #[cfg(feature = "indexmap_impl")]
 use indexmap::IndexMap;
 #[cfg(feature = "num-complex")]
@@ -11,6 +47,10 @@ use num_complex::Complex;
 use num_traits::Float;
 #[cfg(feature = "ordered-float")]
 use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "hash_map_impl")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock, RwLock};
 
 /// Equality that is defined using the absolute difference of two numbers.
 ///
@@ -20,6 +60,8 @@ use ordered_float::{NotNan, OrderedFloat};
 /// `abs_diff_eq`, `abs_diff_ne`, `assert_abs_diff_eq`, and `assert_abs_diff_ne` macros
 /// are all wrappers of the `abs_diff_eq` function in this trait.
 ///
+/// `f32` and `f64` can be compared with each other by wrapping the left-hand side in [`Mixed`].
+///
 /// # Examples
 ///
 /// ```
@@ -27,9 +69,10 @@ use ordered_float::{NotNan, OrderedFloat};
 /// # fn main() {
 /// assert_abs_diff_eq!(1.0f32, 1.00000001f32, epsilon = 1e-8);
 /// assert_abs_diff_ne!(1.0f32, 1.0000001f32, epsilon = 1e-8);
+/// assert_abs_diff_eq!(approxim::Mixed(0.1f32), 0.1f64);
 /// # }
 /// ```
-pub trait AbsDiffEq<Rhs = Self>: PartialEq<Rhs>
+pub trait AbsDiffEq<Rhs = Self>
 where
     Rhs: ?Sized,
 {
@@ -51,6 +94,47 @@ where
     fn abs_diff_ne(&self, other: &Rhs, epsilon: Self::Epsilon) -> bool {
         !Self::abs_diff_eq(self, other, epsilon)
     }
+
+    /// Performs the same comparison as [`AbsDiffEq::abs_diff_eq`], but returns a [`Report`]
+    /// listing every position at which the values differ.
+    ///
+    /// The default implementation reports a single mismatch without any details, so
+    /// implementations for containers should override it to report their elements.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn abs_diff_report(&self, other: &Rhs, epsilon: Self::Epsilon) -> Report {
+        Report::from_eq(Self::abs_diff_eq(self, other, epsilon))
+    }
+
+    /// Performs the same comparison as [`AbsDiffEq::abs_diff_eq`], with special values compared
+    /// according to the given [`Policy`].
+    ///
+    /// The default implementation ignores the policy, so implementations for floating point
+    /// numbers and containers should override it.
+    fn abs_diff_eq_with(&self, other: &Rhs, epsilon: Self::Epsilon, policy: Policy) -> bool {
+        let _ = policy;
+        Self::abs_diff_eq(self, other, epsilon)
+    }
+
+    /// The inverse of [`AbsDiffEq::abs_diff_eq_with`].
+    ///
+    /// With the default policy, the default implementation calls [`AbsDiffEq::abs_diff_ne`], so
+    /// that implementations overriding it are respected.
+    fn abs_diff_ne_with(&self, other: &Rhs, epsilon: Self::Epsilon, policy: Policy) -> bool {
+        if policy == Policy::default() {
+            Self::abs_diff_ne(self, other, epsilon)
+        } else {
+            !Self::abs_diff_eq_with(self, other, epsilon, policy)
+        }
+    }
+
+    /// Performs the same comparison as [`AbsDiffEq::abs_diff_eq_with`], but returns a [`Report`]
+    /// like [`AbsDiffEq::abs_diff_report`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn abs_diff_report_with(&self, other: &Rhs, epsilon: Self::Epsilon, policy: Policy) -> Report {
+        Report::from_eq(Self::abs_diff_eq_with(self, other, epsilon, policy))
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -69,11 +153,16 @@ macro_rules! impl_unsigned_abs_diff_eq {
 
             #[inline]
             fn abs_diff_eq(&self, other: &$T, epsilon: $T) -> bool {
-                (if self > other {
-                    self - other
-                } else {
-                    other - self
-                }) <= epsilon
+                $T::abs_diff(*self, *other) <= epsilon
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$T, epsilon: $T) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon as f64),
+                    ..Applied::default()
+                };
+                Report::leaf($T::abs_diff_eq(self, other, epsilon), self, other, applied)
             }
         }
     };
@@ -97,10 +186,18 @@ macro_rules! impl_signed_abs_diff_eq {
             }
 
             #[inline]
-            #[allow(unused_imports)]
             fn abs_diff_eq(&self, other: &$T, epsilon: $T) -> bool {
-                use num_traits::float::FloatCore;
-                $T::abs(self - other) <= epsilon
+                // The difference itself may not fit into the signed type, eg. for `MIN` and `MAX`.
+                epsilon >= 0 && $T::abs_diff(*self, *other) <= epsilon.unsigned_abs()
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$T, epsilon: $T) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon as f64),
+                    ..Applied::default()
+                };
+                Report::leaf($T::abs_diff_eq(self, other, epsilon), self, other, applied)
             }
         }
     };
@@ -110,9 +207,284 @@ impl_signed_abs_diff_eq!(i8, 0);
 impl_signed_abs_diff_eq!(i16, 0);
 impl_signed_abs_diff_eq!(i32, 0);
 impl_signed_abs_diff_eq!(i64, 0);
+impl_signed_abs_diff_eq!(i128, 0);
 impl_signed_abs_diff_eq!(isize, 0);
-impl_signed_abs_diff_eq!(f32, core::f32::EPSILON);
-impl_signed_abs_diff_eq!(f64, core::f64::EPSILON);
+
+macro_rules! impl_float_abs_diff_eq {
+    ($T:ident, $default_epsilon:expr) => {
+        impl AbsDiffEq for $T {
+            type Epsilon = $T;
+
+            #[inline]
+            fn default_epsilon() -> $T {
+                $default_epsilon
+            }
+
+            #[inline]
+            #[allow(unused_imports)]
+            fn abs_diff_eq(&self, other: &$T, epsilon: $T) -> bool {
+                use num_traits::float::FloatCore;
+                $T::abs(self - other) <= epsilon
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(&self, other: &$T, epsilon: $T, policy: Policy) -> bool {
+                let both_nan = self.is_nan() && other.is_nan();
+                let same_bits = self.to_bits() == other.to_bits();
+                policy.nan.accepts(both_nan, same_bits) || $T::abs_diff_eq(self, other, epsilon)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$T, epsilon: $T) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report_with(&self, other: &$T, epsilon: $T, policy: Policy) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon.as_f64()),
+                    ..Applied::default()
+                };
+                Report::leaf(
+                    $T::abs_diff_eq_with(self, other, epsilon, policy),
+                    self,
+                    other,
+                    applied,
+                )
+            }
+        }
+    };
+}
+
+impl_float_abs_diff_eq!(f32, scoped_default!(f32, epsilon, f32::EPSILON));
+impl_float_abs_diff_eq!(f64, scoped_default!(f64, epsilon, f64::EPSILON));
+#[cfg(approxim_nightly_float)]
+impl_float_abs_diff_eq!(f16, f16::EPSILON);
+#[cfg(approxim_nightly_float)]
+impl_float_abs_diff_eq!(f128, f128::EPSILON);
+
+// Half-precision arithmetic rounds every result back to 16 bits, so the difference is computed
+// in single precision instead.
+#[cfg(feature = "half")]
+macro_rules! impl_half_abs_diff_eq {
+    ($T:ident) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+        impl AbsDiffEq for $T {
+            type Epsilon = $T;
+
+            #[inline]
+            fn default_epsilon() -> $T {
+                $T::EPSILON
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$T, epsilon: $T) -> bool {
+                use num_traits::float::FloatCore;
+                FloatCore::abs(self.to_f32() - other.to_f32()) <= epsilon.to_f32()
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(&self, other: &$T, epsilon: $T, policy: Policy) -> bool {
+                let both_nan = self.is_nan() && other.is_nan();
+                let same_bits = self.to_bits() == other.to_bits();
+                policy.nan.accepts(both_nan, same_bits) || $T::abs_diff_eq(self, other, epsilon)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$T, epsilon: $T) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report_with(&self, other: &$T, epsilon: $T, policy: Policy) -> Report {
+                let applied = Applied {
+                    epsilon: Some(f64::from(epsilon)),
+                    ..Applied::default()
+                };
+                Report::leaf(
+                    $T::abs_diff_eq_with(self, other, epsilon, policy),
+                    self,
+                    other,
+                    applied,
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "half")]
+impl_half_abs_diff_eq!(half_f16);
+#[cfg(feature = "half")]
+impl_half_abs_diff_eq!(bf16);
+
+// Mixed-precision comparisons compute the difference of the operands widened to `f64`, and default
+// to the tolerances of `f32`.
+macro_rules! impl_mixed_abs_diff_eq {
+    ($T:ident, $U:ident) => {
+        impl AbsDiffEq<$U> for Mixed<$T> {
+            type Epsilon = f64;
+
+            #[inline]
+            fn default_epsilon() -> f64 {
+                f64::from(<f32 as AbsDiffEq>::default_epsilon())
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$U, epsilon: f64) -> bool {
+                Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(&self, other: &$U, epsilon: f64, policy: Policy) -> bool {
+                f64::abs_diff_eq_with(&f64::from(self.0), &f64::from(*other), epsilon, policy)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$U, epsilon: f64) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report_with(&self, other: &$U, epsilon: f64, policy: Policy) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon),
+                    ..Applied::default()
+                };
+                Report::leaf(
+                    AbsDiffEq::abs_diff_eq_with(self, other, epsilon, policy),
+                    self,
+                    other,
+                    applied,
+                )
+            }
+        }
+    };
+}
+
+impl_mixed_abs_diff_eq!(f32, f64);
+impl_mixed_abs_diff_eq!(f64, f32);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Integer wrappers
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_wrapping_abs_diff_eq {
+    ($T:ident, $U:ident) => {
+        /// The difference is measured along the shorter way around the wrapping boundary, so
+        /// that `Wrapping(MAX)` and `Wrapping(MIN)` are one apart.
+        impl AbsDiffEq for Wrapping<$T> {
+            type Epsilon = $T;
+
+            #[inline]
+            fn default_epsilon() -> $T {
+                $T::default_epsilon()
+            }
+
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn abs_diff_eq(&self, other: &Wrapping<$T>, epsilon: $T) -> bool {
+                let forward = self.0.wrapping_sub(other.0) as $U;
+                let distance = core::cmp::min(forward, forward.wrapping_neg());
+                epsilon >= 0 && distance <= epsilon as $U
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &Wrapping<$T>, epsilon: $T) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon as f64),
+                    ..Applied::default()
+                };
+                let eq = Wrapping::<$T>::abs_diff_eq(self, other, epsilon);
+                Report::leaf(eq, &self.0, &other.0, applied)
+            }
+        }
+    };
+}
+
+impl_wrapping_abs_diff_eq!(u8, u8);
+impl_wrapping_abs_diff_eq!(u16, u16);
+impl_wrapping_abs_diff_eq!(u32, u32);
+impl_wrapping_abs_diff_eq!(u64, u64);
+impl_wrapping_abs_diff_eq!(u128, u128);
+impl_wrapping_abs_diff_eq!(usize, usize);
+impl_wrapping_abs_diff_eq!(i8, u8);
+impl_wrapping_abs_diff_eq!(i16, u16);
+impl_wrapping_abs_diff_eq!(i32, u32);
+impl_wrapping_abs_diff_eq!(i64, u64);
+impl_wrapping_abs_diff_eq!(i128, u128);
+impl_wrapping_abs_diff_eq!(isize, usize);
+
+impl<T: AbsDiffEq> AbsDiffEq for Saturating<T> {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Saturating<T>, epsilon: T::Epsilon) -> bool {
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &Saturating<T>, epsilon: T::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(&self.0, &other.0, epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &Saturating<T>, epsilon: T::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(
+        &self,
+        other: &Saturating<T>,
+        epsilon: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::abs_diff_report_with(&self.0, &other.0, epsilon, policy)
+    }
+}
+
+macro_rules! impl_non_zero_abs_diff_eq {
+    ($($NonZero:ident => $T:ident),*) => {$(
+        impl AbsDiffEq for $NonZero {
+            type Epsilon = $T;
+
+            #[inline]
+            fn default_epsilon() -> $T {
+                $T::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$NonZero, epsilon: $T) -> bool {
+                $T::abs_diff_eq(&self.get(), &other.get(), epsilon)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$NonZero, epsilon: $T) -> Report {
+                $T::abs_diff_report(&self.get(), &other.get(), epsilon)
+            }
+        }
+    )*};
+}
+
+impl_non_zero_abs_diff_eq!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+);
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
@@ -128,12 +500,37 @@ impl<T: AbsDiffEq> AbsDiffEq for Option<T> {
 
     #[inline]
     fn abs_diff_eq(&self, other: &Option<T>, epsilon: T::Epsilon) -> bool {
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &Option<T>, epsilon: T::Epsilon, policy: Policy) -> bool {
         match (self, other) {
-            (Some(a), Some(b)) => T::abs_diff_eq(a, b, epsilon),
+            (Some(a), Some(b)) => T::abs_diff_eq_with(a, b, epsilon, policy),
             (None, None) => true,
             _ => false,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &Option<T>, epsilon: T::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(
+        &self,
+        other: &Option<T>,
+        epsilon: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        match (self, other) {
+            (Some(a), Some(b)) => T::abs_diff_report_with(a, b, epsilon, policy),
+            (None, None) => Report::new(),
+            (Some(_), None) => Report::variant("Some(..)", "None"),
+            (None, Some(_)) => Report::variant("None", "Some(..)"),
+        }
+    }
 }
 
 impl<T: AbsDiffEq, E: AbsDiffEq> AbsDiffEq for Result<T, E> {
@@ -146,12 +543,42 @@ impl<T: AbsDiffEq, E: AbsDiffEq> AbsDiffEq for Result<T, E> {
 
     #[inline]
     fn abs_diff_eq(&self, other: &Result<T, E>, epsilon: (T::Epsilon, E::Epsilon)) -> bool {
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(
+        &self,
+        other: &Result<T, E>,
+        epsilon: (T::Epsilon, E::Epsilon),
+        policy: Policy,
+    ) -> bool {
         match (self, other) {
-            (Ok(a), Ok(b)) => T::abs_diff_eq(a, b, epsilon.0),
-            (Err(a), Err(b)) => E::abs_diff_eq(a, b, epsilon.1),
+            (Ok(a), Ok(b)) => T::abs_diff_eq_with(a, b, epsilon.0, policy),
+            (Err(a), Err(b)) => E::abs_diff_eq_with(a, b, epsilon.1, policy),
             _ => false,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &Result<T, E>, epsilon: (T::Epsilon, E::Epsilon)) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(
+        &self,
+        other: &Result<T, E>,
+        epsilon: (T::Epsilon, E::Epsilon),
+        policy: Policy,
+    ) -> Report {
+        match (self, other) {
+            (Ok(a), Ok(b)) => T::abs_diff_report_with(a, b, epsilon.0, policy),
+            (Err(a), Err(b)) => E::abs_diff_report_with(a, b, epsilon.1, policy),
+            (Ok(_), Err(_)) => Report::variant("Ok(..)", "Err(..)"),
+            (Err(_), Ok(_)) => Report::variant("Err(..)", "Ok(..)"),
+        }
+    }
 }
 
 impl<'a, T: AbsDiffEq + ?Sized> AbsDiffEq for &'a T {
@@ -164,7 +591,22 @@ impl<'a, T: AbsDiffEq + ?Sized> AbsDiffEq for &'a T {
 
     #[inline]
     fn abs_diff_eq(&self, other: &&'a T, epsilon: T::Epsilon) -> bool {
-        T::abs_diff_eq(*self, *other, epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &&'a T, epsilon: T::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(*self, *other, epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &&'a T, epsilon: T::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(&self, other: &&'a T, epsilon: T::Epsilon, policy: Policy) -> Report {
+        T::abs_diff_report_with(*self, *other, epsilon, policy)
     }
 }
 
@@ -178,7 +620,27 @@ impl<'a, T: AbsDiffEq + ?Sized> AbsDiffEq for &'a mut T {
 
     #[inline]
     fn abs_diff_eq(&self, other: &&'a mut T, epsilon: T::Epsilon) -> bool {
-        T::abs_diff_eq(*self, *other, epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &&'a mut T, epsilon: T::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(*self, *other, epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &&'a mut T, epsilon: T::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(
+        &self,
+        other: &&'a mut T,
+        epsilon: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::abs_diff_report_with(*self, *other, epsilon, policy)
     }
 }
 
@@ -192,7 +654,27 @@ impl<T: AbsDiffEq + Copy> AbsDiffEq for cell::Cell<T> {
 
     #[inline]
     fn abs_diff_eq(&self, other: &cell::Cell<T>, epsilon: T::Epsilon) -> bool {
-        T::abs_diff_eq(&self.get(), &other.get(), epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &cell::Cell<T>, epsilon: T::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(&self.get(), &other.get(), epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &cell::Cell<T>, epsilon: T::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(
+        &self,
+        other: &cell::Cell<T>,
+        epsilon: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::abs_diff_report_with(&self.get(), &other.get(), epsilon, policy)
     }
 }
 
@@ -206,10 +688,200 @@ impl<T: AbsDiffEq + ?Sized> AbsDiffEq for cell::RefCell<T> {
 
     #[inline]
     fn abs_diff_eq(&self, other: &cell::RefCell<T>, epsilon: T::Epsilon) -> bool {
-        T::abs_diff_eq(&self.borrow(), &other.borrow(), epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
     }
+
+    #[inline]
+    fn abs_diff_eq_with(
+        &self,
+        other: &cell::RefCell<T>,
+        epsilon: T::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::abs_diff_eq_with(&self.borrow(), &other.borrow(), epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &cell::RefCell<T>, epsilon: T::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(
+        &self,
+        other: &cell::RefCell<T>,
+        epsilon: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::abs_diff_report_with(&self.borrow(), &other.borrow(), epsilon, policy)
+    }
+}
+
+// Smart pointers and owned wrappers compare the values they point to.
+macro_rules! impl_deref_abs_diff_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Type:ty => $T:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> AbsDiffEq for $Type
+        where
+            $T: AbsDiffEq,
+        {
+            type Epsilon = <$T as AbsDiffEq>::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                <$T as AbsDiffEq>::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Type, epsilon: Self::Epsilon) -> bool {
+                Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(
+                &self,
+                other: &$Type,
+                epsilon: Self::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                <$T as AbsDiffEq>::abs_diff_eq_with(&**self, &**other, epsilon, policy)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$Type, epsilon: Self::Epsilon) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report_with(
+                &self,
+                other: &$Type,
+                epsilon: Self::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                <$T as AbsDiffEq>::abs_diff_report_with(&**self, &**other, epsilon, policy)
+            }
+        }
+    )*};
+}
+
+impl_deref_abs_diff_eq! {
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::boxed::Box<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::rc::Rc<T> => T;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::sync::Arc<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    ['a, T: alloc::borrow::ToOwned + ?Sized] alloc::borrow::Cow<'a, T> => T;
+    [P: core::ops::Deref] core::pin::Pin<P> => P::Target;
+}
+
+// Locks compare the values they protect, see `sync::with_locked` for the poisoning and deadlocks.
+#[cfg(feature = "std")]
+macro_rules! impl_lock_abs_diff_eq {
+    ($($Lock:ident),*) => {$(
+        /// Compares the protected values. A poisoned lock is still compared, and a lock compared
+        /// with itself is only locked once instead of deadlocking.
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl<T: AbsDiffEq + ?Sized> AbsDiffEq for $Lock<T> {
+            type Epsilon = T::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Lock<T>, epsilon: T::Epsilon) -> bool {
+                Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(
+                &self,
+                other: &$Lock<T>,
+                epsilon: T::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                with_locked(self, other, |a, b| T::abs_diff_eq_with(a, b, epsilon, policy))
+            }
+
+            fn abs_diff_report(&self, other: &$Lock<T>, epsilon: T::Epsilon) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            fn abs_diff_report_with(
+                &self,
+                other: &$Lock<T>,
+                epsilon: T::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                with_locked(self, other, |a, b| T::abs_diff_report_with(a, b, epsilon, policy))
+            }
+        }
+    )*};
 }
 
+#[cfg(feature = "std")]
+impl_lock_abs_diff_eq!(Mutex, RwLock);
+
+// Cells which are initialized once compare like an `Option` of their value.
+macro_rules! impl_once_abs_diff_eq {
+    ($(#[$attr:meta])* $Once:ident) => {
+        $(#[$attr])*
+        impl<T: AbsDiffEq> AbsDiffEq for $Once<T> {
+            type Epsilon = T::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> T::Epsilon {
+                T::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Once<T>, epsilon: T::Epsilon) -> bool {
+                Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(
+                &self,
+                other: &$Once<T>,
+                epsilon: T::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                Option::abs_diff_eq_with(&self.get(), &other.get(), epsilon, policy)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$Once<T>, epsilon: T::Epsilon) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report_with(
+                &self,
+                other: &$Once<T>,
+                epsilon: T::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                Option::abs_diff_report_with(&self.get(), &other.get(), epsilon, policy)
+            }
+        }
+    };
+}
+
+impl_once_abs_diff_eq!(OnceCell);
+#[cfg(feature = "std")]
+impl_once_abs_diff_eq!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    OnceLock
+);
+
 impl<A, B> AbsDiffEq<[B]> for [A]
 where
     A: AbsDiffEq<B>,
@@ -224,8 +896,26 @@ where
 
     #[inline]
     fn abs_diff_eq(&self, other: &[B], epsilon: A::Epsilon) -> bool {
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &[B], epsilon: A::Epsilon, policy: Policy) -> bool {
         self.len() == other.len()
-            && Iterator::zip(self.iter(), other).all(|(x, y)| A::abs_diff_eq(x, y, epsilon.clone()))
+            && Iterator::zip(self.iter(), other)
+                .all(|(x, y)| A::abs_diff_eq_with(x, y, epsilon.clone(), policy))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &[B], epsilon: A::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(&self, other: &[B], epsilon: A::Epsilon, policy: Policy) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::abs_diff_report_with(x, y, epsilon.clone(), policy)
+        })
     }
 }
 
@@ -245,8 +935,26 @@ where
 
     #[inline]
     fn abs_diff_eq(&self, other: &[B; N], epsilon: A::Epsilon) -> bool {
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &[B; N], epsilon: A::Epsilon, policy: Policy) -> bool {
         self.len() == other.len()
-            && Iterator::zip(self.iter(), other).all(|(x, y)| A::abs_diff_eq(x, y, epsilon.clone()))
+            && Iterator::zip(self.iter(), other)
+                .all(|(x, y)| A::abs_diff_eq_with(x, y, epsilon.clone(), policy))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &[B; N], epsilon: A::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(&self, other: &[B; N], epsilon: A::Epsilon, policy: Policy) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::abs_diff_report_with(x, y, epsilon.clone(), policy)
+        })
     }
 }
 
@@ -278,7 +986,38 @@ macro_rules! impl_abs_diff_eq {
                 }
 
                 fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-                    true $( && self.$idx.abs_diff_eq(&other.$idx, epsilon.$idx) )+
+                    Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+                }
+
+                fn abs_diff_eq_with(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    policy: Policy,
+                ) -> bool {
+                    true $( && self.$idx.abs_diff_eq_with(&other.$idx, epsilon.$idx, policy) )+
+                }
+
+                #[cfg(feature = "alloc")]
+                fn abs_diff_report(&self, other: &Self, epsilon: Self::Epsilon) -> Report {
+                    Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+                }
+
+                #[cfg(feature = "alloc")]
+                fn abs_diff_report_with(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    policy: Policy,
+                ) -> Report {
+                    let mut report = Report::new();
+                    $(
+                        report.nest(
+                            PathSegment::Tuple($idx),
+                            self.$idx.abs_diff_report_with(&other.$idx, epsilon.$idx, policy),
+                        );
+                    )+
+                    report
                 }
             }
         }
@@ -321,8 +1060,26 @@ where
 
     #[inline]
     fn abs_diff_eq(&self, other: &Vec<B>, epsilon: A::Epsilon) -> bool {
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &Vec<B>, epsilon: A::Epsilon, policy: Policy) -> bool {
         self.len() == other.len()
-            && Iterator::zip(self.iter(), other).all(|(x, y)| A::abs_diff_eq(x, y, epsilon.clone()))
+            && Iterator::zip(self.iter(), other)
+                .all(|(x, y)| A::abs_diff_eq_with(x, y, epsilon.clone(), policy))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &Vec<B>, epsilon: A::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(&self, other: &Vec<B>, epsilon: A::Epsilon, policy: Policy) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::abs_diff_report_with(x, y, epsilon.clone(), policy)
+        })
     }
 }
 
@@ -341,8 +1098,37 @@ where
 
     #[inline]
     fn abs_diff_eq(&self, other: &Complex<T>, epsilon: T::Epsilon) -> bool {
-        T::abs_diff_eq(&self.re, &other.re, epsilon.clone())
-            && T::abs_diff_eq(&self.im, &other.im, epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &Complex<T>, epsilon: T::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(&self.re, &other.re, epsilon.clone(), policy)
+            && T::abs_diff_eq_with(&self.im, &other.im, epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &Complex<T>, epsilon: T::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(
+        &self,
+        other: &Complex<T>,
+        epsilon: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        let mut report = Report::new();
+        report.nest(
+            PathSegment::Field("re"),
+            T::abs_diff_report_with(&self.re, &other.re, epsilon.clone(), policy),
+        );
+        report.nest(
+            PathSegment::Field("im"),
+            T::abs_diff_report_with(&self.im, &other.im, epsilon, policy),
+        );
+        report
     }
 }
 
@@ -358,7 +1144,22 @@ impl<T: AbsDiffEq + Copy> AbsDiffEq for NotNan<T> {
 
     #[inline]
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        T::abs_diff_eq(&self.into_inner(), &other.into_inner(), epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &Self, epsilon: Self::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(&self.into_inner(), &other.into_inner(), epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &Self, epsilon: Self::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(&self, other: &Self, epsilon: Self::Epsilon, policy: Policy) -> Report {
+        T::abs_diff_report_with(&self.into_inner(), &other.into_inner(), epsilon, policy)
     }
 }
 
@@ -374,7 +1175,22 @@ impl<T: AbsDiffEq + Float + ordered_float::FloatCore> AbsDiffEq<T> for NotNan<T>
 
     #[inline]
     fn abs_diff_eq(&self, other: &T, epsilon: Self::Epsilon) -> bool {
-        T::abs_diff_eq(&self.into_inner(), other, epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &T, epsilon: Self::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(&self.into_inner(), other, epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &T, epsilon: Self::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(&self, other: &T, epsilon: Self::Epsilon, policy: Policy) -> Report {
+        T::abs_diff_report_with(&self.into_inner(), other, epsilon, policy)
     }
 }
 
@@ -390,7 +1206,22 @@ impl<T: AbsDiffEq + Float + ordered_float::FloatCore> AbsDiffEq for OrderedFloat
 
     #[inline]
     fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
-        T::abs_diff_eq(&self.into_inner(), &other.into_inner(), epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &Self, epsilon: Self::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(&self.into_inner(), &other.into_inner(), epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &Self, epsilon: Self::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(&self, other: &Self, epsilon: Self::Epsilon, policy: Policy) -> Report {
+        T::abs_diff_report_with(&self.into_inner(), &other.into_inner(), epsilon, policy)
     }
 }
 
@@ -406,7 +1237,22 @@ impl<T: AbsDiffEq + Float + ordered_float::FloatCore> AbsDiffEq<T> for OrderedFl
 
     #[inline]
     fn abs_diff_eq(&self, other: &T, epsilon: Self::Epsilon) -> bool {
-        T::abs_diff_eq(&self.into_inner(), other, epsilon)
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(&self, other: &T, epsilon: Self::Epsilon, policy: Policy) -> bool {
+        T::abs_diff_eq_with(&self.into_inner(), other, epsilon, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &T, epsilon: Self::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(&self, other: &T, epsilon: Self::Epsilon, policy: Policy) -> Report {
+        T::abs_diff_report_with(&self.into_inner(), other, epsilon, policy)
     }
 }
 
@@ -414,7 +1260,7 @@ impl<T: AbsDiffEq + Float + ordered_float::FloatCore> AbsDiffEq<T> for OrderedFl
 #[cfg_attr(docsrs, doc(cfg(feature = "indexmap_impl")))]
 impl<K, V1, V2, S1, S2> AbsDiffEq<IndexMap<K, V2, S2>> for IndexMap<K, V1, S1>
 where
-    K: Hash + Eq,
+    K: Hash + Eq + Debug,
     V1: AbsDiffEq<V2>,
     V1::Epsilon: Clone,
     S1: BuildHasher,
@@ -429,11 +1275,284 @@ where
 
     #[inline]
     fn abs_diff_eq(&self, other: &IndexMap<K, V2, S2>, epsilon: Self::Epsilon) -> bool {
+        Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+    }
+
+    #[inline]
+    fn abs_diff_eq_with(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        epsilon: Self::Epsilon,
+        policy: Policy,
+    ) -> bool {
         self.len() == other.len()
             && self.iter().all(|(key, value)| {
                 other
                     .get(key)
-                    .map_or(false, |v| V1::abs_diff_eq(value, v, epsilon.clone()))
+                    .is_some_and(|v| V1::abs_diff_eq_with(value, v, epsilon.clone(), policy))
             })
     }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report(&self, other: &IndexMap<K, V2, S2>, epsilon: Self::Epsilon) -> Report {
+        Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn abs_diff_report_with(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        epsilon: Self::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        if self.len() != other.len() {
+            return Report::length(self.len(), other.len());
+        }
+        let mut report = Report::new();
+        for (key, value) in self {
+            let nested = match other.get(key) {
+                Some(v) => V1::abs_diff_report_with(value, v, epsilon.clone(), policy),
+                None => Report::missing_key(),
+            };
+            report.nest(PathSegment::key(key), nested);
+        }
+        report
+    }
+}
+
+// Sequences other than slices compare element-wise like `Vec`.
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+macro_rules! impl_sequence_abs_diff_eq {
+    ($($(#[$attr:meta])* $Seq:ident;)*) => {$(
+        $(#[$attr])*
+        impl<A, B> AbsDiffEq<$Seq<B>> for $Seq<A>
+        where
+            A: AbsDiffEq<B>,
+            A::Epsilon: Clone,
+        {
+            type Epsilon = A::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> A::Epsilon {
+                A::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Seq<B>, epsilon: A::Epsilon) -> bool {
+                Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(
+                &self,
+                other: &$Seq<B>,
+                epsilon: A::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && Iterator::zip(self.iter(), other)
+                        .all(|(x, y)| A::abs_diff_eq_with(x, y, epsilon.clone(), policy))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$Seq<B>, epsilon: A::Epsilon) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report_with(
+                &self,
+                other: &$Seq<B>,
+                epsilon: A::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                Report::sequence(self, other, |x, y| {
+                    A::abs_diff_report_with(x, y, epsilon.clone(), policy)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+impl_sequence_abs_diff_eq! {
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    VecDeque;
+    #[cfg(feature = "linked_list_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "linked_list_impl")))]
+    LinkedList;
+}
+
+// Maps compare the values of the same keys like `IndexMap`, and locate mismatches by key.
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+macro_rules! impl_map_abs_diff_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*, V1, V2> AbsDiffEq<$Right> for $Left
+        where
+            V1: AbsDiffEq<V2>,
+            V1::Epsilon: Clone,
+        {
+            type Epsilon = V1::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> V1::Epsilon {
+                V1::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Right, epsilon: Self::Epsilon) -> bool {
+                Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(
+                &self,
+                other: &$Right,
+                epsilon: Self::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, value)| {
+                        other.get(key).is_some_and(|v| {
+                            V1::abs_diff_eq_with(value, v, epsilon.clone(), policy)
+                        })
+                    })
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$Right, epsilon: Self::Epsilon) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report_with(
+                &self,
+                other: &$Right,
+                epsilon: Self::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                if self.len() != other.len() {
+                    return Report::length(self.len(), other.len());
+                }
+                let mut report = Report::new();
+                for (key, value) in self {
+                    let nested = match other.get(key) {
+                        Some(v) => V1::abs_diff_report_with(value, v, epsilon.clone(), policy),
+                        None => Report::missing_key(),
+                    };
+                    report.nest(PathSegment::key(key), nested);
+                }
+                report
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+impl_map_abs_diff_eq! {
+    #[cfg(feature = "btree_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "btree_map_impl")))]
+    [K: Ord + Debug] BTreeMap<K, V1> => BTreeMap<K, V2>;
+    #[cfg(feature = "hash_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hash_map_impl")))]
+    [K: Hash + Eq + Debug, S1: BuildHasher, S2: BuildHasher] HashMap<K, V1, S1> => HashMap<K, V2, S2>;
+}
+
+// Different kinds of sequences compare element-wise, so that eg. a `Vec` can be compared with an
+// array literal.
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+macro_rules! impl_cross_abs_diff_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> AbsDiffEq<$Right> for $Left
+        where
+            A: AbsDiffEq<B>,
+            A::Epsilon: Clone,
+        {
+            type Epsilon = A::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> A::Epsilon {
+                A::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &$Right, epsilon: A::Epsilon) -> bool {
+                Self::abs_diff_eq_with(self, other, epsilon, Policy::default())
+            }
+
+            #[inline]
+            fn abs_diff_eq_with(
+                &self,
+                other: &$Right,
+                epsilon: A::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && Iterator::zip(self.iter(), other.iter())
+                        .all(|(x, y)| A::abs_diff_eq_with(x, y, epsilon.clone(), policy))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report(&self, other: &$Right, epsilon: A::Epsilon) -> Report {
+                Self::abs_diff_report_with(self, other, epsilon, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn abs_diff_report_with(
+                &self,
+                other: &$Right,
+                epsilon: A::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                Report::sequence(self.iter(), other.iter(), |x, y| {
+                    A::abs_diff_report_with(x, y, epsilon.clone(), policy)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+impl_cross_abs_diff_eq! {
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] Vec<A> => [B; N];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] [A; N] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] Vec<A> => [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] [A] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] Vec<A> => &'a [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] &'a [A] => Vec<B>;
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A; N] => [B];
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A] => [B; N];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] VecDeque<A> => [B];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] [A] => VecDeque<B>;
 }
@@ -0,0 +1,260 @@
+use crate::{
+    AbsDiff, AbsDiffEq, ApproxError, Combined, IsClose, IsCloseEq, MathIsClose, Relative,
+    RelativeEq, Ulps, UlpsEq,
+};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::mem;
+use core::panic::Location;
+
+/// A failing comparison recorded by [`Checks`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Failure {
+    /// Where the comparison was recorded.
+    pub location: &'static Location<'static>,
+    /// The description of the failure.
+    pub error: ApproxError,
+}
+
+/// Collects failing approximate comparisons, and panics once with all of them instead of stopping
+/// at the first one.
+///
+/// Comparisons are recorded with [`check`](Checks::check), which takes the result of one of the
+/// `check_*!` macros, or with the methods named after the comparisons, which take the builder
+/// holding the tolerances. The source location of every call is kept along with the failure.
+///
+/// [`finish`](Checks::finish) panics with a table of all the failures, if there are any. A
+/// collector which is dropped without being finished panics the same way, unless the thread is
+/// already panicking.
+///
+/// ```text
+/// 2 of 3 approximate comparison(s) failed
+///
+///     location          comparison                left  right  mismatch
+///     src/main.rs:9:8   check_relative_eq!(a, b)  1.0   1.5    values differ (abs_diff = 0.5, ...)
+///     src/main.rs:10:8  relative_eq               2.0   2.5    values differ (abs_diff = 0.5, ...)
+/// ```
+///
+/// # Example
+///
+/// ```rust,should_panic
+/// #[macro_use]
+/// extern crate approxim;
+///
+/// use approxim::{Checks, Relative};
+///
+/// # fn main() {
+/// let mut checks = Checks::new();
+/// checks.check(check_relative_eq!(0.1 + 0.2, 0.3));
+/// checks.check(check_relative_eq!(1.0, 1.5, max_relative = 0.1));
+/// checks.relative_eq(&2.0, &2.5, Relative::default().max_relative(0.1));
+/// assert_eq!(checks.failures().len(), 2);
+///
+/// checks.finish();
+/// # }
+/// ```
+#[derive(Debug, Default)]
+#[must_use = "the failures are only reported by `Checks::finish`"]
+pub struct Checks {
+    total: usize,
+    failures: Vec<Failure>,
+}
+
+impl Checks {
+    /// Creates a collector without any recorded comparison.
+    #[inline]
+    pub fn new() -> Checks {
+        Checks::default()
+    }
+
+    /// Records the result of a comparison, such as the one of a `check_*!` macro, and returns
+    /// whether it succeeded.
+    #[track_caller]
+    pub fn check(&mut self, result: Result<(), ApproxError>) -> bool {
+        self.total += 1;
+        match result {
+            Ok(()) => true,
+            Err(error) => {
+                let location = Location::caller();
+                self.failures.push(Failure { location, error });
+                false
+            }
+        }
+    }
+
+    /// Records an equality comparison using [`AbsDiffEq`], see [`AbsDiff::check`].
+    #[track_caller]
+    pub fn abs_diff_eq<A, B>(&mut self, lhs: &A, rhs: &B, tolerance: AbsDiff<A, B>) -> bool
+    where
+        A: AbsDiffEq<B> + fmt::Debug + ?Sized,
+        B: fmt::Debug + ?Sized,
+        A::Epsilon: Clone + fmt::Debug,
+    {
+        self.check(tolerance.check(lhs, rhs))
+    }
+
+    /// Records an equality comparison using [`RelativeEq`], see [`Relative::check`].
+    #[track_caller]
+    pub fn relative_eq<A, B>(&mut self, lhs: &A, rhs: &B, tolerance: Relative<A, B>) -> bool
+    where
+        A: RelativeEq<B> + fmt::Debug + ?Sized,
+        B: fmt::Debug + ?Sized,
+        A::Epsilon: Clone + fmt::Debug,
+    {
+        self.check(tolerance.check(lhs, rhs))
+    }
+
+    /// Records an equality comparison using [`UlpsEq`], see [`Ulps::check`].
+    #[track_caller]
+    pub fn ulps_eq<A, B>(&mut self, lhs: &A, rhs: &B, tolerance: Ulps<A, B>) -> bool
+    where
+        A: UlpsEq<B> + fmt::Debug + ?Sized,
+        B: fmt::Debug + ?Sized,
+        A::Epsilon: Clone + fmt::Debug,
+    {
+        self.check(tolerance.check(lhs, rhs))
+    }
+
+    /// Records an equality comparison using the formula of `numpy.isclose`, see
+    /// [`IsClose::check`].
+    #[track_caller]
+    pub fn isclose<A, B>(&mut self, lhs: &A, rhs: &B, tolerance: IsClose<A, B>) -> bool
+    where
+        A: IsCloseEq<B> + fmt::Debug + ?Sized,
+        B: fmt::Debug + ?Sized,
+        A::Epsilon: Clone + fmt::Debug,
+    {
+        self.check(tolerance.check(lhs, rhs))
+    }
+
+    /// Records an equality comparison using the formula of `math.isclose`, see
+    /// [`MathIsClose::check`].
+    #[track_caller]
+    pub fn math_isclose<A, B>(&mut self, lhs: &A, rhs: &B, tolerance: MathIsClose<A, B>) -> bool
+    where
+        A: IsCloseEq<B> + fmt::Debug + ?Sized,
+        B: fmt::Debug + ?Sized,
+        A::Epsilon: Clone + fmt::Debug,
+    {
+        self.check(tolerance.check(lhs, rhs))
+    }
+
+    /// Records an equality comparison combining several criteria, see [`Combined::check`].
+    #[track_caller]
+    pub fn approx_eq<A, B>(&mut self, lhs: &A, rhs: &B, tolerance: Combined<A, B>) -> bool
+    where
+        A: RelativeEq<B> + UlpsEq<B> + fmt::Debug + ?Sized,
+        B: fmt::Debug + ?Sized,
+        A::Epsilon: Clone + fmt::Debug + Default,
+    {
+        self.check(tolerance.check(lhs, rhs))
+    }
+
+    /// The number of recorded comparisons, including the successful ones.
+    #[inline]
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The failing comparisons, in the order in which they were recorded.
+    #[inline]
+    pub fn failures(&self) -> &[Failure] {
+        &self.failures
+    }
+
+    /// Panics with a table of the failing comparisons, if there are any.
+    #[track_caller]
+    pub fn finish(mut self) {
+        let failures = mem::take(&mut self.failures);
+        if !failures.is_empty() {
+            panic!("{}", Table(&failures, self.total));
+        }
+    }
+}
+
+impl Drop for Checks {
+    fn drop(&mut self) {
+        if !self.failures.is_empty() && !std::thread::panicking() {
+            let failures = mem::take(&mut self.failures);
+            panic!("{}", Table(&failures, self.total));
+        }
+    }
+}
+
+/// Summarises the mismatch of a failure on a single line.
+fn summary(error: &ApproxError) -> String {
+    let mismatch = match error.report().first() {
+        Some(mismatch) => mismatch,
+        None => return String::from("values are equal"),
+    };
+    let mut summary = format!("{}", mismatch.kind);
+    if !mismatch.path.is_empty() {
+        let _ = write!(summary, " at {}", mismatch.display_path());
+    }
+    let numbers: [(&str, Option<&dyn fmt::Debug>); 3] = [
+        ("abs_diff", mismatch.abs_diff.as_ref().map(|v| v as _)),
+        ("rel_diff", mismatch.rel_diff.as_ref().map(|v| v as _)),
+        ("ulps", mismatch.ulps.as_ref().map(|v| v as _)),
+    ];
+    let mut separator = " (";
+    for (name, value) in numbers {
+        if let Some(value) = value {
+            let _ = write!(summary, "{}{} = {:?}", separator, name, value);
+            separator = ", ";
+        }
+    }
+    if separator == ", " {
+        summary.push(')');
+    }
+    summary
+}
+
+/// The panic message of [`Checks`], with one row per failure.
+struct Table<'a>(&'a [Failure], usize);
+
+impl fmt::Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = ["location", "comparison", "left", "right", "mismatch"];
+        let rows: Vec<[String; 5]> = self
+            .0
+            .iter()
+            .map(|failure| {
+                [
+                    format!("{}", failure.location),
+                    format!("{}", failure.error.display_invocation()),
+                    String::from(failure.error.left()),
+                    String::from(failure.error.right()),
+                    summary(&failure.error),
+                ]
+            })
+            .collect();
+
+        let mut widths = header.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        writeln!(
+            f,
+            "{} of {} approximate comparison(s) failed",
+            self.0.len(),
+            self.1
+        )?;
+        let header = header.map(String::from);
+        for row in core::iter::once(&header).chain(&rows) {
+            write!(f, "\n   ")?;
+            for (i, cell) in row.iter().enumerate() {
+                if i + 1 == row.len() {
+                    write!(f, " {}", cell)?;
+                } else {
+                    write!(f, " {:<width$} ", cell, width = widths[i])?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
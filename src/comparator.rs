@@ -0,0 +1,293 @@
+#[cfg(feature = "alloc")]
+use crate::{report, Report};
+use core::fmt;
+
+/// A strategy for comparing two values, which can be named, combined and passed around.
+///
+/// The builders [`AbsDiff`](crate::AbsDiff), [`Relative`](crate::Relative),
+/// [`Ulps`](crate::Ulps), [`IsClose`](crate::IsClose), [`MathIsClose`](crate::MathIsClose) and
+/// [`Combined`](crate::Combined) are comparators using their tolerances. Comparators are combined
+/// with [`and`](Comparator::and), [`or`](Comparator::or) and [`not`](Comparator::not), and
+/// [`from_fn`] turns a closure into a comparator.
+///
+/// Trait methods cannot be `const`, so a comparator combined in a constant uses the `const`
+/// constructors [`And::new`], [`Or::new`] and [`Not::new`] instead. The builders take their
+/// default tolerances from [`AbsDiffEq::default_epsilon`](crate::AbsDiffEq::default_epsilon) and
+/// its siblings, which are not `const` either, so a combination of builders is shared through a
+/// function returning it, as `close` below.
+///
+/// A comparator is used with the [`assert_approx!`](crate::assert_approx) macro, or directly by
+/// helpers which take it as an argument.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approxim;
+///
+/// use approxim::{AbsDiff, Comparator, Relative};
+///
+/// fn close() -> impl Comparator<f64> {
+///     AbsDiff::default()
+///         .epsilon(1e-12)
+///         .or(Relative::default().max_relative(1e-9))
+/// }
+///
+/// fn assert_all_close(a: &[f64], b: &[f64], cmp: impl Comparator<f64>) {
+///     assert!(a.iter().zip(b).all(|(a, b)| cmp.eq(a, b)));
+/// }
+///
+/// # fn main() {
+/// assert_all_close(&[0.1 + 0.2, 1e-13], &[0.3, 0.0], close());
+/// assert_approx!(1e6 + 1e-4, 1e6, using = close());
+/// assert_approx!(1.0, 1.1, using = close().not());
+/// # }
+/// ```
+pub trait Comparator<A, B = A>
+where
+    A: ?Sized,
+    B: ?Sized,
+{
+    /// Returns `true` if the values compare equal.
+    fn eq(&self, lhs: &A, rhs: &B) -> bool;
+
+    /// Returns `true` if the values do not compare equal.
+    #[inline]
+    fn ne(&self, lhs: &A, rhs: &B) -> bool {
+        !self.eq(lhs, rhs)
+    }
+
+    /// Performs the same comparison as [`Comparator::eq`], but returns a [`Report`] listing every
+    /// position at which the values differ.
+    ///
+    /// The default implementation records a failed comparison as a single mismatch without
+    /// details, see [`Report::from_eq`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn report(&self, lhs: &A, rhs: &B) -> Report {
+        Report::from_eq(self.eq(lhs, rhs))
+    }
+
+    /// Combines two comparators, which must both compare the values equal.
+    #[inline]
+    fn and<C>(self, other: C) -> And<Self, C>
+    where
+        Self: Sized,
+        C: Comparator<A, B>,
+    {
+        And(self, other)
+    }
+
+    /// Combines two comparators, either of which must compare the values equal.
+    #[inline]
+    fn or<C>(self, other: C) -> Or<Self, C>
+    where
+        Self: Sized,
+        C: Comparator<A, B>,
+    {
+        Or(self, other)
+    }
+
+    /// Inverts the comparator, which then compares equal the values it did not.
+    #[inline]
+    fn not(self) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self)
+    }
+}
+
+impl<A, B, C> Comparator<A, B> for &C
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B> + ?Sized,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        C::eq(self, lhs, rhs)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn report(&self, lhs: &A, rhs: &B) -> Report {
+        C::report(self, lhs, rhs)
+    }
+}
+
+/// A comparator which requires both comparators to compare the values equal, see
+/// [`Comparator::and`].
+#[derive(Clone, Copy, Debug)]
+pub struct And<L, R>(L, R);
+
+impl<L, R> And<L, R> {
+    /// Combines two comparators like [`Comparator::and`], which is usable in a constant.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use approxim::{from_fn, And, Comparator, FromFn};
+    ///
+    /// type Cmp = FromFn<fn(&f64, &f64) -> bool>;
+    ///
+    /// const POSITIVE: And<Cmp, Cmp> =
+    ///     And::new(from_fn(|a, _| *a > 0.0), from_fn(|_, b| *b > 0.0));
+    ///
+    /// assert!(POSITIVE.eq(&1.0, &2.0));
+    /// assert!(POSITIVE.ne(&1.0, &-2.0));
+    /// ```
+    #[inline]
+    pub const fn new(left: L, right: R) -> And<L, R> {
+        And(left, right)
+    }
+}
+
+impl<A, B, L, R> Comparator<A, B> for And<L, R>
+where
+    A: ?Sized,
+    B: ?Sized,
+    L: Comparator<A, B>,
+    R: Comparator<A, B>,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        self.0.eq(lhs, rhs) && self.1.eq(lhs, rhs)
+    }
+
+    /// Reports the mismatches of the first comparator which fails.
+    #[cfg(feature = "alloc")]
+    fn report(&self, lhs: &A, rhs: &B) -> Report {
+        let report = self.0.report(lhs, rhs);
+        if report.is_eq() {
+            self.1.report(lhs, rhs)
+        } else {
+            report
+        }
+    }
+}
+
+/// A comparator which requires either comparator to compare the values equal, see
+/// [`Comparator::or`].
+#[derive(Clone, Copy, Debug)]
+pub struct Or<L, R>(L, R);
+
+impl<L, R> Or<L, R> {
+    /// Combines two comparators like [`Comparator::or`], which is usable in a constant.
+    #[inline]
+    pub const fn new(left: L, right: R) -> Or<L, R> {
+        Or(left, right)
+    }
+}
+
+impl<A, B, L, R> Comparator<A, B> for Or<L, R>
+where
+    A: ?Sized,
+    B: ?Sized,
+    L: Comparator<A, B>,
+    R: Comparator<A, B>,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        self.0.eq(lhs, rhs) || self.1.eq(lhs, rhs)
+    }
+
+    /// Reports the mismatches of the first comparator, if both fail.
+    #[cfg(feature = "alloc")]
+    fn report(&self, lhs: &A, rhs: &B) -> Report {
+        let report = self.0.report(lhs, rhs);
+        if report.is_eq() || self.1.eq(lhs, rhs) {
+            Report::new()
+        } else {
+            report
+        }
+    }
+}
+
+/// A comparator which compares equal the values another comparator does not, see
+/// [`Comparator::not`].
+#[derive(Clone, Copy, Debug)]
+pub struct Not<C>(C);
+
+impl<C> Not<C> {
+    /// Inverts a comparator like [`Comparator::not`], which is usable in a constant.
+    #[inline]
+    pub const fn new(cmp: C) -> Not<C> {
+        Not(cmp)
+    }
+}
+
+impl<A, B, C> Comparator<A, B> for Not<C>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B>,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        self.0.ne(lhs, rhs)
+    }
+}
+
+/// A comparator calling a closure, see [`from_fn`].
+#[derive(Clone, Copy)]
+pub struct FromFn<F>(F);
+
+impl<F> fmt::Debug for FromFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromFn").finish_non_exhaustive()
+    }
+}
+
+impl<A, B, F> Comparator<A, B> for FromFn<F>
+where
+    A: ?Sized,
+    B: ?Sized,
+    F: Fn(&A, &B) -> bool,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        (self.0)(lhs, rhs)
+    }
+}
+
+/// Creates a comparator which calls the given closure to compare two values.
+///
+/// Since this is a `const fn`, a comparator made from a closure which captures nothing can be
+/// stored in a constant.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approxim;
+///
+/// use approxim::{from_fn, Comparator, FromFn};
+///
+/// const SAME_SIGN: FromFn<fn(&f64, &f64) -> bool> =
+///     from_fn(|a, b| a.is_sign_negative() == b.is_sign_negative());
+///
+/// # fn main() {
+/// assert!(SAME_SIGN.eq(&1.0, &2.0));
+/// assert_approx!(-1.0, -1e-300, using = SAME_SIGN);
+/// # }
+/// ```
+#[inline]
+pub const fn from_fn<F>(f: F) -> FromFn<F> {
+    FromFn(f)
+}
+
+#[doc(hidden)]
+pub fn __details<A, B, C>(cmp: &C, lhs: &A, rhs: &B) -> impl fmt::Display
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: Comparator<A, B> + ?Sized,
+{
+    #[cfg(feature = "alloc")]
+    return report::Details(cmp.report(lhs, rhs));
+    #[cfg(not(feature = "alloc"))]
+    {
+        let _ = (cmp, lhs, rhs);
+        ""
+    }
+}
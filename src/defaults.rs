@@ -0,0 +1,165 @@
+use core::cell::Cell;
+
+/// Overrides of the default tolerances of a floating point type, see [`with_defaults`].
+///
+/// The tolerances which are `None` keep the value of the enclosing scope, or the built-in default
+/// of the type outside of any scope.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Defaults<T> {
+    /// Overrides [`AbsDiffEq::default_epsilon`](crate::AbsDiffEq::default_epsilon).
+    pub epsilon: Option<T>,
+    /// Overrides [`RelativeEq::default_max_relative`](crate::RelativeEq::default_max_relative).
+    pub max_relative: Option<T>,
+    /// Overrides [`UlpsEq::default_max_ulps`](crate::UlpsEq::default_max_ulps).
+    pub max_ulps: Option<u32>,
+}
+
+impl Defaults<f32> {
+    /// Overrides of the default tolerances of `f32`, which do not override anything yet.
+    #[inline]
+    pub const fn f32() -> Defaults<f32> {
+        Defaults::NONE
+    }
+}
+
+impl Defaults<f64> {
+    /// Overrides of the default tolerances of `f64`, which do not override anything yet.
+    #[inline]
+    pub const fn f64() -> Defaults<f64> {
+        Defaults::NONE
+    }
+}
+
+impl<T> Defaults<T> {
+    const NONE: Defaults<T> = Defaults {
+        epsilon: None,
+        max_relative: None,
+        max_ulps: None,
+    };
+
+    /// Replace the default epsilon.
+    #[inline]
+    pub fn epsilon(self, epsilon: T) -> Defaults<T> {
+        Defaults {
+            epsilon: Some(epsilon),
+            ..self
+        }
+    }
+
+    /// Replace the default maximum relative difference.
+    #[inline]
+    pub fn max_relative(self, max_relative: T) -> Defaults<T> {
+        Defaults {
+            max_relative: Some(max_relative),
+            ..self
+        }
+    }
+
+    /// Replace the default maximum ULPs.
+    #[inline]
+    pub fn max_ulps(self, max_ulps: u32) -> Defaults<T> {
+        Defaults {
+            max_ulps: Some(max_ulps),
+            ..self
+        }
+    }
+
+    /// Fills the tolerances which are not overridden with the ones of `outer`.
+    fn or(self, outer: Defaults<T>) -> Defaults<T> {
+        Defaults {
+            epsilon: self.epsilon.or(outer.epsilon),
+            max_relative: self.max_relative.or(outer.max_relative),
+            max_ulps: self.max_ulps.or(outer.max_ulps),
+        }
+    }
+}
+
+/// A floating point type whose default tolerances can be overridden with [`with_defaults`].
+///
+/// This trait is sealed, and implemented for `f32` and `f64`.
+pub trait ScopedDefaults: private::Sealed {}
+
+impl ScopedDefaults for f32 {}
+impl ScopedDefaults for f64 {}
+
+mod private {
+    use super::Defaults;
+    use core::cell::Cell;
+    use std::thread::LocalKey;
+
+    pub trait Sealed: Copy + 'static {
+        /// The overrides which are active on the current thread.
+        fn overrides() -> &'static LocalKey<Cell<Defaults<Self>>>;
+    }
+
+    std::thread_local! {
+        static F32: Cell<Defaults<f32>> = const { Cell::new(Defaults::NONE) };
+        static F64: Cell<Defaults<f64>> = const { Cell::new(Defaults::NONE) };
+    }
+
+    impl Sealed for f32 {
+        #[inline]
+        fn overrides() -> &'static LocalKey<Cell<Defaults<f32>>> {
+            &F32
+        }
+    }
+
+    impl Sealed for f64 {
+        #[inline]
+        fn overrides() -> &'static LocalKey<Cell<Defaults<f64>>> {
+            &F64
+        }
+    }
+}
+
+/// The overrides which are active on the current thread.
+pub(crate) fn current<T: ScopedDefaults>() -> Defaults<T> {
+    T::overrides().try_with(Cell::get).unwrap_or(Defaults::NONE)
+}
+
+/// Restores the overrides of the enclosing scope when dropped.
+struct Restore<T: ScopedDefaults>(Defaults<T>);
+
+impl<T: ScopedDefaults> Drop for Restore<T> {
+    fn drop(&mut self) {
+        let _ = T::overrides().try_with(|overrides| overrides.set(self.0));
+    }
+}
+
+/// Calls `f` with the default tolerances of `T` overridden on the current thread.
+///
+/// Within `f`, the [`Default`] implementations of [`AbsDiff`](crate::AbsDiff),
+/// [`Relative`](crate::Relative) and [`Ulps`](crate::Ulps), and therefore the comparison macros
+/// without explicit tolerances, use the overridden values. So does everything else which relies
+/// on [`AbsDiffEq::default_epsilon`](crate::AbsDiffEq::default_epsilon) and its siblings, such as
+/// the implementations for containers of `T`. Comparisons through [`Mixed`](crate::Mixed) use the
+/// overridden values of `f32`.
+///
+/// Scopes can be nested, the tolerances which are not overridden by the inner scope keep the
+/// values of the outer one. The previous values are restored when `f` returns or panics. Other
+/// threads are not affected.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approxim;
+///
+/// use approxim::{with_defaults, Defaults};
+///
+/// # fn main() {
+/// with_defaults(Defaults::f64().max_relative(1e-9), || {
+///     assert_relative_eq!(1.0, 1.0 + 1e-10);
+///     assert_relative_eq!([1.0, 2.0][..], [1.0 + 1e-10, 2.0][..]);
+/// });
+/// assert_relative_ne!(1.0, 1.0 + 1e-10);
+/// # }
+/// ```
+pub fn with_defaults<T, R>(defaults: Defaults<T>, f: impl FnOnce() -> R) -> R
+where
+    T: ScopedDefaults,
+{
+    let previous = T::overrides().with(|overrides| overrides.replace(defaults.or(overrides.get())));
+    let _restore = Restore(previous);
+    f()
+}
@@ -0,0 +1,1039 @@
+#[cfg(feature = "std")]
+use crate::sync::with_locked;
+use crate::Mixed;
+#[cfg(feature = "btree_map_impl")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "linked_list_impl")]
+use alloc::collections::LinkedList;
+#[cfg(feature = "vec_deque_impl")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "vec_impl")]
+use alloc::vec::Vec;
+use core::cell::{self, OnceCell};
+#[cfg(any(feature = "indexmap_impl", feature = "hash_map_impl"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "half")]
+use half::{bf16, f16 as half_f16};
+#[cfg(feature = "indexmap_impl")]
+use indexmap::IndexMap;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+use num_traits::float::FloatCore;
+#[cfg(feature = "ordered-float")]
+use num_traits::Float;
+#[cfg(feature = "ordered-float")]
+use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "hash_map_impl")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock, RwLock};
+
+/// The error metrics underlying the approximate comparisons.
+///
+/// The absolute and relative differences are returned as [`f64`] and the ULPs distance as [`u64`],
+/// regardless of the compared type. Collections return the largest metric over all of their
+/// elements, and an infinite difference (or [`u64::MAX`] ULPs) if they cannot be compared
+/// element-wise, eg. because their lengths differ.
+///
+/// This allows logging the error of a computation and choosing tolerances empirically instead of
+/// by trial and error. The free functions [`abs_diff`], [`relative_diff`] and [`ulps_distance`]
+/// are shorthands for the methods of this trait.
+///
+/// # Examples
+///
+/// ```
+/// use approxim::{abs_diff, relative_diff, ulps_distance};
+///
+/// assert_eq!(abs_diff(&1.0, &1.5), 0.5);
+/// assert_eq!(relative_diff(&1.0, &2.0), 0.5);
+/// assert_eq!(ulps_distance(&1.0f32, &f32::from_bits(1.0f32.to_bits() + 3)), 3);
+///
+/// assert_eq!(abs_diff(&[1.0, 2.0][..], &[1.25, 2.5][..]), 0.5);
+/// ```
+pub trait Distance<Rhs = Self>
+where
+    Rhs: ?Sized,
+{
+    /// The absolute difference `|a - b|`.
+    fn abs_diff(&self, other: &Rhs) -> f64;
+
+    /// The relative difference `|a - b| / max(|a|, |b|)`.
+    ///
+    /// This is zero if both values are equal, including when both are zero.
+    fn relative_diff(&self, other: &Rhs) -> f64;
+
+    /// The number of representable values between `a` and `b`, ie. the distance in units in the
+    /// last place.
+    ///
+    /// Values with different signs or NaN values are [`u64::MAX`] ULPs apart.
+    fn ulps_distance(&self, other: &Rhs) -> u64;
+}
+
+/// The absolute difference `|a - b|`, see [`Distance::abs_diff`].
+#[inline]
+pub fn abs_diff<A, B>(a: &A, b: &B) -> f64
+where
+    A: Distance<B> + ?Sized,
+    B: ?Sized,
+{
+    A::abs_diff(a, b)
+}
+
+/// The relative difference `|a - b| / max(|a|, |b|)`, see [`Distance::relative_diff`].
+#[inline]
+pub fn relative_diff<A, B>(a: &A, b: &B) -> f64
+where
+    A: Distance<B> + ?Sized,
+    B: ?Sized,
+{
+    A::relative_diff(a, b)
+}
+
+/// The distance in units in the last place, see [`Distance::ulps_distance`].
+#[inline]
+pub fn ulps_distance<A, B>(a: &A, b: &B) -> u64
+where
+    A: Distance<B> + ?Sized,
+    B: ?Sized,
+{
+    A::ulps_distance(a, b)
+}
+
+/// The larger of two differences, propagating NaN.
+#[inline]
+fn max(a: f64, b: f64) -> f64 {
+    if a.is_nan() || a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Converts a floating point value to `f64`, rounding where [`From`] is not implemented because
+/// the conversion is lossy.
+pub(crate) trait AsF64: Copy {
+    fn as_f64(self) -> f64;
+
+    /// The absolute difference `|self - other|`, subtracted before rounding to `f64` wherever
+    /// rounding first would lose the difference.
+    #[inline]
+    fn abs_diff_f64(self, other: Self) -> f64 {
+        FloatCore::abs(self.as_f64() - other.as_f64())
+    }
+
+    /// The relative difference `|self - other| / max(|self|, |other|)` of two unequal values.
+    #[inline]
+    fn relative_diff_f64(self, other: Self) -> f64 {
+        let largest = FloatCore::max(
+            FloatCore::abs(self.as_f64()),
+            FloatCore::abs(other.as_f64()),
+        );
+        // Different infinities are infinitely far apart
+        if FloatCore::is_infinite(largest) {
+            return f64::INFINITY;
+        }
+        self.abs_diff_f64(other) / largest
+    }
+}
+
+macro_rules! impl_as_f64 {
+    ($($T:ident),*) => {$(
+        impl AsF64 for $T {
+            #[inline]
+            fn as_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
+}
+
+impl_as_f64!(f32, f64);
+#[cfg(approxim_nightly_float)]
+impl_as_f64!(f16);
+
+// Rounding `f128` values to `f64` first would lose any difference below `f64` precision, so the
+// differences are computed in `f128` and only the result is rounded.
+#[cfg(approxim_nightly_float)]
+impl AsF64 for f128 {
+    #[inline]
+    fn as_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline]
+    fn abs_diff_f64(self, other: f128) -> f64 {
+        (self - other).abs() as f64
+    }
+
+    #[inline]
+    fn relative_diff_f64(self, other: f128) -> f64 {
+        let largest = self.abs().max(other.abs());
+        // Different infinities are infinitely far apart
+        if largest.is_infinite() {
+            return f64::INFINITY;
+        }
+        ((self - other).abs() / largest) as f64
+    }
+}
+
+#[cfg(feature = "half")]
+impl AsF64 for half_f16 {
+    #[inline]
+    fn as_f64(self) -> f64 {
+        self.to_f64()
+    }
+}
+
+#[cfg(feature = "half")]
+impl AsF64 for bf16 {
+    #[inline]
+    fn as_f64(self) -> f64 {
+        self.to_f64()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Base implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_int_distance {
+    ($($T:ident),*) => {$(
+        impl Distance for $T {
+            #[inline]
+            fn abs_diff(&self, other: &$T) -> f64 {
+                $T::abs_diff(*self, *other) as f64
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$T) -> f64 {
+                if self == other {
+                    return 0.0;
+                }
+                let largest = FloatCore::max(
+                    FloatCore::abs(*self as f64),
+                    FloatCore::abs(*other as f64),
+                );
+                Distance::abs_diff(self, other) / largest
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$T) -> u64 {
+                u64::try_from($T::abs_diff(*self, *other)).unwrap_or(u64::MAX)
+            }
+        }
+    )*};
+}
+
+impl_int_distance!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_float_distance {
+    ($T:ident) => {
+        impl Distance for $T {
+            #[inline]
+            fn abs_diff(&self, other: &$T) -> f64 {
+                // Handle same infinities
+                if self == other {
+                    return 0.0;
+                }
+                AsF64::abs_diff_f64(*self, *other)
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$T) -> f64 {
+                if self == other {
+                    return 0.0;
+                }
+                AsF64::relative_diff_f64(*self, *other)
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$T) -> u64 {
+                if self == other {
+                    return 0;
+                }
+                if $T::is_nan(*self)
+                    || $T::is_nan(*other)
+                    || $T::is_sign_negative(*self) != $T::is_sign_negative(*other)
+                {
+                    return u64::MAX;
+                }
+                u64::try_from(self.to_bits().abs_diff(other.to_bits())).unwrap_or(u64::MAX)
+            }
+        }
+    };
+}
+
+impl_float_distance!(f32);
+impl_float_distance!(f64);
+#[cfg(approxim_nightly_float)]
+impl_float_distance!(f16);
+#[cfg(approxim_nightly_float)]
+impl_float_distance!(f128);
+#[cfg(feature = "half")]
+impl_float_distance!(half_f16);
+#[cfg(feature = "half")]
+impl_float_distance!(bf16);
+
+/// Rounds an `f64` to the nearest `f32`, or returns `None` if a finite value is out of range.
+///
+/// Such a value has no meaningful distance to any finite `f32`.
+#[inline]
+pub(crate) fn narrow(value: f64) -> Option<f32> {
+    let narrowed = value as f32;
+    if narrowed.is_infinite() && value.is_finite() {
+        None
+    } else {
+        Some(narrowed)
+    }
+}
+
+/// Rounds both operands of a mixed-precision comparison to `f32`, see [`narrow`].
+///
+/// Only the distance in ULPs is measured this way, in the lower precision. The absolute and
+/// relative differences are computed on the operands widened to `f64`, so that they include the
+/// rounding error of the `f32`.
+#[inline]
+pub(crate) fn narrow_both(a: impl Into<f64>, b: impl Into<f64>) -> Option<(f32, f32)> {
+    Some((narrow(a.into())?, narrow(b.into())?))
+}
+
+macro_rules! impl_mixed_distance {
+    ($T:ident, $U:ident) => {
+        impl Distance<$U> for Mixed<$T> {
+            #[inline]
+            fn abs_diff(&self, other: &$U) -> f64 {
+                f64::abs_diff(&f64::from(self.0), &f64::from(*other))
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$U) -> f64 {
+                f64::relative_diff(&f64::from(self.0), &f64::from(*other))
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$U) -> u64 {
+                narrow_both(self.0, *other).map_or(u64::MAX, |(a, b)| f32::ulps_distance(&a, &b))
+            }
+        }
+    };
+}
+
+impl_mixed_distance!(f32, f64);
+impl_mixed_distance!(f64, f32);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Derived implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: Distance> Distance for Option<T> {
+    #[inline]
+    fn abs_diff(&self, other: &Option<T>) -> f64 {
+        match (self, other) {
+            (Some(a), Some(b)) => T::abs_diff(a, b),
+            (None, None) => 0.0,
+            _ => f64::INFINITY,
+        }
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &Option<T>) -> f64 {
+        match (self, other) {
+            (Some(a), Some(b)) => T::relative_diff(a, b),
+            (None, None) => 0.0,
+            _ => f64::INFINITY,
+        }
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &Option<T>) -> u64 {
+        match (self, other) {
+            (Some(a), Some(b)) => T::ulps_distance(a, b),
+            (None, None) => 0,
+            _ => u64::MAX,
+        }
+    }
+}
+
+impl<T: Distance, E: Distance> Distance for Result<T, E> {
+    #[inline]
+    fn abs_diff(&self, other: &Result<T, E>) -> f64 {
+        match (self, other) {
+            (Ok(a), Ok(b)) => T::abs_diff(a, b),
+            (Err(a), Err(b)) => E::abs_diff(a, b),
+            _ => f64::INFINITY,
+        }
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &Result<T, E>) -> f64 {
+        match (self, other) {
+            (Ok(a), Ok(b)) => T::relative_diff(a, b),
+            (Err(a), Err(b)) => E::relative_diff(a, b),
+            _ => f64::INFINITY,
+        }
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &Result<T, E>) -> u64 {
+        match (self, other) {
+            (Ok(a), Ok(b)) => T::ulps_distance(a, b),
+            (Err(a), Err(b)) => E::ulps_distance(a, b),
+            _ => u64::MAX,
+        }
+    }
+}
+
+impl<'a, T: Distance + ?Sized> Distance for &'a T {
+    #[inline]
+    fn abs_diff(&self, other: &&'a T) -> f64 {
+        T::abs_diff(*self, *other)
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &&'a T) -> f64 {
+        T::relative_diff(*self, *other)
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &&'a T) -> u64 {
+        T::ulps_distance(*self, *other)
+    }
+}
+
+impl<'a, T: Distance + ?Sized> Distance for &'a mut T {
+    #[inline]
+    fn abs_diff(&self, other: &&'a mut T) -> f64 {
+        T::abs_diff(*self, *other)
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &&'a mut T) -> f64 {
+        T::relative_diff(*self, *other)
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &&'a mut T) -> u64 {
+        T::ulps_distance(*self, *other)
+    }
+}
+
+impl<T: Distance + Copy> Distance for cell::Cell<T> {
+    #[inline]
+    fn abs_diff(&self, other: &cell::Cell<T>) -> f64 {
+        T::abs_diff(&self.get(), &other.get())
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &cell::Cell<T>) -> f64 {
+        T::relative_diff(&self.get(), &other.get())
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &cell::Cell<T>) -> u64 {
+        T::ulps_distance(&self.get(), &other.get())
+    }
+}
+
+impl<T: Distance + ?Sized> Distance for cell::RefCell<T> {
+    #[inline]
+    fn abs_diff(&self, other: &cell::RefCell<T>) -> f64 {
+        T::abs_diff(&self.borrow(), &other.borrow())
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &cell::RefCell<T>) -> f64 {
+        T::relative_diff(&self.borrow(), &other.borrow())
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &cell::RefCell<T>) -> u64 {
+        T::ulps_distance(&self.borrow(), &other.borrow())
+    }
+}
+
+// Smart pointers and owned wrappers compare the values they point to.
+macro_rules! impl_deref_distance {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Type:ty => $T:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> Distance for $Type
+        where
+            $T: Distance,
+        {
+            #[inline]
+            fn abs_diff(&self, other: &$Type) -> f64 {
+                <$T as Distance>::abs_diff(&**self, &**other)
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$Type) -> f64 {
+                <$T as Distance>::relative_diff(&**self, &**other)
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$Type) -> u64 {
+                <$T as Distance>::ulps_distance(&**self, &**other)
+            }
+        }
+    )*};
+}
+
+impl_deref_distance! {
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::boxed::Box<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::rc::Rc<T> => T;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::sync::Arc<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    ['a, T: alloc::borrow::ToOwned + ?Sized] alloc::borrow::Cow<'a, T> => T;
+    [P: core::ops::Deref] core::pin::Pin<P> => P::Target;
+}
+
+// Locks compare the values they protect, see `sync::with_locked` for the poisoning and deadlocks.
+#[cfg(feature = "std")]
+macro_rules! impl_lock_distance {
+    ($($Lock:ident),*) => {$(
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl<T: Distance + ?Sized> Distance for $Lock<T> {
+            #[inline]
+            fn abs_diff(&self, other: &$Lock<T>) -> f64 {
+                with_locked(self, other, T::abs_diff)
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$Lock<T>) -> f64 {
+                with_locked(self, other, T::relative_diff)
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$Lock<T>) -> u64 {
+                with_locked(self, other, T::ulps_distance)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "std")]
+impl_lock_distance!(Mutex, RwLock);
+
+// Cells which are initialized once compare like an `Option` of their value.
+macro_rules! impl_once_distance {
+    ($(#[$attr:meta])* $Once:ident) => {
+        $(#[$attr])*
+        impl<T: Distance> Distance for $Once<T> {
+            #[inline]
+            fn abs_diff(&self, other: &$Once<T>) -> f64 {
+                Option::abs_diff(&self.get(), &other.get())
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$Once<T>) -> f64 {
+                Option::relative_diff(&self.get(), &other.get())
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$Once<T>) -> u64 {
+                Option::ulps_distance(&self.get(), &other.get())
+            }
+        }
+    };
+}
+
+impl_once_distance!(OnceCell);
+#[cfg(feature = "std")]
+impl_once_distance!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    OnceLock
+);
+
+impl<A, B> Distance<[B]> for [A]
+where
+    A: Distance<B>,
+{
+    #[inline]
+    fn abs_diff(&self, other: &[B]) -> f64 {
+        if self.len() != other.len() {
+            return f64::INFINITY;
+        }
+        Iterator::zip(self.iter(), other).fold(0.0, |d, (x, y)| max(d, A::abs_diff(x, y)))
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &[B]) -> f64 {
+        if self.len() != other.len() {
+            return f64::INFINITY;
+        }
+        Iterator::zip(self.iter(), other).fold(0.0, |d, (x, y)| max(d, A::relative_diff(x, y)))
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &[B]) -> u64 {
+        if self.len() != other.len() {
+            return u64::MAX;
+        }
+        Iterator::zip(self.iter(), other).fold(0, |d, (x, y)| d.max(A::ulps_distance(x, y)))
+    }
+}
+
+#[cfg(feature = "array_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+impl<A, B, const N: usize> Distance<[B; N]> for [A; N]
+where
+    A: Distance<B>,
+{
+    #[inline]
+    fn abs_diff(&self, other: &[B; N]) -> f64 {
+        <[A]>::abs_diff(self, other)
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &[B; N]) -> f64 {
+        <[A]>::relative_diff(self, other)
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &[B; N]) -> u64 {
+        <[A]>::ulps_distance(self, other)
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+impl<A, B> Distance<Vec<B>> for Vec<A>
+where
+    A: Distance<B>,
+{
+    #[inline]
+    fn abs_diff(&self, other: &Vec<B>) -> f64 {
+        <[A]>::abs_diff(self, other)
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &Vec<B>) -> f64 {
+        <[A]>::relative_diff(self, other)
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &Vec<B>) -> u64 {
+        <[A]>::ulps_distance(self, other)
+    }
+}
+
+#[cfg(feature = "tuple_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tuple_impl")))]
+macro_rules! impl_distance {
+    () => {
+        impl Distance for () {
+            fn abs_diff(&self, _other: &Self) -> f64 {
+                0.0
+            }
+
+            fn relative_diff(&self, _other: &Self) -> f64 {
+                0.0
+            }
+
+            fn ulps_distance(&self, _other: &Self) -> u64 {
+                0
+            }
+        }
+    };
+
+    ($($idx:tt),+) => {
+        paste::paste! {
+            impl<$( [<T $idx>], )+> Distance for ($( [<T $idx>], )+)
+            where
+                $( [<T $idx>]: Distance, )+
+            {
+                fn abs_diff(&self, other: &Self) -> f64 {
+                    let diff = 0.0;
+                    $( let diff = max(diff, self.$idx.abs_diff(&other.$idx)); )+
+                    diff
+                }
+
+                fn relative_diff(&self, other: &Self) -> f64 {
+                    let diff = 0.0;
+                    $( let diff = max(diff, self.$idx.relative_diff(&other.$idx)); )+
+                    diff
+                }
+
+                fn ulps_distance(&self, other: &Self) -> u64 {
+                    0 $( .max(self.$idx.ulps_distance(&other.$idx)) )+
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "tuple_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tuple_impl")))]
+mod distance_tuple_impls {
+    use super::*;
+
+    impl_distance!();
+    impl_distance!(0);
+    impl_distance!(0, 1);
+    impl_distance!(0, 1, 2);
+    impl_distance!(0, 1, 2, 3);
+    impl_distance!(0, 1, 2, 3, 4);
+    impl_distance!(0, 1, 2, 3, 4, 5);
+    impl_distance!(0, 1, 2, 3, 4, 5, 6);
+    impl_distance!(0, 1, 2, 3, 4, 5, 6, 7);
+    impl_distance!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+    impl_distance!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+    impl_distance!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    impl_distance!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+}
+
+#[cfg(feature = "num-complex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-complex")))]
+impl<T: Distance> Distance for Complex<T> {
+    #[inline]
+    fn abs_diff(&self, other: &Complex<T>) -> f64 {
+        max(
+            T::abs_diff(&self.re, &other.re),
+            T::abs_diff(&self.im, &other.im),
+        )
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &Complex<T>) -> f64 {
+        max(
+            T::relative_diff(&self.re, &other.re),
+            T::relative_diff(&self.im, &other.im),
+        )
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &Complex<T>) -> u64 {
+        T::ulps_distance(&self.re, &other.re).max(T::ulps_distance(&self.im, &other.im))
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+impl<T: Distance + Copy> Distance for NotNan<T> {
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> f64 {
+        T::abs_diff(&self.into_inner(), &other.into_inner())
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &Self) -> f64 {
+        T::relative_diff(&self.into_inner(), &other.into_inner())
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &Self) -> u64 {
+        T::ulps_distance(&self.into_inner(), &other.into_inner())
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+impl<T: Distance + Float + ordered_float::FloatCore> Distance<T> for NotNan<T> {
+    #[inline]
+    fn abs_diff(&self, other: &T) -> f64 {
+        T::abs_diff(&self.into_inner(), other)
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &T) -> f64 {
+        T::relative_diff(&self.into_inner(), other)
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &T) -> u64 {
+        T::ulps_distance(&self.into_inner(), other)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+impl<T: Distance + Float + ordered_float::FloatCore> Distance for OrderedFloat<T> {
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> f64 {
+        T::abs_diff(&self.into_inner(), &other.into_inner())
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &Self) -> f64 {
+        T::relative_diff(&self.into_inner(), &other.into_inner())
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &Self) -> u64 {
+        T::ulps_distance(&self.into_inner(), &other.into_inner())
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+impl<T: Distance + Float + ordered_float::FloatCore> Distance<T> for OrderedFloat<T> {
+    #[inline]
+    fn abs_diff(&self, other: &T) -> f64 {
+        T::abs_diff(&self.into_inner(), other)
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &T) -> f64 {
+        T::relative_diff(&self.into_inner(), other)
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &T) -> u64 {
+        T::ulps_distance(&self.into_inner(), other)
+    }
+}
+
+#[cfg(feature = "indexmap_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap_impl")))]
+impl<K, V1, V2, S1, S2> Distance<IndexMap<K, V2, S2>> for IndexMap<K, V1, S1>
+where
+    K: Hash + Eq,
+    V1: Distance<V2>,
+    S1: BuildHasher,
+    S2: BuildHasher,
+{
+    #[inline]
+    fn abs_diff(&self, other: &IndexMap<K, V2, S2>) -> f64 {
+        if self.len() != other.len() {
+            return f64::INFINITY;
+        }
+        self.iter().fold(0.0, |d, (key, value)| {
+            max(
+                d,
+                other
+                    .get(key)
+                    .map_or(f64::INFINITY, |v| V1::abs_diff(value, v)),
+            )
+        })
+    }
+
+    #[inline]
+    fn relative_diff(&self, other: &IndexMap<K, V2, S2>) -> f64 {
+        if self.len() != other.len() {
+            return f64::INFINITY;
+        }
+        self.iter().fold(0.0, |d, (key, value)| {
+            max(
+                d,
+                other
+                    .get(key)
+                    .map_or(f64::INFINITY, |v| V1::relative_diff(value, v)),
+            )
+        })
+    }
+
+    #[inline]
+    fn ulps_distance(&self, other: &IndexMap<K, V2, S2>) -> u64 {
+        if self.len() != other.len() {
+            return u64::MAX;
+        }
+        self.iter().fold(0, |d, (key, value)| {
+            d.max(
+                other
+                    .get(key)
+                    .map_or(u64::MAX, |v| V1::ulps_distance(value, v)),
+            )
+        })
+    }
+}
+
+// Sequences other than slices compare element-wise like `Vec`.
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+macro_rules! impl_sequence_distance {
+    ($($(#[$attr:meta])* $Seq:ident;)*) => {$(
+        $(#[$attr])*
+        impl<A, B> Distance<$Seq<B>> for $Seq<A>
+        where
+            A: Distance<B>,
+        {
+            #[inline]
+            fn abs_diff(&self, other: &$Seq<B>) -> f64 {
+                if self.len() != other.len() {
+                    return f64::INFINITY;
+                }
+                Iterator::zip(self.iter(), other).fold(0.0, |d, (x, y)| max(d, A::abs_diff(x, y)))
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$Seq<B>) -> f64 {
+                if self.len() != other.len() {
+                    return f64::INFINITY;
+                }
+                Iterator::zip(self.iter(), other)
+                    .fold(0.0, |d, (x, y)| max(d, A::relative_diff(x, y)))
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$Seq<B>) -> u64 {
+                if self.len() != other.len() {
+                    return u64::MAX;
+                }
+                Iterator::zip(self.iter(), other).fold(0, |d, (x, y)| d.max(A::ulps_distance(x, y)))
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+impl_sequence_distance! {
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    VecDeque;
+    #[cfg(feature = "linked_list_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "linked_list_impl")))]
+    LinkedList;
+}
+
+// Maps compare the values of the same keys like `IndexMap`.
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+macro_rules! impl_map_distance {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*, V1, V2> Distance<$Right> for $Left
+        where
+            V1: Distance<V2>,
+        {
+            #[inline]
+            fn abs_diff(&self, other: &$Right) -> f64 {
+                if self.len() != other.len() {
+                    return f64::INFINITY;
+                }
+                self.iter().fold(0.0, |d, (key, value)| {
+                    max(
+                        d,
+                        other
+                            .get(key)
+                            .map_or(f64::INFINITY, |v| V1::abs_diff(value, v)),
+                    )
+                })
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$Right) -> f64 {
+                if self.len() != other.len() {
+                    return f64::INFINITY;
+                }
+                self.iter().fold(0.0, |d, (key, value)| {
+                    max(
+                        d,
+                        other
+                            .get(key)
+                            .map_or(f64::INFINITY, |v| V1::relative_diff(value, v)),
+                    )
+                })
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$Right) -> u64 {
+                if self.len() != other.len() {
+                    return u64::MAX;
+                }
+                self.iter().fold(0, |d, (key, value)| {
+                    d.max(
+                        other
+                            .get(key)
+                            .map_or(u64::MAX, |v| V1::ulps_distance(value, v)),
+                    )
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+impl_map_distance! {
+    #[cfg(feature = "btree_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "btree_map_impl")))]
+    [K: Ord] BTreeMap<K, V1> => BTreeMap<K, V2>;
+    #[cfg(feature = "hash_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hash_map_impl")))]
+    [K: Hash + Eq, S1: BuildHasher, S2: BuildHasher] HashMap<K, V1, S1> => HashMap<K, V2, S2>;
+}
+
+// Different kinds of sequences compare element-wise, so that eg. a `Vec` can be compared with an
+// array literal.
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+macro_rules! impl_cross_distance {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> Distance<$Right> for $Left
+        where
+            A: Distance<B>,
+        {
+            #[inline]
+            fn abs_diff(&self, other: &$Right) -> f64 {
+                if self.len() != other.len() {
+                    return f64::INFINITY;
+                }
+                Iterator::zip(self.iter(), other.iter())
+                    .fold(0.0, |d, (x, y)| max(d, A::abs_diff(x, y)))
+            }
+
+            #[inline]
+            fn relative_diff(&self, other: &$Right) -> f64 {
+                if self.len() != other.len() {
+                    return f64::INFINITY;
+                }
+                Iterator::zip(self.iter(), other.iter())
+                    .fold(0.0, |d, (x, y)| max(d, A::relative_diff(x, y)))
+            }
+
+            #[inline]
+            fn ulps_distance(&self, other: &$Right) -> u64 {
+                if self.len() != other.len() {
+                    return u64::MAX;
+                }
+                Iterator::zip(self.iter(), other.iter())
+                    .fold(0, |d, (x, y)| d.max(A::ulps_distance(x, y)))
+            }
+        }
+    )*};
+}
+
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+impl_cross_distance! {
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] Vec<A> => [B; N];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] [A; N] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] Vec<A> => [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] [A] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] Vec<A> => &'a [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] &'a [A] => Vec<B>;
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A; N] => [B];
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A] => [B; N];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] VecDeque<A> => [B];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] [A] => VecDeque<B>;
+}
@@ -0,0 +1,202 @@
+#[cfg(feature = "alloc")]
+use crate::Report;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, format, string::String};
+use core::fmt;
+
+/// The error returned by the `check_*!` macros and the `check` and `check_ne` methods of the
+/// builders, when a comparison fails.
+///
+/// It carries what the `assert_*!` macros put in their panic message, so that the failure can be
+/// reported without panicking. The [`Display`](fmt::Display) output is the same message, minus the
+/// trailing blank lines. The values, tolerances and mismatches are only kept with the `alloc`
+/// feature, otherwise the error only names the comparison and the expressions.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approxim;
+///
+/// # fn main() {
+/// let error = check_relative_eq!(1.0, 1.5, max_relative = 0.1).unwrap_err();
+/// assert_eq!(error.comparison(), "relative_eq");
+/// assert_eq!(error.expressions(), Some(("1.0", "1.5")));
+/// assert_eq!(error.options(), "max_relative = 0.1");
+///
+/// assert!(check_relative_eq!(1.0, 1.05, max_relative = 0.1).is_ok());
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApproxError {
+    comparison: &'static str,
+    expressions: Option<(&'static str, &'static str)>,
+    options: &'static str,
+    #[cfg(feature = "alloc")]
+    pub(crate) values: Box<Values>,
+}
+
+/// The formatted values and tolerances, boxed to keep the error small.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Values {
+    left: String,
+    right: String,
+    tolerance: String,
+    pub(crate) report: Report,
+}
+
+impl ApproxError {
+    /// Create the error of a failed comparison, without the expressions.
+    pub(crate) fn new<A, B, C>(
+        comparison: &'static str,
+        left: &A,
+        right: &B,
+        tolerance: &C,
+    ) -> ApproxError
+    where
+        A: fmt::Debug + ?Sized,
+        B: fmt::Debug + ?Sized,
+        C: fmt::Debug,
+    {
+        #[cfg(not(feature = "alloc"))]
+        let _ = (left, right, tolerance);
+        ApproxError {
+            comparison,
+            expressions: None,
+            options: "",
+            #[cfg(feature = "alloc")]
+            values: Box::new(Values {
+                left: format!("{:?}", left),
+                right: format!("{:?}", right),
+                tolerance: format!("{:?}", tolerance),
+                report: Report::new(),
+            }),
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn __with_expressions(
+        self,
+        given: &'static str,
+        expected: &'static str,
+        options: &'static str,
+    ) -> ApproxError {
+        ApproxError {
+            expressions: Some((given, expected)),
+            options,
+            ..self
+        }
+    }
+
+    /// The name of the comparison which failed, eg. `"relative_eq"` or `"ulps_ne"`.
+    #[inline]
+    pub fn comparison(&self) -> &'static str {
+        self.comparison
+    }
+
+    /// The source code of the compared expressions, if the error comes from a `check_*!` macro.
+    #[inline]
+    pub fn expressions(&self) -> Option<(&'static str, &'static str)> {
+        self.expressions
+    }
+
+    /// The source code of the options passed to the `check_*!` macro, or an empty string.
+    #[inline]
+    pub fn options(&self) -> &'static str {
+        self.options
+    }
+
+    /// The [`Debug`](fmt::Debug) representation of the left-hand side.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn left(&self) -> &str {
+        &self.values.left
+    }
+
+    /// The [`Debug`](fmt::Debug) representation of the right-hand side.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn right(&self) -> &str {
+        &self.values.right
+    }
+
+    /// The [`Debug`](fmt::Debug) representation of the builder holding the tolerances.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn tolerance(&self) -> &str {
+        &self.values.tolerance
+    }
+
+    /// Every position at which the values differ. Empty when an inequality fails.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn report(&self) -> &Report {
+        &self.values.report
+    }
+
+    /// Displays the failing macro invocation, or the name of the comparison if the error does not
+    /// come from a macro.
+    pub(crate) fn display_invocation(&self) -> impl fmt::Display + '_ {
+        struct DisplayInvocation<'a>(&'a ApproxError);
+
+        impl fmt::Display for DisplayInvocation<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let error = self.0;
+                match error.expressions {
+                    Some((given, expected)) if error.options.is_empty() => {
+                        write!(f, "check_{}!({}, {})", error.comparison, given, expected)
+                    }
+                    Some((given, expected)) => write!(
+                        f,
+                        "check_{}!({}, {}, {})",
+                        error.comparison, given, expected, error.options
+                    ),
+                    None => write!(f, "{}", error.comparison),
+                }
+            }
+        }
+
+        DisplayInvocation(self)
+    }
+}
+
+/// Displays the failed comparison, followed by the values, the tolerances and the first mismatch.
+///
+/// ```text
+/// check_relative_eq!(a, b, max_relative = 0.1) failed
+///
+///     left      = 1.0
+///     right     = 1.5
+///     tolerance = Relative { epsilon: 2.220446049250313e-16, max_relative: 0.1, ... }
+///
+///     first of 1 mismatch(es):
+///     values differ
+///     ...
+/// ```
+impl fmt::Display for ApproxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed", self.display_invocation())?;
+        #[cfg(feature = "alloc")]
+        {
+            write!(f, "\n\n    left      = {}", self.values.left)?;
+            write!(f, "\n    right     = {}", self.values.right)?;
+            write!(f, "\n    tolerance = {}", self.values.tolerance)?;
+            if let Some(first) = self.values.report.first() {
+                write!(
+                    f,
+                    "\n\n    first of {} mismatch(es):\n",
+                    self.values.report.len()
+                )?;
+                first.write_indented(f, "    ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ApproxError {}
@@ -0,0 +1,1358 @@
+#[cfg(feature = "alloc")]
+use crate::distance::AsF64;
+#[cfg(all(
+    feature = "alloc",
+    any(
+        feature = "tuple_impl",
+        feature = "num-complex",
+        feature = "indexmap_impl",
+        feature = "btree_map_impl",
+        feature = "hash_map_impl"
+    )
+))]
+use crate::report::PathSegment;
+#[cfg(feature = "alloc")]
+use crate::report::{Applied, Report};
+#[cfg(feature = "std")]
+use crate::sync::with_locked;
+use crate::{AbsDiffEq, Mixed};
+#[cfg(feature = "btree_map_impl")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "linked_list_impl")]
+use alloc::collections::LinkedList;
+#[cfg(feature = "vec_deque_impl")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "vec_impl")]
+use alloc::vec::Vec;
+use core::cell::{self, OnceCell};
+#[cfg(any(
+    feature = "btree_map_impl",
+    feature = "hash_map_impl",
+    feature = "indexmap_impl"
+))]
+use core::fmt::Debug;
+#[cfg(any(feature = "indexmap_impl", feature = "hash_map_impl"))]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "half")]
+use half::{bf16, f16 as half_f16};
+#[cfg(feature = "indexmap_impl")]
+use indexmap::IndexMap;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+#[cfg(feature = "ordered-float")]
+use num_traits::Float;
+#[cfg(feature = "ordered-float")]
+use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "hash_map_impl")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock, RwLock};
+
+/// The formula used by [`IsCloseEq`] to combine the absolute and relative tolerances.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IsCloseMode {
+    /// The formula of [`numpy.isclose`](https://numpy.org/doc/stable/reference/generated/numpy.isclose.html):
+    /// `|a - b| <= atol + rtol * |b|`.
+    ///
+    /// The tolerances are added, and the relative tolerance is scaled by the right-hand value
+    /// only, so the comparison is not symmetric. Infinite values are only close to themselves.
+    /// The default tolerances are `atol = 1e-8` and `rtol = 1e-5`.
+    #[default]
+    NumPy,
+    /// The formula of [`math.isclose`](https://docs.python.org/3/library/math.html#math.isclose):
+    /// `|a - b| <= max(rtol * max(|a|, |b|), atol)`.
+    ///
+    /// The comparison is symmetric, and negative tolerances never compare unequal values as
+    /// close. The default tolerances are `atol = 0` and `rtol = 1e-9`.
+    Math,
+}
+
+/// Equality comparisons combining an absolute and a relative tolerance the way Python does.
+///
+/// Unlike [`RelativeEq`](crate::RelativeEq), which accepts values that pass either the absolute
+/// or the relative test, this trait implements the exact formulas of `numpy.isclose` and
+/// `math.isclose`, selected by [`IsCloseMode`], so that tolerances from ported NumPy or SciPy
+/// tests keep their meaning.
+///
+/// `isclose`, `not_isclose`, `assert_isclose`, and `assert_not_isclose` macros use the NumPy
+/// formula, while their `math_isclose` counterparts use the formula of the standard library.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use] extern crate approxim;
+/// # fn main() {
+/// assert_isclose!(100.0, 100.001);
+/// assert_not_isclose!(100.0, 100.002);
+/// assert_isclose!(1e-9, 0.0);
+/// assert_not_math_isclose!(1e-9, 0.0);
+/// assert_math_isclose!(1.0, 1.1, rel_tol = 0.1);
+/// # }
+/// ```
+pub trait IsCloseEq<Rhs = Self>: AbsDiffEq<Rhs>
+where
+    Rhs: ?Sized,
+{
+    /// The default absolute tolerance of the given mode.
+    ///
+    /// This is used when no `atol` or `abs_tol` value is supplied to the
+    /// [`isclose!`](crate::isclose) or [`math_isclose!`](crate::math_isclose) macros.
+    fn default_atol(mode: IsCloseMode) -> Self::Epsilon;
+
+    /// The default relative tolerance of the given mode.
+    ///
+    /// This is used when no `rtol` or `rel_tol` value is supplied to the
+    /// [`isclose!`](crate::isclose) or [`math_isclose!`](crate::math_isclose) macros.
+    fn default_rtol(mode: IsCloseMode) -> Self::Epsilon;
+
+    /// A test for equality that combines the absolute and relative tolerances according to
+    /// `mode`.
+    fn isclose(
+        &self,
+        other: &Rhs,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool;
+
+    /// The inverse of [`IsCloseEq::isclose`].
+    fn not_isclose(
+        &self,
+        other: &Rhs,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        !Self::isclose(self, other, atol, rtol, mode)
+    }
+
+    /// Performs the same comparison as [`IsCloseEq::isclose`], but returns a [`Report`] listing
+    /// every position at which the values differ.
+    ///
+    /// The absolute and relative tolerances are recorded as the `epsilon` and `max_relative` of
+    /// each mismatch.
+    ///
+    /// The default implementation reports a single mismatch without any details, so
+    /// implementations for containers should override it to report their elements.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn isclose_report(
+        &self,
+        other: &Rhs,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        Report::from_eq(Self::isclose(self, other, atol, rtol, mode))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Base implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Implementation based on `numpy.isclose` and CPython's `math_isclose_impl`.
+macro_rules! impl_isclose_eq {
+    ($T:ident) => {
+        impl IsCloseEq for $T {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> $T {
+                match mode {
+                    IsCloseMode::NumPy => 1e-8,
+                    IsCloseMode::Math => 0.0,
+                }
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> $T {
+                match mode {
+                    IsCloseMode::NumPy => 1e-5,
+                    IsCloseMode::Math => 1e-9,
+                }
+            }
+
+            #[inline]
+            #[allow(unused_imports)]
+            fn isclose(&self, other: &$T, atol: $T, rtol: $T, mode: IsCloseMode) -> bool {
+                use num_traits::float::FloatCore;
+                // Handle same infinities
+                if self == other {
+                    return true;
+                }
+
+                // Handle remaining infinities
+                if $T::is_infinite(*self) || $T::is_infinite(*other) {
+                    return false;
+                }
+
+                let abs_diff = $T::abs(self - other);
+                match mode {
+                    IsCloseMode::NumPy => abs_diff <= atol + rtol * $T::abs(*other),
+                    IsCloseMode::Math => {
+                        let abs_self = $T::abs(*self);
+                        let abs_other = $T::abs(*other);
+                        let largest = if abs_other > abs_self {
+                            abs_other
+                        } else {
+                            abs_self
+                        };
+                        abs_diff <= rtol * largest || abs_diff <= atol
+                    }
+                }
+            }
+
+            #[cfg(feature = "alloc")]
+            fn isclose_report(&self, other: &$T, atol: $T, rtol: $T, mode: IsCloseMode) -> Report {
+                let applied = Applied {
+                    epsilon: Some(atol.as_f64()),
+                    max_relative: Some(rtol.as_f64()),
+                    ..Applied::default()
+                };
+                let eq = $T::isclose(self, other, atol, rtol, mode);
+                Report::leaf(eq, self, other, applied)
+            }
+        }
+    };
+}
+
+impl_isclose_eq!(f32);
+impl_isclose_eq!(f64);
+#[cfg(approxim_nightly_float)]
+impl_isclose_eq!(f16);
+#[cfg(approxim_nightly_float)]
+impl_isclose_eq!(f128);
+
+// Same as `impl_isclose_eq!`, but computed in single precision like `impl_half_abs_diff_eq!`.
+#[cfg(feature = "half")]
+macro_rules! impl_half_isclose_eq {
+    ($T:ident) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+        impl IsCloseEq for $T {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> $T {
+                $T::from_f32(<f32 as IsCloseEq>::default_atol(mode))
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> $T {
+                $T::from_f32(<f32 as IsCloseEq>::default_rtol(mode))
+            }
+
+            #[inline]
+            fn isclose(&self, other: &$T, atol: $T, rtol: $T, mode: IsCloseMode) -> bool {
+                let (lhs, rhs) = (self.to_f32(), other.to_f32());
+                f32::isclose(&lhs, &rhs, atol.to_f32(), rtol.to_f32(), mode)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn isclose_report(&self, other: &$T, atol: $T, rtol: $T, mode: IsCloseMode) -> Report {
+                let applied = Applied {
+                    epsilon: Some(f64::from(atol)),
+                    max_relative: Some(f64::from(rtol)),
+                    ..Applied::default()
+                };
+                let eq = $T::isclose(self, other, atol, rtol, mode);
+                Report::leaf(eq, self, other, applied)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "half")]
+impl_half_isclose_eq!(half_f16);
+#[cfg(feature = "half")]
+impl_half_isclose_eq!(bf16);
+
+// Same as `impl_mixed_abs_diff_eq!`, comparing the widened values.
+macro_rules! impl_mixed_isclose_eq {
+    ($T:ident, $U:ident) => {
+        impl IsCloseEq<$U> for Mixed<$T> {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> f64 {
+                f64::from(<f32 as IsCloseEq>::default_atol(mode))
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> f64 {
+                f64::from(<f32 as IsCloseEq>::default_rtol(mode))
+            }
+
+            #[inline]
+            fn isclose(&self, other: &$U, atol: f64, rtol: f64, mode: IsCloseMode) -> bool {
+                f64::isclose(&f64::from(self.0), &f64::from(*other), atol, rtol, mode)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn isclose_report(
+                &self,
+                other: &$U,
+                atol: f64,
+                rtol: f64,
+                mode: IsCloseMode,
+            ) -> Report {
+                let applied = Applied {
+                    epsilon: Some(atol),
+                    max_relative: Some(rtol),
+                    ..Applied::default()
+                };
+                let eq = IsCloseEq::isclose(self, other, atol, rtol, mode);
+                Report::leaf(eq, self, other, applied)
+            }
+        }
+    };
+}
+
+impl_mixed_isclose_eq!(f32, f64);
+impl_mixed_isclose_eq!(f64, f32);
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Derived implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T: IsCloseEq> IsCloseEq for Option<T> {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &Option<T>,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        match (self, other) {
+            (Some(a), Some(b)) => T::isclose(a, b, atol, rtol, mode),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &Option<T>,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        match (self, other) {
+            (Some(a), Some(b)) => T::isclose_report(a, b, atol, rtol, mode),
+            (None, None) => Report::new(),
+            (Some(_), None) => Report::variant("Some(..)", "None"),
+            (None, Some(_)) => Report::variant("None", "Some(..)"),
+        }
+    }
+}
+
+impl<T: IsCloseEq, E: IsCloseEq> IsCloseEq for Result<T, E> {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> (T::Epsilon, E::Epsilon) {
+        (T::default_atol(mode), E::default_atol(mode))
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> (T::Epsilon, E::Epsilon) {
+        (T::default_rtol(mode), E::default_rtol(mode))
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &Result<T, E>,
+        atol: (T::Epsilon, E::Epsilon),
+        rtol: (T::Epsilon, E::Epsilon),
+        mode: IsCloseMode,
+    ) -> bool {
+        match (self, other) {
+            (Ok(a), Ok(b)) => T::isclose(a, b, atol.0, rtol.0, mode),
+            (Err(a), Err(b)) => E::isclose(a, b, atol.1, rtol.1, mode),
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &Result<T, E>,
+        atol: (T::Epsilon, E::Epsilon),
+        rtol: (T::Epsilon, E::Epsilon),
+        mode: IsCloseMode,
+    ) -> Report {
+        match (self, other) {
+            (Ok(a), Ok(b)) => T::isclose_report(a, b, atol.0, rtol.0, mode),
+            (Err(a), Err(b)) => E::isclose_report(a, b, atol.1, rtol.1, mode),
+            (Ok(_), Err(_)) => Report::variant("Ok(..)", "Err(..)"),
+            (Err(_), Ok(_)) => Report::variant("Err(..)", "Ok(..)"),
+        }
+    }
+}
+
+impl<'a, T: IsCloseEq + ?Sized> IsCloseEq for &'a T {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &&'a T,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(*self, *other, atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &&'a T,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        T::isclose_report(*self, *other, atol, rtol, mode)
+    }
+}
+
+impl<'a, T: IsCloseEq + ?Sized> IsCloseEq for &'a mut T {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &&'a mut T,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(*self, *other, atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &&'a mut T,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        T::isclose_report(*self, *other, atol, rtol, mode)
+    }
+}
+
+impl<T: IsCloseEq + Copy> IsCloseEq for cell::Cell<T> {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &cell::Cell<T>,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(&self.get(), &other.get(), atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &cell::Cell<T>,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        T::isclose_report(&self.get(), &other.get(), atol, rtol, mode)
+    }
+}
+
+impl<T: IsCloseEq + ?Sized> IsCloseEq for cell::RefCell<T> {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &cell::RefCell<T>,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(&self.borrow(), &other.borrow(), atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &cell::RefCell<T>,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        T::isclose_report(&self.borrow(), &other.borrow(), atol, rtol, mode)
+    }
+}
+
+// Smart pointers and owned wrappers compare the values they point to.
+macro_rules! impl_deref_isclose_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Type:ty => $T:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> IsCloseEq for $Type
+        where
+            $T: IsCloseEq,
+        {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> Self::Epsilon {
+                <$T as IsCloseEq>::default_atol(mode)
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> Self::Epsilon {
+                <$T as IsCloseEq>::default_rtol(mode)
+            }
+
+            #[inline]
+            fn isclose(
+                &self,
+                other: &$Type,
+                atol: Self::Epsilon,
+                rtol: Self::Epsilon,
+                mode: IsCloseMode,
+            ) -> bool {
+                <$T as IsCloseEq>::isclose(&**self, &**other, atol, rtol, mode)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn isclose_report(
+                &self,
+                other: &$Type,
+                atol: Self::Epsilon,
+                rtol: Self::Epsilon,
+                mode: IsCloseMode,
+            ) -> Report {
+                <$T as IsCloseEq>::isclose_report(&**self, &**other, atol, rtol, mode)
+            }
+        }
+    )*};
+}
+
+impl_deref_isclose_eq! {
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::boxed::Box<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::rc::Rc<T> => T;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::sync::Arc<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    ['a, T: alloc::borrow::ToOwned + ?Sized] alloc::borrow::Cow<'a, T> => T;
+    [P: core::ops::Deref] core::pin::Pin<P> => P::Target;
+}
+
+// Locks compare the values they protect, see `sync::with_locked` for the poisoning and deadlocks.
+#[cfg(feature = "std")]
+macro_rules! impl_lock_isclose_eq {
+    ($($Lock:ident),*) => {$(
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl<T: IsCloseEq + ?Sized> IsCloseEq for $Lock<T> {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> T::Epsilon {
+                T::default_atol(mode)
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> T::Epsilon {
+                T::default_rtol(mode)
+            }
+
+            #[inline]
+            fn isclose(
+                &self,
+                other: &$Lock<T>,
+                atol: T::Epsilon,
+                rtol: T::Epsilon,
+                mode: IsCloseMode,
+            ) -> bool {
+                with_locked(self, other, |a, b| T::isclose(a, b, atol, rtol, mode))
+            }
+
+            fn isclose_report(
+                &self,
+                other: &$Lock<T>,
+                atol: T::Epsilon,
+                rtol: T::Epsilon,
+                mode: IsCloseMode,
+            ) -> Report {
+                with_locked(self, other, |a, b| T::isclose_report(a, b, atol, rtol, mode))
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "std")]
+impl_lock_isclose_eq!(Mutex, RwLock);
+
+// Cells which are initialized once compare like an `Option` of their value.
+macro_rules! impl_once_isclose_eq {
+    ($(#[$attr:meta])* $Once:ident) => {
+        $(#[$attr])*
+        impl<T: IsCloseEq> IsCloseEq for $Once<T> {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> T::Epsilon {
+                T::default_atol(mode)
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> T::Epsilon {
+                T::default_rtol(mode)
+            }
+
+            #[inline]
+            fn isclose(
+                &self,
+                other: &$Once<T>,
+                atol: T::Epsilon,
+                rtol: T::Epsilon,
+                mode: IsCloseMode,
+            ) -> bool {
+                Option::isclose(&self.get(), &other.get(), atol, rtol, mode)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn isclose_report(
+                &self,
+                other: &$Once<T>,
+                atol: T::Epsilon,
+                rtol: T::Epsilon,
+                mode: IsCloseMode,
+            ) -> Report {
+                Option::isclose_report(&self.get(), &other.get(), atol, rtol, mode)
+            }
+        }
+    };
+}
+
+impl_once_isclose_eq!(OnceCell);
+#[cfg(feature = "std")]
+impl_once_isclose_eq!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    OnceLock
+);
+
+impl<A, B> IsCloseEq<[B]> for [A]
+where
+    A: IsCloseEq<B>,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> A::Epsilon {
+        A::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> A::Epsilon {
+        A::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(&self, other: &[B], atol: A::Epsilon, rtol: A::Epsilon, mode: IsCloseMode) -> bool {
+        self.len() == other.len()
+            && Iterator::zip(self.iter(), other)
+                .all(|(x, y)| A::isclose(x, y, atol.clone(), rtol.clone(), mode))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &[B],
+        atol: A::Epsilon,
+        rtol: A::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::isclose_report(x, y, atol.clone(), rtol.clone(), mode)
+        })
+    }
+}
+
+#[cfg(feature = "array_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+impl<A, B, const N: usize> IsCloseEq<[B; N]> for [A; N]
+where
+    A: IsCloseEq<B>,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> A::Epsilon {
+        A::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> A::Epsilon {
+        A::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &[B; N],
+        atol: A::Epsilon,
+        rtol: A::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        <[A]>::isclose(self, other, atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &[B; N],
+        atol: A::Epsilon,
+        rtol: A::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        <[A]>::isclose_report(self, other, atol, rtol, mode)
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+impl<A, B> IsCloseEq<Vec<B>> for Vec<A>
+where
+    A: IsCloseEq<B>,
+    A::Epsilon: Clone,
+{
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> A::Epsilon {
+        A::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> A::Epsilon {
+        A::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &Vec<B>,
+        atol: A::Epsilon,
+        rtol: A::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        <[A]>::isclose(self, other, atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &Vec<B>,
+        atol: A::Epsilon,
+        rtol: A::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        <[A]>::isclose_report(self, other, atol, rtol, mode)
+    }
+}
+
+#[cfg(feature = "tuple_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tuple_impl")))]
+macro_rules! impl_isclose_eq {
+    () => {
+        impl IsCloseEq for () {
+            fn default_atol(_mode: IsCloseMode) -> Self::Epsilon {}
+
+            fn default_rtol(_mode: IsCloseMode) -> Self::Epsilon {}
+
+            fn isclose(
+                &self,
+                _other: &Self,
+                _atol: Self::Epsilon,
+                _rtol: Self::Epsilon,
+                _mode: IsCloseMode,
+            ) -> bool {
+                true
+            }
+        }
+    };
+
+    ($($idx:tt),+) => {
+        paste::paste! {
+            impl<$( [<T $idx>], )+> IsCloseEq for ($( [<T $idx>], )+)
+            where
+                $( [<T $idx>]: IsCloseEq, )+
+            {
+                fn default_atol(mode: IsCloseMode) -> Self::Epsilon {
+                    ($( [<T $idx>]::default_atol(mode), )+)
+                }
+
+                fn default_rtol(mode: IsCloseMode) -> Self::Epsilon {
+                    ($( [<T $idx>]::default_rtol(mode), )+)
+                }
+
+                fn isclose(
+                    &self,
+                    other: &Self,
+                    atol: Self::Epsilon,
+                    rtol: Self::Epsilon,
+                    mode: IsCloseMode,
+                ) -> bool {
+                    true $( && self.$idx.isclose(&other.$idx, atol.$idx, rtol.$idx, mode) )+
+                }
+
+                #[cfg(feature = "alloc")]
+                fn isclose_report(
+                    &self,
+                    other: &Self,
+                    atol: Self::Epsilon,
+                    rtol: Self::Epsilon,
+                    mode: IsCloseMode,
+                ) -> Report {
+                    let mut report = Report::new();
+                    $(
+                        report.nest(
+                            PathSegment::Tuple($idx),
+                            self.$idx.isclose_report(&other.$idx, atol.$idx, rtol.$idx, mode),
+                        );
+                    )+
+                    report
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "tuple_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tuple_impl")))]
+mod isclose_eq_tuple_impls {
+    use super::*;
+
+    impl_isclose_eq!();
+    impl_isclose_eq!(0);
+    impl_isclose_eq!(0, 1);
+    impl_isclose_eq!(0, 1, 2);
+    impl_isclose_eq!(0, 1, 2, 3);
+    impl_isclose_eq!(0, 1, 2, 3, 4);
+    impl_isclose_eq!(0, 1, 2, 3, 4, 5);
+    impl_isclose_eq!(0, 1, 2, 3, 4, 5, 6);
+    impl_isclose_eq!(0, 1, 2, 3, 4, 5, 6, 7);
+    impl_isclose_eq!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+    impl_isclose_eq!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+    impl_isclose_eq!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10);
+    impl_isclose_eq!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+}
+
+#[cfg(feature = "num-complex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-complex")))]
+impl<T: IsCloseEq> IsCloseEq for Complex<T>
+where
+    T::Epsilon: Clone,
+{
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> T::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &Complex<T>,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(&self.re, &other.re, atol.clone(), rtol.clone(), mode)
+            && T::isclose(&self.im, &other.im, atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &Complex<T>,
+        atol: T::Epsilon,
+        rtol: T::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        let mut report = Report::new();
+        report.nest(
+            PathSegment::Field("re"),
+            T::isclose_report(&self.re, &other.re, atol.clone(), rtol.clone(), mode),
+        );
+        report.nest(
+            PathSegment::Field("im"),
+            T::isclose_report(&self.im, &other.im, atol, rtol, mode),
+        );
+        report
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+impl<T: IsCloseEq + Copy> IsCloseEq for NotNan<T> {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> Self::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> Self::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &Self,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(&self.into_inner(), &other.into_inner(), atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &Self,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        T::isclose_report(&self.into_inner(), &other.into_inner(), atol, rtol, mode)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+impl<T: IsCloseEq + Float + ordered_float::FloatCore> IsCloseEq<T> for NotNan<T> {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> Self::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> Self::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &T,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(&self.into_inner(), other, atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &T,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        T::isclose_report(&self.into_inner(), other, atol, rtol, mode)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+impl<T: IsCloseEq + Float + ordered_float::FloatCore> IsCloseEq for OrderedFloat<T> {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> Self::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> Self::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &Self,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(&self.into_inner(), &other.into_inner(), atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &Self,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        T::isclose_report(&self.into_inner(), &other.into_inner(), atol, rtol, mode)
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
+impl<T: IsCloseEq + Float + ordered_float::FloatCore> IsCloseEq<T> for OrderedFloat<T> {
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> Self::Epsilon {
+        T::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> Self::Epsilon {
+        T::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &T,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        T::isclose(&self.into_inner(), other, atol, rtol, mode)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &T,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        T::isclose_report(&self.into_inner(), other, atol, rtol, mode)
+    }
+}
+
+#[cfg(feature = "indexmap_impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap_impl")))]
+impl<K, V1, V2, S1, S2> IsCloseEq<IndexMap<K, V2, S2>> for IndexMap<K, V1, S1>
+where
+    K: Hash + Eq + Debug,
+    V1: IsCloseEq<V2>,
+    V1::Epsilon: Clone,
+    S1: BuildHasher,
+    S2: BuildHasher,
+{
+    #[inline]
+    fn default_atol(mode: IsCloseMode) -> V1::Epsilon {
+        V1::default_atol(mode)
+    }
+
+    #[inline]
+    fn default_rtol(mode: IsCloseMode) -> V1::Epsilon {
+        V1::default_rtol(mode)
+    }
+
+    #[inline]
+    fn isclose(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> bool {
+        self.len() == other.len()
+            && self.iter().all(|(key, value)| {
+                other
+                    .get(key)
+                    .is_some_and(|v| V1::isclose(value, v, atol.clone(), rtol.clone(), mode))
+            })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn isclose_report(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        atol: Self::Epsilon,
+        rtol: Self::Epsilon,
+        mode: IsCloseMode,
+    ) -> Report {
+        if self.len() != other.len() {
+            return Report::length(self.len(), other.len());
+        }
+        let mut report = Report::new();
+        for (key, value) in self {
+            let nested = match other.get(key) {
+                Some(v) => V1::isclose_report(value, v, atol.clone(), rtol.clone(), mode),
+                None => Report::missing_key(),
+            };
+            report.nest(PathSegment::key(key), nested);
+        }
+        report
+    }
+}
+
+// Sequences other than slices compare element-wise like `Vec`.
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+macro_rules! impl_sequence_isclose_eq {
+    ($($(#[$attr:meta])* $Seq:ident;)*) => {$(
+        $(#[$attr])*
+        impl<A, B> IsCloseEq<$Seq<B>> for $Seq<A>
+        where
+            A: IsCloseEq<B>,
+            A::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> A::Epsilon {
+                A::default_atol(mode)
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> A::Epsilon {
+                A::default_rtol(mode)
+            }
+
+            #[inline]
+            fn isclose(
+                &self,
+                other: &$Seq<B>,
+                atol: A::Epsilon,
+                rtol: A::Epsilon,
+                mode: IsCloseMode,
+            ) -> bool {
+                self.len() == other.len()
+                    && Iterator::zip(self.iter(), other)
+                        .all(|(x, y)| A::isclose(x, y, atol.clone(), rtol.clone(), mode))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn isclose_report(
+                &self,
+                other: &$Seq<B>,
+                atol: A::Epsilon,
+                rtol: A::Epsilon,
+                mode: IsCloseMode,
+            ) -> Report {
+                Report::sequence(self, other, |x, y| {
+                    A::isclose_report(x, y, atol.clone(), rtol.clone(), mode)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+impl_sequence_isclose_eq! {
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    VecDeque;
+    #[cfg(feature = "linked_list_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "linked_list_impl")))]
+    LinkedList;
+}
+
+// Maps compare the values of the same keys like `IndexMap`, and locate mismatches by key.
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+macro_rules! impl_map_isclose_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*, V1, V2> IsCloseEq<$Right> for $Left
+        where
+            V1: IsCloseEq<V2>,
+            V1::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> V1::Epsilon {
+                V1::default_atol(mode)
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> V1::Epsilon {
+                V1::default_rtol(mode)
+            }
+
+            #[inline]
+            fn isclose(
+                &self,
+                other: &$Right,
+                atol: Self::Epsilon,
+                rtol: Self::Epsilon,
+                mode: IsCloseMode,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, value)| {
+                        other.get(key).is_some_and(|v| {
+                            V1::isclose(value, v, atol.clone(), rtol.clone(), mode)
+                        })
+                    })
+            }
+
+            #[cfg(feature = "alloc")]
+            fn isclose_report(
+                &self,
+                other: &$Right,
+                atol: Self::Epsilon,
+                rtol: Self::Epsilon,
+                mode: IsCloseMode,
+            ) -> Report {
+                if self.len() != other.len() {
+                    return Report::length(self.len(), other.len());
+                }
+                let mut report = Report::new();
+                for (key, value) in self {
+                    let nested = match other.get(key) {
+                        Some(v) => V1::isclose_report(value, v, atol.clone(), rtol.clone(), mode),
+                        None => Report::missing_key(),
+                    };
+                    report.nest(PathSegment::key(key), nested);
+                }
+                report
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+impl_map_isclose_eq! {
+    #[cfg(feature = "btree_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "btree_map_impl")))]
+    [K: Ord + Debug] BTreeMap<K, V1> => BTreeMap<K, V2>;
+    #[cfg(feature = "hash_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hash_map_impl")))]
+    [K: Hash + Eq + Debug, S1: BuildHasher, S2: BuildHasher] HashMap<K, V1, S1> => HashMap<K, V2, S2>;
+}
+
+// Different kinds of sequences compare element-wise, so that eg. a `Vec` can be compared with an
+// array literal.
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+macro_rules! impl_cross_isclose_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> IsCloseEq<$Right> for $Left
+        where
+            A: IsCloseEq<B>,
+            A::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_atol(mode: IsCloseMode) -> A::Epsilon {
+                A::default_atol(mode)
+            }
+
+            #[inline]
+            fn default_rtol(mode: IsCloseMode) -> A::Epsilon {
+                A::default_rtol(mode)
+            }
+
+            #[inline]
+            fn isclose(
+                &self,
+                other: &$Right,
+                atol: A::Epsilon,
+                rtol: A::Epsilon,
+                mode: IsCloseMode,
+            ) -> bool {
+                self.len() == other.len()
+                    && Iterator::zip(self.iter(), other.iter())
+                        .all(|(x, y)| A::isclose(x, y, atol.clone(), rtol.clone(), mode))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn isclose_report(
+                &self,
+                other: &$Right,
+                atol: A::Epsilon,
+                rtol: A::Epsilon,
+                mode: IsCloseMode,
+            ) -> Report {
+                Report::sequence(self.iter(), other.iter(), |x, y| {
+                    A::isclose_report(x, y, atol.clone(), rtol.clone(), mode)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+impl_cross_isclose_eq! {
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] Vec<A> => [B; N];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] [A; N] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] Vec<A> => [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] [A] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] Vec<A> => &'a [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] &'a [A] => Vec<B>;
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A; N] => [B];
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A] => [B; N];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] VecDeque<A> => [B];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] [A] => VecDeque<B>;
+}
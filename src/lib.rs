@@ -49,6 +49,104 @@
 //!
 //! See also the [`abs_diff_eq!`](AbsDiffEq::abs_diff_eq), [`relative_eq!`](RelativeEq::relative_eq) and [`ulps_eq!`](UlpsEq::ulps_eq) macros, which return [`bool`] instead of [`assert`]ing.
 //!
+//! Like [`assert_eq!`], the assertions accept a custom panic message after the options:
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approxim;
+//!
+//! # fn main() {
+//! for p in [1.0, 2.0, 3.0] {
+//!     assert_relative_eq!(p * 0.1, p / 10.0, max_relative = 1e-15, "p = {}", p);
+//! }
+//! # }
+//! ```
+//!
+//! The `check_*!` macros compare the same way, but return an [`ApproxError`] describing the
+//! failure instead of panicking, so that it can be propagated with `?`:
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approxim;
+//!
+//! fn check(x: f64) -> Result<(), approxim::ApproxError> {
+//!     check_relative_eq!(x.sqrt() * x.sqrt(), x, max_relative = 1e-12)?;
+//!     check_ulps_ne!(x, x + 1.0)
+//! }
+//!
+//! # fn main() {
+//! assert!(check(2.0).is_ok());
+//! # }
+//! ```
+//!
+//! Tolerances ported from NumPy or Python keep their meaning with the [`isclose!`] and
+//! [`math_isclose!`] macros, which implement the formulas of `numpy.isclose` and `math.isclose`
+//! (see [`IsCloseEq`]):
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approxim;
+//!
+//! # fn main() {
+//! assert_isclose!(1.0, 1.0 + 1e-6);                     // ✅ |a - b| <= atol + rtol * |b|
+//! assert_isclose!(1.0, 1.1, rtol = 0.1);                // ✅ 0.1 <= 1e-8 + 0.1 * 1.1
+//! assert_not_isclose!(1.1, 1.0, rtol = 0.1, atol = 0.0); // ❌ 0.1 > 0.1 * 1.0, order matters
+//! assert_math_isclose!(1.1, 1.0, rel_tol = 0.1);        // ✅ symmetric: 0.1 <= 0.1 * max(1.1, 1.0)
+//! # }
+//! ```
+//!
+//! The [`approx_eq!`] macros combine the absolute difference, relative and ULPs criteria, and
+//! pass if any of them passes, or only if all of them pass (see [`Combine`]):
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approxim;
+//!
+//! use approxim::Combine;
+//!
+//! # fn main() {
+//! assert_approx_eq!(1.0, 1.0 + 4e-16);                            // ✅ 2 ULPs ≤ default (4)
+//! assert_approx_eq!(1.0, 1.1, max_relative = 0.1, max_ulps = 0);  // ✅ 0.1/1.1 < 0.1
+//! assert_approx_ne!(1.0, 1.0 + 4e-16, combine = Combine::All);    // ❌ 2ε > default epsilon
+//! # }
+//! ```
+//!
+//! APIs which require [`PartialEq`], such as `assert_eq!` or `contains`, can compare values
+//! approximately when they are wrapped in [`Approx`] together with their tolerance:
+//!
+//! ```rust
+//! use approxim::Approx;
+//!
+//! assert_eq!(Approx::relative(0.1 + 0.2).max_relative(1e-9), 0.3);
+//! ```
+//!
+//! When an assertion fails on a collection, the panic message points out the first mismatching
+//! element along with the differences and tolerances which were compared:
+//!
+//! ```text
+//! assert_relative_eq!(a, b)
+//!
+//!     left  = [(1.0, Complex { re: 1.0, im: 2.0 }), (2.0, Complex { re: 1.0, im: 2.0 })]
+//!     right = [(1.0, Complex { re: 1.0, im: 2.0 }), (2.0, Complex { re: 1.0, im: 2.5 })]
+//!
+//!     first of 1 mismatch(es):
+//!     values differ at [1].1.im
+//!         left         = 2.0
+//!         right        = 2.5
+//!         abs_diff     = 0.5
+//!         rel_diff     = 0.2
+//!         ulps         = 1125899906842624
+//!         epsilon      = 2.220446049250313e-16
+//!         max_relative = 2.220446049250313e-16
+//! ```
+//!
+//! The same information is available programmatically through [`Report`] (requires the `alloc`
+//! feature).
+//!
+//! The default tolerances of `f32` and `f64` can be overridden for the duration of a closure with
+//! [`with_defaults`] (requires the `std` feature), eg. for a whole test which needs looser
+//! tolerances than the built-in ones.
+//!
 //! # Implementing approximate equality for custom types
 //!
 //! The `*Eq` traits allow approximimate equalities to be implemented on types, based on the
@@ -161,9 +259,12 @@
 //!   https://docs.oracle.com/cd/E19957-01/806-3568/ncg_goldberg.html)
 
 #![no_std]
-#![allow(clippy::transmute_float_to_int)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(approxim_nightly_float, feature(f16, f128))]
 
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+extern crate half;
 #[cfg(feature = "num-complex")]
 #[cfg_attr(docsrs, doc(cfg(feature = "num-complex")))]
 extern crate num_complex;
@@ -171,19 +272,72 @@ extern crate num_traits;
 #[cfg(feature = "ordered-float")]
 #[cfg_attr(docsrs, doc(cfg(feature = "ordered-float")))]
 extern crate ordered_float;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+extern crate serde;
 
-#[cfg(any(feature = "vec_impl", feature = "indexmap_impl"))]
+#[cfg(any(feature = "alloc", feature = "vec_impl", feature = "indexmap_impl"))]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+/// The default tolerance `$field` of `$T`, unless it is overridden with `with_defaults`.
+macro_rules! scoped_default {
+    ($T:ident, $field:ident, $default:expr) => {{
+        #[cfg(feature = "std")]
+        let default = crate::defaults::current::<$T>().$field.unwrap_or($default);
+        #[cfg(not(feature = "std"))]
+        let default = $default;
+        default
+    }};
+}
 
 mod abs_diff_eq;
+#[cfg(feature = "std")]
+mod checks;
+mod comparator;
+#[cfg(feature = "std")]
+mod defaults;
+mod distance;
+mod error;
+mod is_close;
+mod mixed;
+mod policy;
 mod relative_eq;
+#[cfg(feature = "alloc")]
+mod report;
+#[cfg(feature = "std")]
+mod sync;
+mod tolerance;
 mod ulps_eq;
+mod wrapper;
 
 mod macros;
 
+use core::fmt;
+
 pub use abs_diff_eq::AbsDiffEq;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use checks::{Checks, Failure};
+#[doc(hidden)]
+pub use comparator::__details as __comparator_details;
+pub use comparator::{from_fn, And, Comparator, FromFn, Not, Or};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use defaults::{with_defaults, Defaults, ScopedDefaults};
+pub use distance::{abs_diff, relative_diff, ulps_distance, Distance};
+pub use error::ApproxError;
+pub use is_close::{IsCloseEq, IsCloseMode};
+pub use mixed::Mixed;
+pub use policy::{NanPolicy, Policy, UlpsMode};
 pub use relative_eq::RelativeEq;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use report::{Mismatch, MismatchKind, PathSegment, Report};
+pub use tolerance::Tolerance;
 pub use ulps_eq::UlpsEq;
+pub use wrapper::Approx;
 
 /// The requisite parameters for testing for approximimate equality using a
 /// absolute difference based comparison.
@@ -199,6 +353,7 @@ pub use ulps_eq::UlpsEq;
 ///
 /// AbsDiff::default().eq(&1.0, &1.0);
 /// AbsDiff::default().epsilon(f64::EPSILON).eq(&1.0, &1.0);
+/// AbsDiff::default().nan_equal(true).eq(&f64::NAN, &f64::NAN);
 /// ```
 pub struct AbsDiff<A, B = A>
 where
@@ -207,6 +362,7 @@ where
 {
     /// The tolerance to use when testing values that are close together.
     pub epsilon: A::Epsilon,
+    policy: Policy,
 }
 
 impl<A, B> Default for AbsDiff<A, B>
@@ -218,6 +374,7 @@ where
     fn default() -> AbsDiff<A, B> {
         AbsDiff {
             epsilon: A::default_epsilon(),
+            policy: Policy::default(),
         }
     }
 }
@@ -230,21 +387,55 @@ where
     /// Replace the epsilon value with the one specified.
     #[inline]
     pub fn epsilon(self, epsilon: A::Epsilon) -> AbsDiff<A, B> {
-        AbsDiff { epsilon }
+        AbsDiff { epsilon, ..self }
+    }
+
+    /// Replace the policy for special values such as NaN with the one specified.
+    #[inline]
+    pub fn policy(self, policy: Policy) -> AbsDiff<A, B> {
+        AbsDiff { policy, ..self }
+    }
+
+    /// Replace the NaN policy with the one specified.
+    ///
+    /// Passing `true` makes NaN equal to any other NaN, see [`NanPolicy`].
+    #[inline]
+    pub fn nan_equal(mut self, nan_equal: impl Into<NanPolicy>) -> AbsDiff<A, B> {
+        self.policy.nan = nan_equal.into();
+        self
     }
 
     /// Perform the equality comparison
     #[inline]
     #[must_use]
     pub fn eq(self, lhs: &A, rhs: &B) -> bool {
-        A::abs_diff_eq(lhs, rhs, self.epsilon)
+        A::abs_diff_eq_with(lhs, rhs, self.epsilon, self.policy)
     }
 
     /// Perform the inequality comparison
     #[inline]
     #[must_use]
     pub fn ne(self, lhs: &A, rhs: &B) -> bool {
-        A::abs_diff_ne(lhs, rhs, self.epsilon)
+        A::abs_diff_ne_with(lhs, rhs, self.epsilon, self.policy)
+    }
+
+    /// Perform the equality comparison, reporting every position at which the values differ
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn report(self, lhs: &A, rhs: &B) -> Report {
+        A::abs_diff_report_with(lhs, rhs, self.epsilon, self.policy)
+    }
+
+    #[doc(hidden)]
+    pub fn __details(self, lhs: &A, rhs: &B) -> impl fmt::Display {
+        #[cfg(feature = "alloc")]
+        return report::Details(self.report(lhs, rhs));
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = (lhs, rhs);
+            ""
+        }
     }
 }
 
@@ -265,6 +456,7 @@ where
 /// Relative::default().max_relative(1.0).eq(&1.0, &1.0);
 /// Relative::default().epsilon(f64::EPSILON).max_relative(1.0).eq(&1.0, &1.0);
 /// Relative::default().max_relative(1.0).epsilon(f64::EPSILON).eq(&1.0, &1.0);
+/// Relative::default().nan_equal(true).eq(&f64::NAN, &f64::NAN);
 /// ```
 pub struct Relative<A, B = A>
 where
@@ -275,6 +467,7 @@ where
     pub epsilon: A::Epsilon,
     /// The relative tolerance for testing values that are far-apart.
     pub max_relative: A::Epsilon,
+    policy: Policy,
 }
 
 impl<A, B> Default for Relative<A, B>
@@ -287,6 +480,7 @@ where
         Relative {
             epsilon: A::default_epsilon(),
             max_relative: A::default_max_relative(),
+            policy: Policy::default(),
         }
     }
 }
@@ -311,18 +505,52 @@ where
         }
     }
 
+    /// Replace the policy for special values such as NaN with the one specified.
+    #[inline]
+    pub fn policy(self, policy: Policy) -> Relative<A, B> {
+        Relative { policy, ..self }
+    }
+
+    /// Replace the NaN policy with the one specified.
+    ///
+    /// Passing `true` makes NaN equal to any other NaN, see [`NanPolicy`].
+    #[inline]
+    pub fn nan_equal(mut self, nan_equal: impl Into<NanPolicy>) -> Relative<A, B> {
+        self.policy.nan = nan_equal.into();
+        self
+    }
+
     /// Perform the equality comparison
     #[inline]
     #[must_use]
     pub fn eq(self, lhs: &A, rhs: &B) -> bool {
-        A::relative_eq(lhs, rhs, self.epsilon, self.max_relative)
+        A::relative_eq_with(lhs, rhs, self.epsilon, self.max_relative, self.policy)
     }
 
     /// Perform the inequality comparison
     #[inline]
     #[must_use]
     pub fn ne(self, lhs: &A, rhs: &B) -> bool {
-        A::relative_ne(lhs, rhs, self.epsilon, self.max_relative)
+        A::relative_ne_with(lhs, rhs, self.epsilon, self.max_relative, self.policy)
+    }
+
+    /// Perform the equality comparison, reporting every position at which the values differ
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn report(self, lhs: &A, rhs: &B) -> Report {
+        A::relative_report_with(lhs, rhs, self.epsilon, self.max_relative, self.policy)
+    }
+
+    #[doc(hidden)]
+    pub fn __details(self, lhs: &A, rhs: &B) -> impl fmt::Display {
+        #[cfg(feature = "alloc")]
+        return report::Details(self.report(lhs, rhs));
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = (lhs, rhs);
+            ""
+        }
     }
 }
 
@@ -336,13 +564,15 @@ where
 ///
 /// ```rust
 /// use std::f64;
-/// use approxim::Ulps;
+/// use approxim::{Ulps, UlpsMode};
 ///
 /// Ulps::default().eq(&1.0, &1.0);
 /// Ulps::default().epsilon(f64::EPSILON).eq(&1.0, &1.0);
 /// Ulps::default().max_ulps(4).eq(&1.0, &1.0);
 /// Ulps::default().epsilon(f64::EPSILON).max_ulps(4).eq(&1.0, &1.0);
 /// Ulps::default().max_ulps(4).epsilon(f64::EPSILON).eq(&1.0, &1.0);
+/// Ulps::default().nan_equal(true).eq(&f64::NAN, &f64::NAN);
+/// Ulps::default().ulps_mode(UlpsMode::Monotonic).eq(&-1e-300, &1e-300);
 /// ```
 pub struct Ulps<A, B = A>
 where
@@ -353,6 +583,7 @@ where
     pub epsilon: A::Epsilon,
     /// The ULPs to tolerate when testing values that are far-apart.
     pub max_ulps: u32,
+    policy: Policy,
 }
 
 impl<A, B> Default for Ulps<A, B>
@@ -365,6 +596,7 @@ where
         Ulps {
             epsilon: A::default_epsilon(),
             max_ulps: A::default_max_ulps(),
+            policy: Policy::default(),
         }
     }
 }
@@ -386,21 +618,626 @@ where
         Ulps { max_ulps, ..self }
     }
 
+    /// Replace the way the distance in ULPs is measured with the one specified.
+    #[inline]
+    pub fn ulps_mode(mut self, ulps_mode: UlpsMode) -> Ulps<A, B> {
+        self.policy.ulps = ulps_mode;
+        self
+    }
+
+    /// Specify whether `+0.0` and `-0.0` are distinct values, see [`Policy::distinct_zeros`].
+    #[inline]
+    pub fn distinct_zeros(mut self, distinct_zeros: bool) -> Ulps<A, B> {
+        self.policy.distinct_zeros = distinct_zeros;
+        self
+    }
+
+    /// Replace the policy for special values such as NaN with the one specified.
+    #[inline]
+    pub fn policy(self, policy: Policy) -> Ulps<A, B> {
+        Ulps { policy, ..self }
+    }
+
+    /// Replace the NaN policy with the one specified.
+    ///
+    /// Passing `true` makes NaN equal to any other NaN, see [`NanPolicy`].
+    #[inline]
+    pub fn nan_equal(mut self, nan_equal: impl Into<NanPolicy>) -> Ulps<A, B> {
+        self.policy.nan = nan_equal.into();
+        self
+    }
+
     /// Perform the equality comparison
     #[inline]
     #[must_use]
     pub fn eq(self, lhs: &A, rhs: &B) -> bool {
-        A::ulps_eq(lhs, rhs, self.epsilon, self.max_ulps)
+        A::ulps_eq_with(lhs, rhs, self.epsilon, self.max_ulps, self.policy)
     }
 
     /// Perform the inequality comparison
     #[inline]
     #[must_use]
     pub fn ne(self, lhs: &A, rhs: &B) -> bool {
-        A::ulps_ne(lhs, rhs, self.epsilon, self.max_ulps)
+        A::ulps_ne_with(lhs, rhs, self.epsilon, self.max_ulps, self.policy)
+    }
+
+    /// Perform the equality comparison, reporting every position at which the values differ
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn report(self, lhs: &A, rhs: &B) -> Report {
+        A::ulps_report_with(lhs, rhs, self.epsilon, self.max_ulps, self.policy)
+    }
+
+    #[doc(hidden)]
+    pub fn __details(self, lhs: &A, rhs: &B) -> impl fmt::Display {
+        #[cfg(feature = "alloc")]
+        return report::Details(self.report(lhs, rhs));
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = (lhs, rhs);
+            ""
+        }
     }
 }
 
+/// The requisite parameters for testing for approximimate equality using the formula of
+/// `numpy.isclose`, see [`IsCloseMode::NumPy`].
+///
+/// This is not normally used directly, rather via the
+/// `assert_isclose!`, `assert_not_isclose!`, `isclose!` and `not_isclose!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approxim::IsClose;
+///
+/// IsClose::default().eq(&1.0, &1.0);
+/// IsClose::default().atol(1e-8).eq(&1.0, &1.0);
+/// IsClose::default().rtol(1e-5).eq(&1.0, &1.0);
+/// IsClose::default().atol(0.0).rtol(1e-7).eq(&1.0, &1.0);
+/// ```
+pub struct IsClose<A, B = A>
+where
+    A: IsCloseEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// The absolute tolerance.
+    pub atol: A::Epsilon,
+    /// The relative tolerance, scaled by the magnitude of the right-hand value.
+    pub rtol: A::Epsilon,
+}
+
+impl<A, B> Default for IsClose<A, B>
+where
+    A: IsCloseEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn default() -> IsClose<A, B> {
+        IsClose {
+            atol: A::default_atol(IsCloseMode::NumPy),
+            rtol: A::default_rtol(IsCloseMode::NumPy),
+        }
+    }
+}
+
+impl<A, B> IsClose<A, B>
+where
+    A: IsCloseEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Replace the absolute tolerance with the one specified.
+    #[inline]
+    pub fn atol(self, atol: A::Epsilon) -> IsClose<A, B> {
+        IsClose { atol, ..self }
+    }
+
+    /// Replace the relative tolerance with the one specified.
+    #[inline]
+    pub fn rtol(self, rtol: A::Epsilon) -> IsClose<A, B> {
+        IsClose { rtol, ..self }
+    }
+
+    /// Perform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &A, rhs: &B) -> bool {
+        A::isclose(lhs, rhs, self.atol, self.rtol, IsCloseMode::NumPy)
+    }
+
+    /// Perform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &A, rhs: &B) -> bool {
+        A::not_isclose(lhs, rhs, self.atol, self.rtol, IsCloseMode::NumPy)
+    }
+
+    /// Perform the equality comparison, reporting every position at which the values differ
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn report(self, lhs: &A, rhs: &B) -> Report {
+        A::isclose_report(lhs, rhs, self.atol, self.rtol, IsCloseMode::NumPy)
+    }
+
+    #[doc(hidden)]
+    pub fn __details(self, lhs: &A, rhs: &B) -> impl fmt::Display {
+        #[cfg(feature = "alloc")]
+        return report::Details(self.report(lhs, rhs));
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = (lhs, rhs);
+            ""
+        }
+    }
+}
+
+/// The requisite parameters for testing for approximimate equality using the formula of
+/// `math.isclose`, see [`IsCloseMode::Math`].
+///
+/// This is not normally used directly, rather via the `assert_math_isclose!`,
+/// `assert_not_math_isclose!`, `math_isclose!` and `not_math_isclose!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approxim::MathIsClose;
+///
+/// MathIsClose::default().eq(&1.0, &1.0);
+/// MathIsClose::default().rel_tol(1e-9).eq(&1.0, &1.0);
+/// MathIsClose::default().abs_tol(1e-12).eq(&1.0, &1.0);
+/// MathIsClose::default().rel_tol(1e-9).abs_tol(1e-12).eq(&1.0, &1.0);
+/// ```
+pub struct MathIsClose<A, B = A>
+where
+    A: IsCloseEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// The relative tolerance, scaled by the larger magnitude of both values.
+    pub rel_tol: A::Epsilon,
+    /// The absolute tolerance.
+    pub abs_tol: A::Epsilon,
+}
+
+impl<A, B> Default for MathIsClose<A, B>
+where
+    A: IsCloseEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn default() -> MathIsClose<A, B> {
+        MathIsClose {
+            rel_tol: A::default_rtol(IsCloseMode::Math),
+            abs_tol: A::default_atol(IsCloseMode::Math),
+        }
+    }
+}
+
+impl<A, B> MathIsClose<A, B>
+where
+    A: IsCloseEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Replace the relative tolerance with the one specified.
+    #[inline]
+    pub fn rel_tol(self, rel_tol: A::Epsilon) -> MathIsClose<A, B> {
+        MathIsClose { rel_tol, ..self }
+    }
+
+    /// Replace the absolute tolerance with the one specified.
+    #[inline]
+    pub fn abs_tol(self, abs_tol: A::Epsilon) -> MathIsClose<A, B> {
+        MathIsClose { abs_tol, ..self }
+    }
+
+    /// Perform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &A, rhs: &B) -> bool {
+        A::isclose(lhs, rhs, self.abs_tol, self.rel_tol, IsCloseMode::Math)
+    }
+
+    /// Perform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &A, rhs: &B) -> bool {
+        A::not_isclose(lhs, rhs, self.abs_tol, self.rel_tol, IsCloseMode::Math)
+    }
+
+    /// Perform the equality comparison, reporting every position at which the values differ
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    pub fn report(self, lhs: &A, rhs: &B) -> Report {
+        A::isclose_report(lhs, rhs, self.abs_tol, self.rel_tol, IsCloseMode::Math)
+    }
+
+    #[doc(hidden)]
+    pub fn __details(self, lhs: &A, rhs: &B) -> impl fmt::Display {
+        #[cfg(feature = "alloc")]
+        return report::Details(self.report(lhs, rhs));
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = (lhs, rhs);
+            ""
+        }
+    }
+}
+
+/// How the criteria of a [`Combined`] comparison are combined.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Combine {
+    /// The values are equal if any of the absolute difference, relative or ULPs criteria passes.
+    #[default]
+    Any,
+    /// The values are equal only if all of the absolute difference, relative and ULPs criteria
+    /// pass.
+    ///
+    /// The relative and ULPs criteria are then tested with an `epsilon` of
+    /// [`Default::default()`], ie. zero for numbers, so that they do not pass on the absolute
+    /// difference alone.
+    All,
+}
+
+/// The requisite parameters for testing for approximimate equality using a combination of the
+/// absolute difference, relative and ULPs based comparisons.
+///
+/// This is not normally used directly, rather via the `assert_approx_{eq|ne}!` and
+/// `approx_{eq|ne}!` macros.
+///
+/// # Example
+///
+/// ```rust
+/// use approxim::{Combine, Combined};
+///
+/// Combined::default().eq(&1.0, &1.0);
+/// Combined::default().epsilon(1e-12).max_relative(1e-9).max_ulps(4).eq(&1.0, &1.0);
+/// Combined::default().max_ulps(0).eq(&1.0, &(1.0 + 1e-16));
+/// Combined::default().combine(Combine::All).max_ulps(4).eq(&1.0, &1.0);
+/// Combined::default().nan_equal(true).eq(&f64::NAN, &f64::NAN);
+/// ```
+pub struct Combined<A, B = A>
+where
+    A: RelativeEq<B> + UlpsEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// The tolerance to use when testing values that are close together.
+    pub epsilon: A::Epsilon,
+    /// The relative tolerance for testing values that are far-apart.
+    pub max_relative: A::Epsilon,
+    /// The ULPs to tolerate when testing values that are far-apart.
+    pub max_ulps: u32,
+    /// Whether any or all of the criteria must pass.
+    pub combine: Combine,
+    policy: Policy,
+}
+
+impl<A, B> Default for Combined<A, B>
+where
+    A: RelativeEq<B> + UlpsEq<B> + ?Sized,
+    B: ?Sized,
+{
+    #[inline]
+    fn default() -> Combined<A, B> {
+        Combined {
+            epsilon: A::default_epsilon(),
+            max_relative: A::default_max_relative(),
+            max_ulps: A::default_max_ulps(),
+            combine: Combine::default(),
+            policy: Policy::default(),
+        }
+    }
+}
+
+impl<A, B> Combined<A, B>
+where
+    A: RelativeEq<B> + UlpsEq<B> + ?Sized,
+    B: ?Sized,
+{
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: A::Epsilon) -> Combined<A, B> {
+        Combined { epsilon, ..self }
+    }
+
+    /// Replace the maximum relative value with the one specified.
+    #[inline]
+    pub fn max_relative(self, max_relative: A::Epsilon) -> Combined<A, B> {
+        Combined {
+            max_relative,
+            ..self
+        }
+    }
+
+    /// Replace the max ulps value with the one specified.
+    #[inline]
+    pub fn max_ulps(self, max_ulps: u32) -> Combined<A, B> {
+        Combined { max_ulps, ..self }
+    }
+
+    /// Replace the way the criteria are combined with the one specified.
+    #[inline]
+    pub fn combine(self, combine: Combine) -> Combined<A, B> {
+        Combined { combine, ..self }
+    }
+
+    /// Replace the way the distance in ULPs is measured with the one specified.
+    #[inline]
+    pub fn ulps_mode(mut self, ulps_mode: UlpsMode) -> Combined<A, B> {
+        self.policy.ulps = ulps_mode;
+        self
+    }
+
+    /// Specify whether `+0.0` and `-0.0` are distinct values, see [`Policy::distinct_zeros`].
+    #[inline]
+    pub fn distinct_zeros(mut self, distinct_zeros: bool) -> Combined<A, B> {
+        self.policy.distinct_zeros = distinct_zeros;
+        self
+    }
+
+    /// Replace the policy for special values such as NaN with the one specified.
+    #[inline]
+    pub fn policy(self, policy: Policy) -> Combined<A, B> {
+        Combined { policy, ..self }
+    }
+
+    /// Replace the NaN policy with the one specified.
+    ///
+    /// Passing `true` makes NaN equal to any other NaN, see [`NanPolicy`].
+    #[inline]
+    pub fn nan_equal(mut self, nan_equal: impl Into<NanPolicy>) -> Combined<A, B> {
+        self.policy.nan = nan_equal.into();
+        self
+    }
+}
+
+impl<A, B> Combined<A, B>
+where
+    A: RelativeEq<B> + UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    A::Epsilon: Clone + Default,
+{
+    /// Perform the equality comparison
+    #[inline]
+    #[must_use]
+    pub fn eq(self, lhs: &A, rhs: &B) -> bool {
+        let Combined {
+            epsilon,
+            max_relative,
+            max_ulps,
+            combine,
+            policy,
+        } = self;
+        match combine {
+            Combine::Any => {
+                A::relative_eq_with(lhs, rhs, epsilon.clone(), max_relative, policy)
+                    || A::ulps_eq_with(lhs, rhs, epsilon, max_ulps, policy)
+            }
+            Combine::All => {
+                A::abs_diff_eq_with(lhs, rhs, epsilon, policy)
+                    && A::relative_eq_with(lhs, rhs, Default::default(), max_relative, policy)
+                    && A::ulps_eq_with(lhs, rhs, Default::default(), max_ulps, policy)
+            }
+        }
+    }
+
+    /// Perform the inequality comparison
+    #[inline]
+    #[must_use]
+    pub fn ne(self, lhs: &A, rhs: &B) -> bool {
+        let Combined {
+            epsilon,
+            max_relative,
+            max_ulps,
+            combine,
+            policy,
+        } = self;
+        match combine {
+            Combine::Any => {
+                A::relative_ne_with(lhs, rhs, epsilon.clone(), max_relative, policy)
+                    && A::ulps_ne_with(lhs, rhs, epsilon, max_ulps, policy)
+            }
+            Combine::All => {
+                A::abs_diff_ne_with(lhs, rhs, epsilon, policy)
+                    || A::relative_ne_with(lhs, rhs, Default::default(), max_relative, policy)
+                    || A::ulps_ne_with(lhs, rhs, Default::default(), max_ulps, policy)
+            }
+        }
+    }
+
+    /// Perform the equality comparison, reporting every position at which the values differ
+    ///
+    /// When the comparison fails, this is the report of the relative criterion with
+    /// [`Combine::Any`], or of the first failing criterion with [`Combine::All`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn report(self, lhs: &A, rhs: &B) -> Report {
+        let Combined {
+            epsilon,
+            max_relative,
+            max_ulps,
+            combine,
+            policy,
+        } = self;
+        match combine {
+            Combine::Any => {
+                let report =
+                    A::relative_report_with(lhs, rhs, epsilon.clone(), max_relative, policy);
+                if report.is_eq() || A::ulps_eq_with(lhs, rhs, epsilon, max_ulps, policy) {
+                    Report::new()
+                } else {
+                    report
+                }
+            }
+            Combine::All => {
+                let report = A::abs_diff_report_with(lhs, rhs, epsilon, policy);
+                if !report.is_eq() {
+                    return report;
+                }
+                let report =
+                    A::relative_report_with(lhs, rhs, Default::default(), max_relative, policy);
+                if !report.is_eq() {
+                    return report;
+                }
+                A::ulps_report_with(lhs, rhs, Default::default(), max_ulps, policy)
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn __details(self, lhs: &A, rhs: &B) -> impl fmt::Display {
+        #[cfg(feature = "alloc")]
+        return report::Details(self.report(lhs, rhs));
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = (lhs, rhs);
+            ""
+        }
+    }
+}
+
+// The builders only store tolerances, so they can be copied and printed whenever the tolerances
+// can, regardless of the compared types.
+macro_rules! impl_builder_traits {
+    ($Builder:ident, $Trait:ident $(+ $Traits:ident)*, $($field:ident),+) => {
+        impl<A, B> Clone for $Builder<A, B>
+        where
+            A: $Trait<B> $(+ $Traits<B>)* + ?Sized,
+            B: ?Sized,
+            A::Epsilon: Clone,
+        {
+            #[inline]
+            fn clone(&self) -> $Builder<A, B> {
+                $Builder {
+                    $($field: Clone::clone(&self.$field),)+
+                }
+            }
+        }
+
+        impl<A, B> Copy for $Builder<A, B>
+        where
+            A: $Trait<B> $(+ $Traits<B>)* + ?Sized,
+            B: ?Sized,
+            A::Epsilon: Copy,
+        {
+        }
+
+        impl<A, B> fmt::Debug for $Builder<A, B>
+        where
+            A: $Trait<B> $(+ $Traits<B>)* + ?Sized,
+            B: ?Sized,
+            A::Epsilon: fmt::Debug,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($Builder))
+                    $(.field(stringify!($field), &self.$field))+
+                    .finish()
+            }
+        }
+    };
+}
+
+macro_rules! impl_builder_check {
+    (
+        $Builder:ident, $Trait:ident $(+ $Traits:ident)*, $eq:literal, $ne:literal
+        $(, $Bound:path)*
+    ) => {
+        impl<A, B> $Builder<A, B>
+        where
+            A: $Trait<B> $(+ $Traits<B>)* + fmt::Debug + ?Sized,
+            B: fmt::Debug + ?Sized,
+            A::Epsilon: Clone + fmt::Debug $(+ $Bound)*,
+        {
+            /// Perform the equality comparison, returning an error which describes the values and
+            /// the tolerances if they differ
+            pub fn check(self, lhs: &A, rhs: &B) -> Result<(), ApproxError> {
+                if self.clone().eq(lhs, rhs) {
+                    return Ok(());
+                }
+                #[allow(unused_mut)]
+                let mut error = ApproxError::new($eq, lhs, rhs, &self);
+                #[cfg(feature = "alloc")]
+                {
+                    error.values.report = self.report(lhs, rhs);
+                }
+                Err(error)
+            }
+
+            /// Perform the inequality comparison, returning an error which describes the values
+            /// and the tolerances if they are equal
+            pub fn check_ne(self, lhs: &A, rhs: &B) -> Result<(), ApproxError> {
+                if self.clone().ne(lhs, rhs) {
+                    return Ok(());
+                }
+                Err(ApproxError::new($ne, lhs, rhs, &self))
+            }
+        }
+    };
+}
+
+// The builders compare with their tolerances as comparators, cloning them for every comparison.
+macro_rules! impl_builder_comparator {
+    ($Builder:ident, $Trait:ident $(+ $Traits:ident)* $(, $Bound:path)*) => {
+        impl<A, B> Comparator<A, B> for $Builder<A, B>
+        where
+            A: $Trait<B> $(+ $Traits<B>)* + ?Sized,
+            B: ?Sized,
+            A::Epsilon: Clone $(+ $Bound)*,
+        {
+            #[inline]
+            fn eq(&self, lhs: &A, rhs: &B) -> bool {
+                self.clone().eq(lhs, rhs)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn report(&self, lhs: &A, rhs: &B) -> Report {
+                self.clone().report(lhs, rhs)
+            }
+        }
+    };
+}
+
+impl_builder_traits!(AbsDiff, AbsDiffEq, epsilon, policy);
+impl_builder_traits!(Relative, RelativeEq, epsilon, max_relative, policy);
+impl_builder_traits!(Ulps, UlpsEq, epsilon, max_ulps, policy);
+impl_builder_traits!(IsClose, IsCloseEq, atol, rtol);
+impl_builder_traits!(MathIsClose, IsCloseEq, rel_tol, abs_tol);
+impl_builder_traits!(
+    Combined,
+    RelativeEq + UlpsEq,
+    epsilon,
+    max_relative,
+    max_ulps,
+    combine,
+    policy
+);
+
+impl_builder_check!(AbsDiff, AbsDiffEq, "abs_diff_eq", "abs_diff_ne");
+impl_builder_check!(Relative, RelativeEq, "relative_eq", "relative_ne");
+impl_builder_check!(Ulps, UlpsEq, "ulps_eq", "ulps_ne");
+impl_builder_check!(IsClose, IsCloseEq, "isclose", "not_isclose");
+impl_builder_check!(MathIsClose, IsCloseEq, "math_isclose", "not_math_isclose");
+impl_builder_check!(
+    Combined,
+    RelativeEq + UlpsEq,
+    "approx_eq",
+    "approx_ne",
+    Default
+);
+
+impl_builder_comparator!(AbsDiff, AbsDiffEq);
+impl_builder_comparator!(Relative, RelativeEq);
+impl_builder_comparator!(Ulps, UlpsEq);
+impl_builder_comparator!(IsClose, IsCloseEq);
+impl_builder_comparator!(MathIsClose, IsCloseEq);
+impl_builder_comparator!(Combined, RelativeEq + UlpsEq, Default);
+
 #[doc(inline)]
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
@@ -417,3 +1254,9 @@ pub use approx_derive::AbsDiffEq;
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use approx_derive::RelativeEq;
+
+/// See [approx_derive]
+///
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use approx_derive::UlpsEq;
@@ -78,10 +78,78 @@ macro_rules! ulps_ne {
     };
 }
 
+/// Approximate equality using the formula of `numpy.isclose`.
+#[macro_export]
+macro_rules! isclose {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::IsClose::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::IsClose::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality using the formula of `numpy.isclose`.
+#[macro_export]
+macro_rules! not_isclose {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::IsClose::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::IsClose::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality using the formula of `math.isclose`.
+#[macro_export]
+macro_rules! math_isclose {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::MathIsClose::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::MathIsClose::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality using the formula of `math.isclose`.
+#[macro_export]
+macro_rules! not_math_isclose {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::MathIsClose::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::MathIsClose::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate equality passing on any, or all, of the absolute difference, relative and ULPs
+/// criteria, see [`Combine`](crate::Combine).
+#[macro_export]
+macro_rules! approx_eq {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Combined::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Combined::default()$(.$opt($val))*.eq(&$lhs, &$rhs)
+    };
+}
+
+/// Approximate inequality passing on any, or all, of the absolute difference, relative and ULPs
+/// criteria, see [`Combine`](crate::Combine).
+#[macro_export]
+macro_rules! approx_ne {
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::Combined::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+    ($lhs:expr, $rhs:expr $(, $opt:ident = $val:expr)*,) => {
+        $crate::Combined::default()$(.$opt($val))*.ne(&$lhs, &$rhs)
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __assert_approxim {
-    ($eq:ident, $given:expr, $expected:expr) => {{
+    ($eq:ident, $cmp:ident, $given:expr, $expected:expr) => {{
         match (&($given), &($expected)) {
             (given, expected) => assert!(
                 $eq!(*given, *expected),
@@ -90,42 +158,120 @@ macro_rules! __assert_approxim {
     left  = {:?}
     right = {:?}
 
-",
+{}",
                 stringify!($eq),
                 stringify!($given),
                 stringify!($expected),
                 given, expected,
+                $crate::$cmp::default().__details(given, expected),
+            ),
+        }
+    }};
+    ($eq:ident, $cmp:ident, $given:expr, $expected:expr, $($opt:ident = $val:expr),+) => {{
+        match (&($given), &($expected)) {
+            (given, expected) => $crate::__assert_approxim!(
+                @bind $eq, $cmp, ($given, $expected, given, expected), [] [$($opt = $val),+] []
             ),
         }
     }};
-    ($eq:ident, $given:expr, $expected:expr, $($opt:ident = $val:expr),+) => {{
+    ($eq:ident, $cmp:ident, $given:expr, $expected:expr, $fmt:literal $($arg:tt)*) => {{
         match (&($given), &($expected)) {
             (given, expected) => assert!(
-                $eq!(*given, *expected, $($opt = $val),+),
-"assert_{}!({}, {}, {})
+                $eq!(*given, *expected),
+"assert_{}!({}, {}): {}
 
     left  = {:?}
     right = {:?}
 
-",
+{}",
                 stringify!($eq),
                 stringify!($given),
                 stringify!($expected),
-                stringify!($($opt = $val),+),
+                format_args!($fmt $($arg)*),
                 given, expected,
+                $crate::$cmp::default().__details(given, expected),
+            ),
+        }
+    }};
+    (
+        $eq:ident, $cmp:ident, $given:expr, $expected:expr, $($opt:ident = $val:expr),+,
+        $fmt:literal $($arg:tt)*
+    ) => {{
+        match (&($given), &($expected)) {
+            (given, expected) => $crate::__assert_approxim!(
+                @bind $eq, $cmp, ($given, $expected, given, expected), [] [$($opt = $val),+]
+                [$fmt $($arg)*]
             ),
         }
     }};
+    // Evaluate every option once, binding it to a `value` which is distinct in each expansion
+    (
+        @bind $eq:ident, $cmp:ident, $operands:tt, [$($bound:tt)*]
+        [$opt:ident = $val:expr $(, $opts:ident = $vals:expr)*] $msg:tt
+    ) => {
+        match $val {
+            value => $crate::__assert_approxim!(
+                @bind $eq, $cmp, $operands, [$($bound)* ($opt = $val, value)]
+                [$($opts = $vals),*] $msg
+            ),
+        }
+    };
+    (
+        @bind $eq:ident, $cmp:ident, ($given:expr, $expected:expr, $g:ident, $e:ident),
+        [$(($opt:ident = $val:expr, $value:ident))+] [] []
+    ) => {
+        assert!(
+            $eq!(*$g, *$e, $($opt = ::core::clone::Clone::clone(&$value)),+),
+"assert_{}!({}, {}, {})
+
+    left  = {:?}
+    right = {:?}
+
+{}",
+            stringify!($eq),
+            stringify!($given),
+            stringify!($expected),
+            stringify!($($opt = $val),+),
+            $g, $e,
+            $crate::$cmp::default()$(.$opt($value))+.__details($g, $e),
+        )
+    };
+    (
+        @bind $eq:ident, $cmp:ident, ($given:expr, $expected:expr, $g:ident, $e:ident),
+        [$(($opt:ident = $val:expr, $value:ident))+] [] [$fmt:literal $($arg:tt)*]
+    ) => {
+        assert!(
+            $eq!(*$g, *$e, $($opt = ::core::clone::Clone::clone(&$value)),+),
+"assert_{}!({}, {}, {}): {}
+
+    left  = {:?}
+    right = {:?}
+
+{}",
+            stringify!($eq),
+            stringify!($given),
+            stringify!($expected),
+            stringify!($($opt = $val),+),
+            format_args!($fmt $($arg)*),
+            $g, $e,
+            $crate::$cmp::default()$(.$opt($value))+.__details($g, $e),
+        )
+    };
 }
 
 /// An assertion that delegates to [`abs_diff_eq!`], and panics with a helpful error on failure.
 #[macro_export(local_inner_macros)]
 macro_rules! assert_abs_diff_eq {
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
-        __assert_approxim!(abs_diff_eq, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(abs_diff_eq, AbsDiff, $given, $expected $(, $opt = $val)*)
     };
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
-        __assert_approxim!(abs_diff_eq, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(abs_diff_eq, AbsDiff, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            abs_diff_eq, AbsDiff, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
     };
 }
 
@@ -133,10 +279,15 @@ macro_rules! assert_abs_diff_eq {
 #[macro_export(local_inner_macros)]
 macro_rules! assert_abs_diff_ne {
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
-        __assert_approxim!(abs_diff_ne, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(abs_diff_ne, AbsDiff, $given, $expected $(, $opt = $val)*)
     };
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
-        __assert_approxim!(abs_diff_ne, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(abs_diff_ne, AbsDiff, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            abs_diff_ne, AbsDiff, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
     };
 }
 
@@ -144,10 +295,15 @@ macro_rules! assert_abs_diff_ne {
 #[macro_export(local_inner_macros)]
 macro_rules! assert_relative_eq {
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
-        __assert_approxim!(relative_eq, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(relative_eq, Relative, $given, $expected $(, $opt = $val)*)
     };
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
-        __assert_approxim!(relative_eq, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(relative_eq, Relative, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            relative_eq, Relative, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
     };
 }
 
@@ -155,10 +311,15 @@ macro_rules! assert_relative_eq {
 #[macro_export(local_inner_macros)]
 macro_rules! assert_relative_ne {
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
-        __assert_approxim!(relative_ne, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(relative_ne, Relative, $given, $expected $(, $opt = $val)*)
     };
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
-        __assert_approxim!(relative_ne, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(relative_ne, Relative, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            relative_ne, Relative, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
     };
 }
 
@@ -166,10 +327,15 @@ macro_rules! assert_relative_ne {
 #[macro_export(local_inner_macros)]
 macro_rules! assert_ulps_eq {
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
-        __assert_approxim!(ulps_eq, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(ulps_eq, Ulps, $given, $expected $(, $opt = $val)*)
     };
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
-        __assert_approxim!(ulps_eq, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(ulps_eq, Ulps, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            ulps_eq, Ulps, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
     };
 }
 
@@ -177,9 +343,475 @@ macro_rules! assert_ulps_eq {
 #[macro_export(local_inner_macros)]
 macro_rules! assert_ulps_ne {
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
-        __assert_approxim!(ulps_ne, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(ulps_ne, Ulps, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approxim!(ulps_ne, Ulps, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            ulps_ne, Ulps, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
+    };
+}
+
+/// An assertion that delegates to [`isclose!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_isclose {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approxim!(isclose, IsClose, $given, $expected $(, $opt = $val)*)
     };
     ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
-        __assert_approxim!(ulps_ne, $given, $expected $(, $opt = $val)*)
+        __assert_approxim!(isclose, IsClose, $given, $expected $(, $opt = $val)*)
     };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            isclose, IsClose, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
+    };
+}
+
+/// An assertion that delegates to [`not_isclose!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_not_isclose {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approxim!(not_isclose, IsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approxim!(not_isclose, IsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            not_isclose, IsClose, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
+    };
+}
+
+/// An assertion that delegates to [`math_isclose!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_math_isclose {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approxim!(math_isclose, MathIsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approxim!(math_isclose, MathIsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            math_isclose, MathIsClose, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
+    };
+}
+
+/// An assertion that delegates to [`not_math_isclose!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_not_math_isclose {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approxim!(not_math_isclose, MathIsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approxim!(not_math_isclose, MathIsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            not_math_isclose, MathIsClose, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
+    };
+}
+
+/// An assertion that delegates to [`approx_eq!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approxim!(approx_eq, Combined, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approxim!(approx_eq, Combined, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            approx_eq, Combined, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
+    };
+}
+
+/// An assertion that delegates to [`approx_ne!`], and panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __assert_approxim!(approx_ne, Combined, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __assert_approxim!(approx_ne, Combined, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*, $fmt:literal $($arg:tt)*) => {
+        __assert_approxim!(
+            approx_ne, Combined, $given, $expected $(, $opt = $val)*, $fmt $($arg)*
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_comparator {
+    ($given:expr, $expected:expr, $cmp:expr) => {{
+        match (&($given), &($expected), &($cmp)) {
+            (given, expected, cmp) => assert!(
+                $crate::Comparator::eq(cmp, given, expected),
+"assert_approx!({}, {}, using = {})
+
+    left  = {:?}
+    right = {:?}
+
+{}",
+                stringify!($given),
+                stringify!($expected),
+                stringify!($cmp),
+                given, expected,
+                $crate::__comparator_details(cmp, given, expected),
+            ),
+        }
+    }};
+    ($given:expr, $expected:expr, $cmp:expr, $fmt:literal $($arg:tt)*) => {{
+        match (&($given), &($expected), &($cmp)) {
+            (given, expected, cmp) => assert!(
+                $crate::Comparator::eq(cmp, given, expected),
+"assert_approx!({}, {}, using = {}): {}
+
+    left  = {:?}
+    right = {:?}
+
+{}",
+                stringify!($given),
+                stringify!($expected),
+                stringify!($cmp),
+                format_args!($fmt $($arg)*),
+                given, expected,
+                $crate::__comparator_details(cmp, given, expected),
+            ),
+        }
+    }};
+}
+
+/// An assertion that compares the values with the given [`Comparator`](crate::Comparator), and
+/// panics with a helpful error on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! assert_approx {
+    ($given:expr, $expected:expr, using = $cmp:expr) => {
+        __assert_comparator!($given, $expected, $cmp)
+    };
+    ($given:expr, $expected:expr, using = $cmp:expr,) => {
+        __assert_comparator!($given, $expected, $cmp)
+    };
+    ($given:expr, $expected:expr, using = $cmp:expr, $fmt:literal $($arg:tt)*) => {
+        __assert_comparator!($given, $expected, $cmp, $fmt $($arg)*)
+    };
+}
+
+/// An assertion that delegates to [`assert_abs_diff_eq!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_abs_diff_eq {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_abs_diff_eq!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_abs_diff_ne!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_abs_diff_ne {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_abs_diff_ne!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_relative_eq!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_relative_eq {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_relative_eq!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_relative_ne!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_relative_ne {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_relative_ne!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_ulps_eq!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_ulps_eq {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_ulps_eq!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_ulps_ne!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_ulps_ne {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_ulps_ne!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_isclose!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_isclose {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_isclose!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_not_isclose!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_not_isclose {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_not_isclose!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_math_isclose!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_math_isclose {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_math_isclose!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_not_math_isclose!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_not_math_isclose {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_not_math_isclose!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_approx_eq!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_approx_eq {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_approx_eq!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_approx_ne!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_approx_ne {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_approx_ne!($($arg)*);
+        }
+    };
+}
+
+/// An assertion that delegates to [`assert_approx!`] only if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_assert_approx {
+    ($($arg:tt)*) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_approx!($($arg)*);
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __check_approxim {
+    ($check:ident, $cmp:ident, $given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        $crate::$cmp::default()$(.$opt($val))*
+            .$check(&$given, &$expected)
+            .map_err(|error| {
+                error.__with_expressions(
+                    stringify!($given),
+                    stringify!($expected),
+                    stringify!($($opt = $val),*),
+                )
+            })
+    };
+}
+
+/// A check that delegates to [`abs_diff_eq!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_abs_diff_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check, AbsDiff, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check, AbsDiff, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`abs_diff_ne!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_abs_diff_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check_ne, AbsDiff, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check_ne, AbsDiff, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`relative_eq!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_relative_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check, Relative, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check, Relative, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`relative_ne!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_relative_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check_ne, Relative, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check_ne, Relative, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`ulps_eq!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_ulps_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check, Ulps, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check, Ulps, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`ulps_ne!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_ulps_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check_ne, Ulps, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check_ne, Ulps, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`isclose!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_isclose {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check, IsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check, IsClose, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`not_isclose!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_not_isclose {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check_ne, IsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check_ne, IsClose, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`math_isclose!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_math_isclose {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check, MathIsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check, MathIsClose, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`not_math_isclose!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_not_math_isclose {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check_ne, MathIsClose, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check_ne, MathIsClose, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`approx_eq!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_approx_eq {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check, Combined, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check, Combined, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+/// A check that delegates to [`approx_ne!`], and returns an
+/// [`ApproxError`](crate::ApproxError) instead of panicking on failure.
+#[macro_export(local_inner_macros)]
+macro_rules! check_approx_ne {
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*) => {
+        __check_approxim!(check_ne, Combined, $given, $expected $(, $opt = $val)*)
+    };
+    ($given:expr, $expected:expr $(, $opt:ident = $val:expr)*,) => {
+        __check_approxim!(check_ne, Combined, $given, $expected $(, $opt = $val)*)
+    };
+}
+
+// The derives of `approx-derive` generate the `*_report` methods inside of this macro, since they
+// only exist if this crate is built with the `alloc` feature.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "alloc")]
+macro_rules! __derive_report {
+    ($($item:tt)*) => {
+        $($item)*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "alloc"))]
+macro_rules! __derive_report {
+    ($($item:tt)*) => {};
 }
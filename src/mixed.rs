@@ -0,0 +1,39 @@
+use core::fmt;
+
+/// Opts into comparing an `f32` with an `f64`, or an `f64` with an `f32`.
+///
+/// The comparison traits are only implemented between floats of the same precision, so that an
+/// unsuffixed literal takes the type of the other operand. Wrapping the left-hand operand in
+/// `Mixed` compares it with a float of the other precision instead. The absolute and relative
+/// differences are then computed on the operands widened to `f64`, while the default tolerances
+/// are those of `f32`, and ULPs are measured in single precision.
+///
+/// Containers of `Mixed` values compare element-wise with containers of the other precision.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approxim;
+///
+/// use approxim::Mixed;
+///
+/// # fn main() {
+/// assert_relative_eq!(Mixed(0.1f32), 0.1f64);
+/// assert_ulps_eq!(Mixed(0.1f64), 0.1f32);
+/// assert_abs_diff_ne!(Mixed(0.1f32), 0.1f64, epsilon = 0.0);
+///
+/// let computed = [0.1f32, 0.2].map(Mixed);
+/// let reference = [0.1f64, 0.2];
+/// assert_relative_eq!(computed[..], reference[..]);
+/// # }
+/// ```
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Mixed<T>(pub T);
+
+/// Displays the wrapped value only, so that reports show the compared numbers.
+impl<T: fmt::Debug> fmt::Debug for Mixed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -0,0 +1,131 @@
+/// Options which change how special values are compared, independently of the tolerances.
+///
+/// A policy is passed to the `*_with` methods of [`AbsDiffEq`](crate::AbsDiffEq),
+/// [`RelativeEq`](crate::RelativeEq) and [`UlpsEq`](crate::UlpsEq), and is forwarded unchanged
+/// by the implementations for containers, so that it applies to every element. The default policy
+/// gives the same results as the methods without the `_with` suffix.
+///
+/// # Example
+///
+/// ```rust
+/// use approxim::{AbsDiffEq, NanPolicy, Policy};
+///
+/// let policy = Policy::new().nan(NanPolicy::Equal);
+/// assert!([1.0, f64::NAN].abs_diff_eq_with(&[1.0, f64::NAN], 0.0, policy));
+/// assert!([1.0, f64::NAN].abs_diff_ne(&[1.0, f64::NAN], 0.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default, deny_unknown_fields)
+)]
+#[non_exhaustive]
+pub struct Policy {
+    /// How NaN values compare to each other.
+    pub nan: NanPolicy,
+    /// How the distance in ULPs is measured. Only used by [`UlpsEq`](crate::UlpsEq).
+    pub ulps: UlpsMode,
+    /// Whether `+0.0` and `-0.0` are distinct values, which are then compared in ULPs only,
+    /// whatever the `epsilon`. Only used by [`UlpsEq`](crate::UlpsEq).
+    pub distinct_zeros: bool,
+}
+
+impl Policy {
+    /// The default policy, which can also be used in constants.
+    #[inline]
+    pub const fn new() -> Policy {
+        Policy {
+            nan: NanPolicy::Unequal,
+            ulps: UlpsMode::SignMagnitude,
+            distinct_zeros: false,
+        }
+    }
+
+    /// Replace the NaN policy with the one specified.
+    #[inline]
+    pub const fn nan(mut self, nan: NanPolicy) -> Policy {
+        self.nan = nan;
+        self
+    }
+
+    /// Replace the way the distance in ULPs is measured with the one specified.
+    #[inline]
+    pub const fn ulps_mode(mut self, ulps_mode: UlpsMode) -> Policy {
+        self.ulps = ulps_mode;
+        self
+    }
+
+    /// Specify whether `+0.0` and `-0.0` are distinct values, see [`Policy::distinct_zeros`].
+    #[inline]
+    pub const fn distinct_zeros(mut self, distinct_zeros: bool) -> Policy {
+        self.distinct_zeros = distinct_zeros;
+        self
+    }
+}
+
+/// How NaN values compare to each other, see [`Policy`].
+///
+/// A NaN never compares equal to a number, whatever the policy.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum NanPolicy {
+    /// NaN is unequal to everything, including itself, as for `==`.
+    #[default]
+    Unequal,
+    /// NaN is equal to any other NaN.
+    Equal,
+    /// NaN is equal to another NaN with identical bits, ie. the same sign and payload.
+    SamePayload,
+}
+
+impl NanPolicy {
+    /// Whether two values, which are not equal otherwise, are accepted because of this policy.
+    #[inline]
+    pub(crate) fn accepts(self, both_nan: bool, same_bits: bool) -> bool {
+        match self {
+            NanPolicy::Unequal => false,
+            NanPolicy::Equal => both_nan,
+            NanPolicy::SamePayload => both_nan && same_bits,
+        }
+    }
+}
+
+/// `true` selects [`NanPolicy::Equal`], and `false` the default [`NanPolicy::Unequal`].
+impl From<bool> for NanPolicy {
+    #[inline]
+    fn from(nan_equal: bool) -> NanPolicy {
+        if nan_equal {
+            NanPolicy::Equal
+        } else {
+            NanPolicy::Unequal
+        }
+    }
+}
+
+/// How the distance in ULPs between two floating point numbers is measured, see [`Policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum UlpsMode {
+    /// The difference of the bit patterns, which is only defined for numbers of the same sign.
+    ///
+    /// Numbers of opposite signs are never within any number of ULPs of each other, so they
+    /// are only equal if they pass the `epsilon` check, however close to zero they are.
+    #[default]
+    SignMagnitude,
+    /// The difference along a line of integers on which the numbers are ordered by value, which
+    /// is well-defined across zero.
+    ///
+    /// The smallest positive and negative subnormal numbers are two ULPs apart, with both zeros in
+    /// between, or three ULPs apart if [`Policy::distinct_zeros`] is set. NaN is never within any
+    /// number of ULPs of another value.
+    Monotonic,
+}
@@ -1,13 +1,47 @@
-use crate::AbsDiffEq;
+#[cfg(all(
+    feature = "alloc",
+    any(
+        feature = "tuple_impl",
+        feature = "num-complex",
+        feature = "indexmap_impl",
+        feature = "btree_map_impl",
+        feature = "hash_map_impl"
+    )
+))]
+use crate::report::PathSegment;
+#[cfg(feature = "alloc")]
+use crate::report::{Applied, Report};
+#[cfg(feature = "std")]
+use crate::sync::with_locked;
+use crate::{AbsDiffEq, Mixed, Policy};
+#[cfg(feature = "btree_map_impl")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "linked_list_impl")]
+use alloc::collections::LinkedList;
+#[cfg(feature = "vec_deque_impl")]
+use alloc::collections::VecDeque;
 #[cfg(feature = "vec_impl")]
 use alloc::vec::Vec;
-#[cfg(feature = "indexmap_impl")]
+use core::cell::{self, OnceCell};
+#[cfg(any(
+    feature = "btree_map_impl",
+    feature = "hash_map_impl",
+    feature = "indexmap_impl"
+))]
+use core::fmt::Debug;
+#[cfg(any(feature = "indexmap_impl", feature = "hash_map_impl"))]
 use core::hash::{BuildHasher, Hash};
-use core::{cell, f32, f64};
+use core::{f32, f64};
+#[cfg(feature = "half")]
+use half::{bf16, f16 as half_f16};
 #[cfg(feature = "indexmap_impl")]
 use indexmap::IndexMap;
 #[cfg(feature = "num-complex")]
 use num_complex::Complex;
+#[cfg(feature = "hash_map_impl")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock, RwLock};
 
 #[cfg(feature = "ordered-float")]
 use num_traits::Float;
@@ -33,6 +67,20 @@ use ordered_float::{NotNan, OrderedFloat};
 /// assert_relative_ne!(1.0f32, 1.5f32, max_relative = 0.33);
 /// # }
 /// ```
+///
+/// # Integers
+///
+/// Integers do not implement `RelativeEq`, since a `max_relative` of the integer type could only
+/// be a whole multiple of `max(|a|, |b|)`. Compare them with [`AbsDiffEq`] or
+/// [`UlpsEq`](crate::UlpsEq), or convert them to floating point numbers first:
+///
+/// ```
+/// #[macro_use] extern crate approxim;
+/// # fn main() {
+/// assert_abs_diff_eq!(100u32, 101u32, epsilon = 1);
+/// assert_relative_eq!(100u32 as f64, 101u32 as f64, max_relative = 0.01);
+/// # }
+/// ```
 pub trait RelativeEq<Rhs = Self>: AbsDiffEq<Rhs>
 where
     Rhs: ?Sized,
@@ -45,7 +93,7 @@ where
 
     /// A test for equality that uses a relative comparison if the values are far apart.
     fn relative_eq(&self, other: &Rhs, epsilon: Self::Epsilon, max_relative: Self::Epsilon)
-    -> bool;
+        -> bool;
 
     /// The inverse of [`RelativeEq::relative_eq`].
     fn relative_ne(
@@ -56,6 +104,76 @@ where
     ) -> bool {
         !Self::relative_eq(self, other, epsilon, max_relative)
     }
+
+    /// Performs the same comparison as [`RelativeEq::relative_eq`], but returns a [`Report`]
+    /// listing every position at which the values differ.
+    ///
+    /// The default implementation reports a single mismatch without any details, so
+    /// implementations for containers should override it to report their elements.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn relative_report(
+        &self,
+        other: &Rhs,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> Report {
+        Report::from_eq(Self::relative_eq(self, other, epsilon, max_relative))
+    }
+
+    /// Performs the same comparison as [`RelativeEq::relative_eq`], with special values compared
+    /// according to the given [`Policy`].
+    ///
+    /// The default implementation ignores the policy, so implementations for floating point
+    /// numbers and containers should override it.
+    fn relative_eq_with(
+        &self,
+        other: &Rhs,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        let _ = policy;
+        Self::relative_eq(self, other, epsilon, max_relative)
+    }
+
+    /// The inverse of [`RelativeEq::relative_eq_with`].
+    ///
+    /// With the default policy, the default implementation calls [`RelativeEq::relative_ne`], so
+    /// that implementations overriding it are respected.
+    fn relative_ne_with(
+        &self,
+        other: &Rhs,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        if policy == Policy::default() {
+            Self::relative_ne(self, other, epsilon, max_relative)
+        } else {
+            !Self::relative_eq_with(self, other, epsilon, max_relative, policy)
+        }
+    }
+
+    /// Performs the same comparison as [`RelativeEq::relative_eq_with`], but returns a
+    /// [`Report`] like [`RelativeEq::relative_report`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn relative_report_with(
+        &self,
+        other: &Rhs,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        Report::from_eq(Self::relative_eq_with(
+            self,
+            other,
+            epsilon,
+            max_relative,
+            policy,
+        ))
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -65,11 +183,11 @@ where
 // Implementation based on: [Comparing Floating Point Numbers, 2012 Edition]
 // (https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/)
 macro_rules! impl_relative_eq {
-    ($T:ident, $U:ident) => {
+    ($T:ident, $U:ident, $default_max_relative:expr) => {
         impl RelativeEq for $T {
             #[inline]
             fn default_max_relative() -> $T {
-                $T::EPSILON
+                $default_max_relative
             }
 
             #[inline]
@@ -105,12 +223,193 @@ macro_rules! impl_relative_eq {
                 // Use a relative difference comparison
                 abs_diff <= largest * max_relative
             }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$T,
+                epsilon: $T,
+                max_relative: $T,
+                policy: Policy,
+            ) -> bool {
+                let both_nan = self.is_nan() && other.is_nan();
+                let same_bits = self.to_bits() == other.to_bits();
+                policy.nan.accepts(both_nan, same_bits)
+                    || $T::relative_eq(self, other, epsilon, max_relative)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report(&self, other: &$T, epsilon: $T, max_relative: $T) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report_with(
+                &self,
+                other: &$T,
+                epsilon: $T,
+                max_relative: $T,
+                policy: Policy,
+            ) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon as f64),
+                    max_relative: Some(max_relative as f64),
+                    ..Applied::default()
+                };
+                let eq = $T::relative_eq_with(self, other, epsilon, max_relative, policy);
+                Report::leaf(eq, self, other, applied)
+            }
         }
     };
 }
 
-impl_relative_eq!(f32, i32);
-impl_relative_eq!(f64, i64);
+impl_relative_eq!(f32, i32, scoped_default!(f32, max_relative, f32::EPSILON));
+impl_relative_eq!(f64, i64, scoped_default!(f64, max_relative, f64::EPSILON));
+#[cfg(approxim_nightly_float)]
+impl_relative_eq!(f16, i16, f16::EPSILON);
+#[cfg(approxim_nightly_float)]
+impl_relative_eq!(f128, i128, f128::EPSILON);
+
+// Same as `impl_relative_eq!`, but computed in single precision like `impl_half_abs_diff_eq!`.
+#[cfg(feature = "half")]
+macro_rules! impl_half_relative_eq {
+    ($T:ident) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+        impl RelativeEq for $T {
+            #[inline]
+            fn default_max_relative() -> $T {
+                $T::EPSILON
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &$T, epsilon: $T, max_relative: $T) -> bool {
+                use num_traits::float::FloatCore;
+                // Handle same infinities
+                if self == other {
+                    return true;
+                }
+
+                // Handle remaining infinities
+                if $T::is_infinite(*self) || $T::is_infinite(*other) {
+                    return false;
+                }
+
+                let (lhs, rhs) = (self.to_f32(), other.to_f32());
+                let abs_diff = FloatCore::abs(lhs - rhs);
+
+                // For when the numbers are really close together
+                if abs_diff <= epsilon.to_f32() {
+                    return true;
+                }
+
+                let largest = FloatCore::max(FloatCore::abs(lhs), FloatCore::abs(rhs));
+
+                // Use a relative difference comparison
+                abs_diff <= largest * max_relative.to_f32()
+            }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$T,
+                epsilon: $T,
+                max_relative: $T,
+                policy: Policy,
+            ) -> bool {
+                let both_nan = self.is_nan() && other.is_nan();
+                let same_bits = self.to_bits() == other.to_bits();
+                policy.nan.accepts(both_nan, same_bits)
+                    || $T::relative_eq(self, other, epsilon, max_relative)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report(&self, other: &$T, epsilon: $T, max_relative: $T) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report_with(
+                &self,
+                other: &$T,
+                epsilon: $T,
+                max_relative: $T,
+                policy: Policy,
+            ) -> Report {
+                let applied = Applied {
+                    epsilon: Some(f64::from(epsilon)),
+                    max_relative: Some(f64::from(max_relative)),
+                    ..Applied::default()
+                };
+                let eq = $T::relative_eq_with(self, other, epsilon, max_relative, policy);
+                Report::leaf(eq, self, other, applied)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "half")]
+impl_half_relative_eq!(half_f16);
+#[cfg(feature = "half")]
+impl_half_relative_eq!(bf16);
+
+// Same as `impl_mixed_abs_diff_eq!`, comparing the widened values.
+macro_rules! impl_mixed_relative_eq {
+    ($T:ident, $U:ident) => {
+        impl RelativeEq<$U> for Mixed<$T> {
+            #[inline]
+            fn default_max_relative() -> f64 {
+                f64::from(<f32 as RelativeEq>::default_max_relative())
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &$U, epsilon: f64, max_relative: f64) -> bool {
+                Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$U,
+                epsilon: f64,
+                max_relative: f64,
+                policy: Policy,
+            ) -> bool {
+                f64::relative_eq_with(
+                    &f64::from(self.0),
+                    &f64::from(*other),
+                    epsilon,
+                    max_relative,
+                    policy,
+                )
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report(&self, other: &$U, epsilon: f64, max_relative: f64) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report_with(
+                &self,
+                other: &$U,
+                epsilon: f64,
+                max_relative: f64,
+                policy: Policy,
+            ) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon),
+                    max_relative: Some(max_relative),
+                    ..Applied::default()
+                };
+                let eq = RelativeEq::relative_eq_with(self, other, epsilon, max_relative, policy);
+                Report::leaf(eq, self, other, applied)
+            }
+        }
+    };
+}
+
+impl_mixed_relative_eq!(f32, f64);
+impl_mixed_relative_eq!(f64, f32);
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
@@ -128,13 +427,50 @@ impl<T: RelativeEq> RelativeEq for Option<T> {
         other: &Option<T>,
         epsilon: T::Epsilon,
         max_relative: T::Epsilon,
+    ) -> bool {
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &Option<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
     ) -> bool {
         match (self, other) {
-            (Some(a), Some(b)) => T::relative_eq(a, b, epsilon, max_relative),
+            (Some(a), Some(b)) => T::relative_eq_with(a, b, epsilon, max_relative, policy),
             (None, None) => true,
             _ => false,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &Option<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &Option<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        match (self, other) {
+            (Some(a), Some(b)) => T::relative_report_with(a, b, epsilon, max_relative, policy),
+            (None, None) => Report::new(),
+            (Some(_), None) => Report::variant("Some(..)", "None"),
+            (None, Some(_)) => Report::variant("None", "Some(..)"),
+        }
+    }
 }
 
 impl<T: RelativeEq, E: RelativeEq> RelativeEq for Result<T, E> {
@@ -149,13 +485,50 @@ impl<T: RelativeEq, E: RelativeEq> RelativeEq for Result<T, E> {
         other: &Result<T, E>,
         epsilon: (T::Epsilon, E::Epsilon),
         max_relative: (T::Epsilon, E::Epsilon),
+    ) -> bool {
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &Result<T, E>,
+        epsilon: (T::Epsilon, E::Epsilon),
+        max_relative: (T::Epsilon, E::Epsilon),
+        policy: Policy,
     ) -> bool {
         match (self, other) {
-            (Ok(a), Ok(b)) => T::relative_eq(a, b, epsilon.0, max_relative.0),
-            (Err(a), Err(b)) => E::relative_eq(a, b, epsilon.1, max_relative.1),
+            (Ok(a), Ok(b)) => T::relative_eq_with(a, b, epsilon.0, max_relative.0, policy),
+            (Err(a), Err(b)) => E::relative_eq_with(a, b, epsilon.1, max_relative.1, policy),
             _ => false,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &Result<T, E>,
+        epsilon: (T::Epsilon, E::Epsilon),
+        max_relative: (T::Epsilon, E::Epsilon),
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &Result<T, E>,
+        epsilon: (T::Epsilon, E::Epsilon),
+        max_relative: (T::Epsilon, E::Epsilon),
+        policy: Policy,
+    ) -> Report {
+        match (self, other) {
+            (Ok(a), Ok(b)) => T::relative_report_with(a, b, epsilon.0, max_relative.0, policy),
+            (Err(a), Err(b)) => E::relative_report_with(a, b, epsilon.1, max_relative.1, policy),
+            (Ok(_), Err(_)) => Report::variant("Ok(..)", "Err(..)"),
+            (Err(_), Ok(_)) => Report::variant("Err(..)", "Ok(..)"),
+        }
+    }
 }
 
 impl<'a, T: RelativeEq + ?Sized> RelativeEq for &'a T {
@@ -166,7 +539,39 @@ impl<'a, T: RelativeEq + ?Sized> RelativeEq for &'a T {
 
     #[inline]
     fn relative_eq(&self, other: &&'a T, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
-        T::relative_eq(*self, *other, epsilon, max_relative)
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &&'a T,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(*self, *other, epsilon, max_relative, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &&'a T,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &&'a T,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::relative_report_with(*self, *other, epsilon, max_relative, policy)
     }
 }
 
@@ -183,7 +588,39 @@ impl<'a, T: RelativeEq + ?Sized> RelativeEq for &'a mut T {
         epsilon: T::Epsilon,
         max_relative: T::Epsilon,
     ) -> bool {
-        T::relative_eq(*self, *other, epsilon, max_relative)
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &&'a mut T,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(*self, *other, epsilon, max_relative, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &&'a mut T,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &&'a mut T,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::relative_report_with(*self, *other, epsilon, max_relative, policy)
     }
 }
 
@@ -200,7 +637,39 @@ impl<T: RelativeEq + Copy> RelativeEq for cell::Cell<T> {
         epsilon: T::Epsilon,
         max_relative: T::Epsilon,
     ) -> bool {
-        T::relative_eq(&self.get(), &other.get(), epsilon, max_relative)
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &cell::Cell<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(&self.get(), &other.get(), epsilon, max_relative, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &cell::Cell<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &cell::Cell<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::relative_report_with(&self.get(), &other.get(), epsilon, max_relative, policy)
     }
 }
 
@@ -217,10 +686,264 @@ impl<T: RelativeEq + ?Sized> RelativeEq for cell::RefCell<T> {
         epsilon: T::Epsilon,
         max_relative: T::Epsilon,
     ) -> bool {
-        T::relative_eq(&self.borrow(), &other.borrow(), epsilon, max_relative)
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
     }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &cell::RefCell<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(
+            &self.borrow(),
+            &other.borrow(),
+            epsilon,
+            max_relative,
+            policy,
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &cell::RefCell<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &cell::RefCell<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::relative_report_with(
+            &self.borrow(),
+            &other.borrow(),
+            epsilon,
+            max_relative,
+            policy,
+        )
+    }
+}
+
+// Smart pointers and owned wrappers compare the values they point to.
+macro_rules! impl_deref_relative_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Type:ty => $T:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> RelativeEq for $Type
+        where
+            $T: RelativeEq,
+        {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                <$T as RelativeEq>::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &$Type,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$Type,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                <$T as RelativeEq>::relative_eq_with(
+                    &**self,
+                    &**other,
+                    epsilon,
+                    max_relative,
+                    policy,
+                )
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report(
+                &self,
+                other: &$Type,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report_with(
+                &self,
+                other: &$Type,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                <$T as RelativeEq>::relative_report_with(
+                    &**self,
+                    &**other,
+                    epsilon,
+                    max_relative,
+                    policy,
+                )
+            }
+        }
+    )*};
+}
+
+impl_deref_relative_eq! {
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::boxed::Box<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::rc::Rc<T> => T;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::sync::Arc<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    ['a, T: alloc::borrow::ToOwned + ?Sized] alloc::borrow::Cow<'a, T> => T;
+    [P: core::ops::Deref] core::pin::Pin<P> => P::Target;
+}
+
+// Locks compare the values they protect, see `sync::with_locked` for the poisoning and deadlocks.
+#[cfg(feature = "std")]
+macro_rules! impl_lock_relative_eq {
+    ($($Lock:ident),*) => {$(
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl<T: RelativeEq + ?Sized> RelativeEq for $Lock<T> {
+            #[inline]
+            fn default_max_relative() -> T::Epsilon {
+                T::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &$Lock<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+            ) -> bool {
+                Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$Lock<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                with_locked(self, other, |a, b| {
+                    T::relative_eq_with(a, b, epsilon, max_relative, policy)
+                })
+            }
+
+            fn relative_report(
+                &self,
+                other: &$Lock<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+            ) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            fn relative_report_with(
+                &self,
+                other: &$Lock<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                with_locked(self, other, |a, b| {
+                    T::relative_report_with(a, b, epsilon, max_relative, policy)
+                })
+            }
+        }
+    )*};
 }
 
+#[cfg(feature = "std")]
+impl_lock_relative_eq!(Mutex, RwLock);
+
+// Cells which are initialized once compare like an `Option` of their value.
+macro_rules! impl_once_relative_eq {
+    ($(#[$attr:meta])* $Once:ident) => {
+        $(#[$attr])*
+        impl<T: RelativeEq> RelativeEq for $Once<T> {
+            #[inline]
+            fn default_max_relative() -> T::Epsilon {
+                T::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &$Once<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+            ) -> bool {
+                Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$Once<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                Option::relative_eq_with(&self.get(), &other.get(), epsilon, max_relative, policy)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report(
+                &self,
+                other: &$Once<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+            ) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report_with(
+                &self,
+                other: &$Once<T>,
+                epsilon: T::Epsilon,
+                max_relative: T::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                let (a, b) = (self.get(), other.get());
+                Option::relative_report_with(&a, &b, epsilon, max_relative, policy)
+            }
+        }
+    };
+}
+
+impl_once_relative_eq!(OnceCell);
+#[cfg(feature = "std")]
+impl_once_relative_eq!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    OnceLock
+);
+
 impl<A, B> RelativeEq<[B]> for [A]
 where
     A: RelativeEq<B>,
@@ -231,11 +954,46 @@ where
         A::default_max_relative()
     }
 
-    #[inline]
-    fn relative_eq(&self, other: &[B], epsilon: A::Epsilon, max_relative: A::Epsilon) -> bool {
-        self.len() == other.len()
-            && Iterator::zip(self.iter(), other)
-                .all(|(x, y)| A::relative_eq(x, y, epsilon.clone(), max_relative.clone()))
+    #[inline]
+    fn relative_eq(&self, other: &[B], epsilon: A::Epsilon, max_relative: A::Epsilon) -> bool {
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &[B],
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        self.len() == other.len()
+            && Iterator::zip(self.iter(), other).all(|(x, y)| {
+                A::relative_eq_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+            })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &[B],
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &[B],
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::relative_report_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+        })
     }
 }
 
@@ -253,9 +1011,44 @@ where
 
     #[inline]
     fn relative_eq(&self, other: &[B; N], epsilon: A::Epsilon, max_relative: A::Epsilon) -> bool {
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &[B; N],
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+        policy: Policy,
+    ) -> bool {
         self.len() == other.len()
-            && Iterator::zip(self.iter(), other)
-                .all(|(x, y)| A::relative_eq(x, y, epsilon.clone(), max_relative.clone()))
+            && Iterator::zip(self.iter(), other).all(|(x, y)| {
+                A::relative_eq_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+            })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &[B; N],
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &[B; N],
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::relative_report_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+        })
     }
 }
 
@@ -273,9 +1066,44 @@ where
 
     #[inline]
     fn relative_eq(&self, other: &Vec<B>, epsilon: A::Epsilon, max_relative: A::Epsilon) -> bool {
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &Vec<B>,
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+        policy: Policy,
+    ) -> bool {
         self.len() == other.len()
-            && Iterator::zip(self.iter(), other)
-                .all(|(x, y)| A::relative_eq(x, y, epsilon.clone(), max_relative.clone()))
+            && Iterator::zip(self.iter(), other).all(|(x, y)| {
+                A::relative_eq_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+            })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &Vec<B>,
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &Vec<B>,
+        epsilon: A::Epsilon,
+        max_relative: A::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::relative_report_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+        })
     }
 }
 
@@ -284,9 +1112,7 @@ where
 macro_rules! impl_relative_eq {
     () => {
         impl RelativeEq for () {
-            fn default_max_relative() -> Self::Epsilon {
-                ()
-            }
+            fn default_max_relative() -> Self::Epsilon {}
 
             fn relative_eq(
                 &self,
@@ -315,7 +1141,63 @@ macro_rules! impl_relative_eq {
                     epsilon: Self::Epsilon,
                     max_relative: Self::Epsilon,
                 ) -> bool {
-                    true $( && self.$idx.relative_eq(&other.$idx, epsilon.$idx, max_relative.$idx) )+
+                    Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+                }
+
+                fn relative_eq_with(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    max_relative: Self::Epsilon,
+                    policy: Policy,
+                ) -> bool {
+                    true $(
+                        && self.$idx.relative_eq_with(
+                            &other.$idx,
+                            epsilon.$idx,
+                            max_relative.$idx,
+                            policy,
+                        )
+                    )+
+                }
+
+                #[cfg(feature = "alloc")]
+                fn relative_report(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    max_relative: Self::Epsilon,
+                ) -> Report {
+                    Self::relative_report_with(
+                        self,
+                        other,
+                        epsilon,
+                        max_relative,
+                        Policy::default(),
+                    )
+                }
+
+                #[cfg(feature = "alloc")]
+                fn relative_report_with(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    max_relative: Self::Epsilon,
+                    policy: Policy,
+                ) -> Report {
+                    let mut report = Report::new();
+                    $(
+                        report.nest(
+                            PathSegment::Tuple($idx),
+                            self.$idx.relative_report_with(
+                                &other.$idx,
+                                epsilon.$idx,
+                                max_relative.$idx,
+                                policy,
+                            ),
+                        );
+                    )+
+                    report
                 }
             }
         }
@@ -360,8 +1242,60 @@ where
         epsilon: T::Epsilon,
         max_relative: T::Epsilon,
     ) -> bool {
-        T::relative_eq(&self.re, &other.re, epsilon.clone(), max_relative.clone())
-            && T::relative_eq(&self.im, &other.im, epsilon, max_relative)
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &Complex<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(
+            &self.re,
+            &other.re,
+            epsilon.clone(),
+            max_relative.clone(),
+            policy,
+        ) && T::relative_eq_with(&self.im, &other.im, epsilon, max_relative, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &Complex<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &Complex<T>,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        let mut report = Report::new();
+        report.nest(
+            PathSegment::Field("re"),
+            T::relative_report_with(
+                &self.re,
+                &other.re,
+                epsilon.clone(),
+                max_relative.clone(),
+                policy,
+            ),
+        );
+        report.nest(
+            PathSegment::Field("im"),
+            T::relative_report_with(&self.im, &other.im, epsilon, max_relative, policy),
+        );
+        report
     }
 }
 
@@ -380,11 +1314,50 @@ impl<T: RelativeEq + Copy> RelativeEq for NotNan<T> {
         epsilon: Self::Epsilon,
         max_relative: Self::Epsilon,
     ) -> bool {
-        T::relative_eq(
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(
+            &self.into_inner(),
+            &other.into_inner(),
+            epsilon,
+            max_relative,
+            policy,
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::relative_report_with(
             &self.into_inner(),
             &other.into_inner(),
             epsilon,
             max_relative,
+            policy,
         )
     }
 }
@@ -399,7 +1372,39 @@ impl<T: RelativeEq + Float + ordered_float::FloatCore> RelativeEq<T> for NotNan<
 
     #[inline]
     fn relative_eq(&self, other: &T, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
-        T::relative_eq(&self.into_inner(), other, epsilon, max_relative)
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &T,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(&self.into_inner(), other, epsilon, max_relative, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &T,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &T,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::relative_report_with(&self.into_inner(), other, epsilon, max_relative, policy)
     }
 }
 
@@ -418,11 +1423,50 @@ impl<T: RelativeEq + Float + ordered_float::FloatCore> RelativeEq for OrderedFlo
         epsilon: Self::Epsilon,
         max_relative: Self::Epsilon,
     ) -> bool {
-        T::relative_eq(
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(
+            &self.into_inner(),
+            &other.into_inner(),
+            epsilon,
+            max_relative,
+            policy,
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::relative_report_with(
             &self.into_inner(),
             &other.into_inner(),
             epsilon,
             max_relative,
+            policy,
         )
     }
 }
@@ -437,7 +1481,39 @@ impl<T: RelativeEq + Float + ordered_float::FloatCore> RelativeEq<T> for Ordered
 
     #[inline]
     fn relative_eq(&self, other: &T, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
-        T::relative_eq(&self.into_inner(), other, epsilon, max_relative)
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &T,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> bool {
+        T::relative_eq_with(&self.into_inner(), other, epsilon, max_relative, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &T,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &T,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        T::relative_report_with(&self.into_inner(), other, epsilon, max_relative, policy)
     }
 }
 
@@ -445,7 +1521,7 @@ impl<T: RelativeEq + Float + ordered_float::FloatCore> RelativeEq<T> for Ordered
 #[cfg_attr(docsrs, doc(cfg(feature = "indexmap_impl")))]
 impl<K, V1, V2, S1, S2> RelativeEq<IndexMap<K, V2, S2>> for IndexMap<K, V1, S1>
 where
-    K: Hash + Eq,
+    K: Hash + Eq + Debug,
     V1: RelativeEq<V2>,
     V1::Epsilon: Clone,
     S1: BuildHasher,
@@ -462,12 +1538,342 @@ where
         other: &IndexMap<K, V2, S2>,
         epsilon: Self::Epsilon,
         max_relative: Self::Epsilon,
+    ) -> bool {
+        Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[inline]
+    fn relative_eq_with(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
     ) -> bool {
         self.len() == other.len()
             && self.iter().all(|(key, value)| {
-                other.get(key).map_or(false, |v| {
-                    V1::relative_eq(value, v, epsilon.clone(), max_relative.clone())
+                other.get(key).is_some_and(|v| {
+                    V1::relative_eq_with(value, v, epsilon.clone(), max_relative.clone(), policy)
                 })
             })
     }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> Report {
+        Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn relative_report_with(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+        policy: Policy,
+    ) -> Report {
+        if self.len() != other.len() {
+            return Report::length(self.len(), other.len());
+        }
+        let mut report = Report::new();
+        for (key, value) in self {
+            let nested = match other.get(key) {
+                Some(v) => V1::relative_report_with(
+                    value,
+                    v,
+                    epsilon.clone(),
+                    max_relative.clone(),
+                    policy,
+                ),
+                None => Report::missing_key(),
+            };
+            report.nest(PathSegment::key(key), nested);
+        }
+        report
+    }
+}
+
+// Sequences other than slices compare element-wise like `Vec`.
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+macro_rules! impl_sequence_relative_eq {
+    ($($(#[$attr:meta])* $Seq:ident;)*) => {$(
+        $(#[$attr])*
+        impl<A, B> RelativeEq<$Seq<B>> for $Seq<A>
+        where
+            A: RelativeEq<B>,
+            A::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_relative() -> A::Epsilon {
+                A::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &$Seq<B>,
+                epsilon: A::Epsilon,
+                max_relative: A::Epsilon,
+            ) -> bool {
+                Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$Seq<B>,
+                epsilon: A::Epsilon,
+                max_relative: A::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && Iterator::zip(self.iter(), other).all(|(x, y)| {
+                        A::relative_eq_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+                    })
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report(
+                &self,
+                other: &$Seq<B>,
+                epsilon: A::Epsilon,
+                max_relative: A::Epsilon,
+            ) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report_with(
+                &self,
+                other: &$Seq<B>,
+                epsilon: A::Epsilon,
+                max_relative: A::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                Report::sequence(self, other, |x, y| {
+                    A::relative_report_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+impl_sequence_relative_eq! {
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    VecDeque;
+    #[cfg(feature = "linked_list_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "linked_list_impl")))]
+    LinkedList;
+}
+
+// Maps compare the values of the same keys like `IndexMap`, and locate mismatches by key.
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+macro_rules! impl_map_relative_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*, V1, V2> RelativeEq<$Right> for $Left
+        where
+            V1: RelativeEq<V2>,
+            V1::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_relative() -> V1::Epsilon {
+                V1::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &$Right,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$Right,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, value)| {
+                        other.get(key).is_some_and(|v| {
+                            V1::relative_eq_with(
+                                value,
+                                v,
+                                epsilon.clone(),
+                                max_relative.clone(),
+                                policy,
+                            )
+                        })
+                    })
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report(
+                &self,
+                other: &$Right,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report_with(
+                &self,
+                other: &$Right,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                if self.len() != other.len() {
+                    return Report::length(self.len(), other.len());
+                }
+                let mut report = Report::new();
+                for (key, value) in self {
+                    let nested = match other.get(key) {
+                        Some(v) => V1::relative_report_with(
+                            value,
+                            v,
+                            epsilon.clone(),
+                            max_relative.clone(),
+                            policy,
+                        ),
+                        None => Report::missing_key(),
+                    };
+                    report.nest(PathSegment::key(key), nested);
+                }
+                report
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+impl_map_relative_eq! {
+    #[cfg(feature = "btree_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "btree_map_impl")))]
+    [K: Ord + Debug] BTreeMap<K, V1> => BTreeMap<K, V2>;
+    #[cfg(feature = "hash_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hash_map_impl")))]
+    [K: Hash + Eq + Debug, S1: BuildHasher, S2: BuildHasher] HashMap<K, V1, S1> => HashMap<K, V2, S2>;
+}
+
+// Different kinds of sequences compare element-wise, so that eg. a `Vec` can be compared with an
+// array literal.
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+macro_rules! impl_cross_relative_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> RelativeEq<$Right> for $Left
+        where
+            A: RelativeEq<B>,
+            A::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_relative() -> A::Epsilon {
+                A::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(
+                &self,
+                other: &$Right,
+                epsilon: A::Epsilon,
+                max_relative: A::Epsilon,
+            ) -> bool {
+                Self::relative_eq_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[inline]
+            fn relative_eq_with(
+                &self,
+                other: &$Right,
+                epsilon: A::Epsilon,
+                max_relative: A::Epsilon,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && Iterator::zip(self.iter(), other.iter()).all(|(x, y)| {
+                        A::relative_eq_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+                    })
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report(
+                &self,
+                other: &$Right,
+                epsilon: A::Epsilon,
+                max_relative: A::Epsilon,
+            ) -> Report {
+                Self::relative_report_with(self, other, epsilon, max_relative, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn relative_report_with(
+                &self,
+                other: &$Right,
+                epsilon: A::Epsilon,
+                max_relative: A::Epsilon,
+                policy: Policy,
+            ) -> Report {
+                Report::sequence(self.iter(), other.iter(), |x, y| {
+                    A::relative_report_with(x, y, epsilon.clone(), max_relative.clone(), policy)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+impl_cross_relative_eq! {
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] Vec<A> => [B; N];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] [A; N] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] Vec<A> => [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] [A] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] Vec<A> => &'a [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] &'a [A] => Vec<B>;
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A; N] => [B];
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A] => [B; N];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] VecDeque<A> => [B];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] [A] => VecDeque<B>;
 }
@@ -0,0 +1,431 @@
+use crate::Distance;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::{fmt, slice};
+
+/// One step on the way from the compared values down to a mismatching leaf.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// The position of an element in a slice, array or `Vec`.
+    Index(usize),
+    /// The `Debug` representation of the key of a map entry.
+    Key(String),
+    /// The position of a field in a tuple.
+    Tuple(usize),
+    /// The name of a field, such as the `re` and `im` parts of a complex number.
+    Field(&'static str),
+}
+
+/// Describes why two values did not compare equal.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum MismatchKind {
+    /// Two leaf values are further apart than the tolerance permits.
+    #[default]
+    Value,
+    /// Two collections have a different number of elements.
+    Length {
+        /// The length of the left-hand side.
+        left: usize,
+        /// The length of the right-hand side.
+        right: usize,
+    },
+    /// Two values are different variants, eg. `Some` and `None` or `Ok` and `Err`.
+    Variant,
+    /// A key of the left-hand map is missing from the right-hand map.
+    MissingKey,
+}
+
+/// A single failing comparison found while building a [`Report`].
+///
+/// The numeric fields are only filled in when the compared values are known to this crate, such
+/// as floating point numbers and integers. For custom types which only implement the boolean
+/// comparison, the mismatch is reported without any further details.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mismatch {
+    /// The location of the mismatch, starting from the outermost compared value.
+    pub path: Vec<PathSegment>,
+    /// The reason for the mismatch.
+    pub kind: MismatchKind,
+    /// The `Debug` representation of the left-hand value.
+    pub left: Option<String>,
+    /// The `Debug` representation of the right-hand value.
+    pub right: Option<String>,
+    /// The absolute difference `|a - b|`.
+    pub abs_diff: Option<f64>,
+    /// The relative difference `|a - b| / max(|a|, |b|)`.
+    pub rel_diff: Option<f64>,
+    /// The distance in units in the last place.
+    ///
+    /// This is `None` if the values have different signs or either value is NaN.
+    pub ulps: Option<u64>,
+    /// The absolute tolerance which was applied.
+    pub epsilon: Option<f64>,
+    /// The relative tolerance which was applied.
+    pub max_relative: Option<f64>,
+    /// The ULPs tolerance which was applied.
+    pub max_ulps: Option<u32>,
+}
+
+#[cfg(any(
+    feature = "btree_map_impl",
+    feature = "hash_map_impl",
+    feature = "indexmap_impl"
+))]
+impl PathSegment {
+    /// The segment of the map entry with the given key.
+    pub(crate) fn key<K: fmt::Debug + ?Sized>(key: &K) -> PathSegment {
+        PathSegment::Key(format!("{:?}", key))
+    }
+}
+
+impl Mismatch {
+    /// Creates an empty mismatch of the given kind.
+    #[inline]
+    pub fn new(kind: MismatchKind) -> Mismatch {
+        Mismatch {
+            kind,
+            ..Mismatch::default()
+        }
+    }
+}
+
+/// The outcome of an approximate comparison, listing every failing position.
+///
+/// A report is obtained from the `*_report` methods of [`AbsDiffEq`](crate::AbsDiffEq),
+/// [`RelativeEq`](crate::RelativeEq) and [`UlpsEq`](crate::UlpsEq), or from the `report`
+/// methods of [`AbsDiff`](crate::AbsDiff), [`Relative`](crate::Relative) and
+/// [`Ulps`](crate::Ulps).
+///
+/// # Example
+///
+/// ```rust
+/// use approxim::{AbsDiffEq, PathSegment};
+///
+/// let report = [1.0, 2.0, 3.0].abs_diff_report(&[1.0, 2.5, 3.0], 0.1);
+/// let mismatch = report.first().unwrap();
+///
+/// assert_eq!(report.len(), 1);
+/// assert_eq!(mismatch.path, [PathSegment::Index(1)]);
+/// assert_eq!(mismatch.abs_diff, Some(0.5));
+/// assert_eq!(mismatch.epsilon, Some(0.1));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    mismatches: Vec<Mismatch>,
+}
+
+impl Report {
+    /// Creates a report without any mismatches.
+    #[inline]
+    pub fn new() -> Report {
+        Report::default()
+    }
+
+    /// Creates a report from the outcome of a boolean comparison.
+    ///
+    /// A failed comparison is recorded as a single [`MismatchKind::Value`] without details.
+    #[inline]
+    pub fn from_eq(eq: bool) -> Report {
+        let mut report = Report::new();
+        if !eq {
+            report.push(Mismatch::new(MismatchKind::Value));
+        }
+        report
+    }
+
+    /// Returns `true` if no mismatch was found.
+    #[inline]
+    pub fn is_eq(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// The number of mismatches found.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.mismatches.len()
+    }
+
+    /// Returns `true` if no mismatch was found.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// The first mismatch found, if any.
+    #[inline]
+    pub fn first(&self) -> Option<&Mismatch> {
+        self.mismatches.first()
+    }
+
+    /// All mismatches found, in the order in which the values were traversed.
+    #[inline]
+    pub fn mismatches(&self) -> &[Mismatch] {
+        &self.mismatches
+    }
+
+    /// An iterator over all mismatches.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, Mismatch> {
+        self.mismatches.iter()
+    }
+
+    /// Records a mismatch.
+    #[inline]
+    pub fn push(&mut self, mismatch: Mismatch) {
+        self.mismatches.push(mismatch);
+    }
+
+    /// Appends the mismatches of a nested comparison, prefixing their paths with `segment`.
+    pub fn nest(&mut self, segment: PathSegment, nested: Report) {
+        self.mismatches
+            .extend(nested.mismatches.into_iter().map(|mut mismatch| {
+                mismatch.path.insert(0, segment.clone());
+                mismatch
+            }));
+    }
+}
+
+impl IntoIterator for Report {
+    type Item = Mismatch;
+    type IntoIter = alloc::vec::IntoIter<Mismatch>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.mismatches.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Report {
+    type Item = &'a Mismatch;
+    type IntoIter = slice::Iter<'a, Mismatch>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.mismatches.iter()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Formatting
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Index(i) => write!(f, "[{}]", i),
+            PathSegment::Key(key) => write!(f, "[{}]", key),
+            PathSegment::Tuple(i) => write!(f, ".{}", i),
+            PathSegment::Field(name) => write!(f, ".{}", name),
+        }
+    }
+}
+
+impl fmt::Display for MismatchKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MismatchKind::Value => write!(f, "values differ"),
+            MismatchKind::Length { left, right } => {
+                write!(f, "lengths differ ({} != {})", left, right)
+            }
+            MismatchKind::Variant => write!(f, "variants differ"),
+            MismatchKind::MissingKey => write!(f, "key is missing from the right-hand side"),
+        }
+    }
+}
+
+impl Mismatch {
+    /// Displays the location of the mismatch, eg. `[3].1.re`.
+    ///
+    /// A mismatch of the outermost values is displayed as an empty string.
+    pub fn display_path(&self) -> impl fmt::Display + '_ {
+        struct DisplayPath<'a>(&'a [PathSegment]);
+
+        impl fmt::Display for DisplayPath<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0
+                    .iter()
+                    .try_for_each(|segment| write!(f, "{}", segment))
+            }
+        }
+
+        DisplayPath(&self.path)
+    }
+
+    pub(crate) fn write_indented(&self, f: &mut fmt::Formatter<'_>, indent: &str) -> fmt::Result {
+        write!(f, "{}{}", indent, self.kind)?;
+        if !self.path.is_empty() {
+            write!(f, " at {}", self.display_path())?;
+        }
+        for (name, value) in [("left", &self.left), ("right", &self.right)] {
+            if let Some(value) = value {
+                write!(f, "\n{}    {:<12} = {}", indent, name, value)?;
+            }
+        }
+        let numbers: [(&str, Option<&dyn fmt::Debug>); 6] = [
+            ("abs_diff", self.abs_diff.as_ref().map(|v| v as _)),
+            ("rel_diff", self.rel_diff.as_ref().map(|v| v as _)),
+            ("ulps", self.ulps.as_ref().map(|v| v as _)),
+            ("epsilon", self.epsilon.as_ref().map(|v| v as _)),
+            ("max_relative", self.max_relative.as_ref().map(|v| v as _)),
+            ("max_ulps", self.max_ulps.as_ref().map(|v| v as _)),
+        ];
+        for (name, value) in numbers {
+            if let Some(value) = value {
+                write!(f, "\n{}    {:<12} = {:?}", indent, name, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Displays the kind and location of the mismatch, followed by one line for every known
+/// difference and tolerance.
+///
+/// ```text
+/// values differ at [3].1.re
+///     left         = 1.0
+///     right        = 1.5
+///     abs_diff     = 0.5
+///     ...
+/// ```
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_indented(f, "")
+    }
+}
+
+/// Displays the first mismatch along with the total number of mismatches.
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.first() {
+            None => write!(f, "no mismatches"),
+            Some(first) => {
+                writeln!(f, "first of {} mismatch(es):", self.len())?;
+                first.write_indented(f, "")
+            }
+        }
+    }
+}
+
+/// The part of an assertion failure message which describes the first mismatch.
+#[doc(hidden)]
+pub struct Details(pub Report);
+
+impl fmt::Display for Details {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(first) = self.0.first() {
+            writeln!(f, "    first of {} mismatch(es):", self.0.len())?;
+            first.write_indented(f, "    ")?;
+            writeln!(f, "\n")?;
+        }
+        Ok(())
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Leaf values
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Mismatch {
+    /// Creates the mismatch of two leaf values, filling in the differences between them.
+    ///
+    /// The tolerances are left for the caller to fill in.
+    pub fn values<A, B>(left: &A, right: &B) -> Mismatch
+    where
+        A: Distance<B> + fmt::Debug + ?Sized,
+        B: fmt::Debug + ?Sized,
+    {
+        let ulps = A::ulps_distance(left, right);
+        Mismatch {
+            left: Some(format!("{:?}", left)),
+            right: Some(format!("{:?}", right)),
+            abs_diff: Some(A::abs_diff(left, right)),
+            rel_diff: Some(A::relative_diff(left, right)),
+            ulps: if ulps == u64::MAX { None } else { Some(ulps) },
+            ..Mismatch::new(MismatchKind::Value)
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// Helpers for the trait implementations
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// The tolerances recorded alongside the differences of a failing leaf comparison.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct Applied {
+    pub epsilon: Option<f64>,
+    pub max_relative: Option<f64>,
+    pub max_ulps: Option<u32>,
+}
+
+impl Report {
+    /// Creates the report of a leaf comparison that yielded `eq`.
+    pub(crate) fn leaf<A, B>(eq: bool, left: &A, right: &B, applied: Applied) -> Report
+    where
+        A: Distance<B> + fmt::Debug,
+        B: fmt::Debug,
+    {
+        let mut report = Report::new();
+        if !eq {
+            report.push(Mismatch {
+                epsilon: applied.epsilon,
+                max_relative: applied.max_relative,
+                max_ulps: applied.max_ulps,
+                ..Mismatch::values(left, right)
+            });
+        }
+        report
+    }
+
+    /// Creates the report of two values that are different variants of the same enum.
+    pub(crate) fn variant(left: &str, right: &str) -> Report {
+        let mut report = Report::new();
+        report.push(Mismatch {
+            left: Some(left.into()),
+            right: Some(right.into()),
+            ..Mismatch::new(MismatchKind::Variant)
+        });
+        report
+    }
+
+    /// Creates the report of two collections with a different number of elements.
+    pub(crate) fn length(left: usize, right: usize) -> Report {
+        let mut report = Report::new();
+        report.push(Mismatch::new(MismatchKind::Length { left, right }));
+        report
+    }
+
+    /// Creates the report of a map key which is missing from the right-hand map.
+    #[cfg(any(
+        feature = "indexmap_impl",
+        feature = "btree_map_impl",
+        feature = "hash_map_impl"
+    ))]
+    pub(crate) fn missing_key() -> Report {
+        let mut report = Report::new();
+        report.push(Mismatch::new(MismatchKind::MissingKey));
+        report
+    }
+
+    /// Creates the report of two sequences by comparing them element-wise with `f`.
+    pub(crate) fn sequence<L, R, F>(left: L, right: R, mut f: F) -> Report
+    where
+        L: IntoIterator,
+        L::IntoIter: ExactSizeIterator,
+        R: IntoIterator,
+        R::IntoIter: ExactSizeIterator,
+        F: FnMut(L::Item, R::Item) -> Report,
+    {
+        let (left, right) = (left.into_iter(), right.into_iter());
+        if left.len() != right.len() {
+            return Report::length(left.len(), right.len());
+        }
+        let mut report = Report::new();
+        for (i, (x, y)) in Iterator::zip(left, right).enumerate() {
+            report.nest(PathSegment::Index(i), f(x, y));
+        }
+        report
+    }
+}
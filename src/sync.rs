@@ -0,0 +1,65 @@
+use core::ops::Deref;
+use core::ptr;
+use std::sync::{Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard};
+
+/// A lock whose value is compared by the implementations for `Mutex` and `RwLock`.
+pub(crate) trait Lock {
+    type Target: ?Sized;
+    type Guard<'a>: Deref<Target = Self::Target>
+    where
+        Self: 'a;
+
+    /// Locks for reading. A poisoned lock is still compared, since the comparison does not modify
+    /// the value.
+    fn read(&self) -> Self::Guard<'_>;
+}
+
+impl<T: ?Sized> Lock for Mutex<T> {
+    type Target = T;
+    type Guard<'a>
+        = MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn read(&self) -> MutexGuard<'_, T> {
+        self.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: ?Sized> Lock for RwLock<T> {
+    type Target = T;
+    type Guard<'a>
+        = RwLockReadGuard<'a, T>
+    where
+        T: 'a;
+
+    #[inline]
+    fn read(&self) -> RwLockReadGuard<'_, T> {
+        RwLock::read(self).unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Calls `f` with the values of both locks.
+///
+/// A lock compared with itself is only locked once, and the locks are otherwise acquired in the
+/// order of their addresses, so that concurrent comparisons of the same locks cannot deadlock.
+pub(crate) fn with_locked<L, R>(a: &L, b: &L, f: impl FnOnce(&L::Target, &L::Target) -> R) -> R
+where
+    L: Lock + ?Sized,
+{
+    let (first, second) = (a as *const L as *const (), b as *const L as *const ());
+    if ptr::eq(first, second) {
+        let guard = a.read();
+        return f(&guard, &guard);
+    }
+    if first < second {
+        let a = a.read();
+        let b = b.read();
+        f(&a, &b)
+    } else {
+        let b = b.read();
+        let a = a.read();
+        f(&a, &b)
+    }
+}
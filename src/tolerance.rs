@@ -0,0 +1,239 @@
+#[cfg(feature = "alloc")]
+use crate::Report;
+use crate::{AbsDiff, Combine, Combined, Comparator, Policy, Relative, RelativeEq, Ulps, UlpsEq};
+
+/// A tolerance chosen at runtime, such as one loaded from a test manifest.
+///
+/// Each variant corresponds to one of the builders [`AbsDiff`], [`Relative`], [`Ulps`] and
+/// [`Combined`]. The tolerances which are `None` are replaced with the defaults of the compared
+/// type, as with the builders. `E` is the type of the `epsilon` and `max_relative` values, ie. the
+/// [`Epsilon`](crate::AbsDiffEq::Epsilon) of the compared values, which is `f64` for `f64` and
+/// for containers of `f64`.
+///
+/// A tolerance is applied with [`eq`](Tolerance::eq), or through the [`Comparator`] trait, eg.
+/// with the [`assert_approx!`](crate::assert_approx) macro, to any value implementing both
+/// [`RelativeEq`] and [`UlpsEq`], and whose `E` implements `Default`. Since the mode is only known
+/// at runtime, these bounds apply to every mode: `AbsDiff` only compares with
+/// [`AbsDiffEq`](crate::AbsDiffEq), and only `Combined` needs `E::default()`, the zero `epsilon` of
+/// its relative and ULPs criteria. Use the builders directly for types which implement less.
+///
+/// With the `serde` feature, a tolerance can be serialized and deserialized. It is represented
+/// as a map with a `mode` of `"abs_diff"`, `"relative"`, `"ulps"` or `"combined"`, along with the
+/// tolerances of that mode, all of which are optional:
+///
+/// ```toml
+/// [energy]
+/// mode = "relative"
+/// max_relative = 1e-9
+///
+/// [positions]
+/// mode = "combined"
+/// epsilon = 1e-12
+/// max_ulps = 8
+/// combine = "all"
+/// policy = { nan = "equal" }
+/// ```
+///
+/// Unknown keys are rejected, so that a misspelled tolerance is not silently replaced with its
+/// default.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approxim;
+///
+/// use approxim::{Policy, Tolerance};
+///
+/// # fn main() {
+/// let tolerance = Tolerance::Relative {
+///     epsilon: None,
+///     max_relative: Some(1e-9),
+///     policy: Policy::default(),
+/// };
+///
+/// assert!(tolerance.eq(&(0.1 + 0.2), &0.3));
+/// assert!(tolerance.eq(&Some(0.1 + 0.2), &Some(0.3)));
+/// assert_approx!([0.1 + 0.2, 1.0][..], [0.3, 1.0][..], using = tolerance);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "mode", rename_all = "snake_case", deny_unknown_fields)
+)]
+pub enum Tolerance<E = f64> {
+    /// Compare using [`AbsDiffEq`](crate::AbsDiffEq), see [`AbsDiff`].
+    AbsDiff {
+        /// The tolerance to use when testing values that are close together.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        epsilon: Option<E>,
+        /// How special values such as NaN are compared.
+        #[cfg_attr(feature = "serde", serde(default))]
+        policy: Policy,
+    },
+    /// Compare using [`RelativeEq`], see [`Relative`].
+    Relative {
+        /// The tolerance to use when testing values that are close together.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        epsilon: Option<E>,
+        /// The relative tolerance for testing values that are far-apart.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        max_relative: Option<E>,
+        /// How special values such as NaN are compared.
+        #[cfg_attr(feature = "serde", serde(default))]
+        policy: Policy,
+    },
+    /// Compare using [`UlpsEq`], see [`Ulps`].
+    Ulps {
+        /// The tolerance to use when testing values that are close together.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        epsilon: Option<E>,
+        /// The ULPs to tolerate when testing values that are far-apart.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        max_ulps: Option<u32>,
+        /// How special values such as NaN are compared.
+        #[cfg_attr(feature = "serde", serde(default))]
+        policy: Policy,
+    },
+    /// Compare using several criteria, see [`Combined`].
+    Combined {
+        /// The tolerance to use when testing values that are close together.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        epsilon: Option<E>,
+        /// The relative tolerance for testing values that are far-apart.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        max_relative: Option<E>,
+        /// The ULPs to tolerate when testing values that are far-apart.
+        #[cfg_attr(
+            feature = "serde",
+            serde(default, skip_serializing_if = "Option::is_none")
+        )]
+        max_ulps: Option<u32>,
+        /// Whether any or all of the criteria must pass.
+        #[cfg_attr(feature = "serde", serde(default))]
+        combine: Combine,
+        /// How special values such as NaN are compared.
+        #[cfg_attr(feature = "serde", serde(default))]
+        policy: Policy,
+    },
+}
+
+impl<E> Tolerance<E>
+where
+    E: Clone + Default,
+{
+    /// Perform the equality comparison
+    pub fn eq<A, B>(&self, lhs: &A, rhs: &B) -> bool
+    where
+        A: RelativeEq<B, Epsilon = E> + UlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        self.with_builder(|builder| builder.eq(lhs, rhs))
+    }
+
+    /// Perform the inequality comparison
+    pub fn ne<A, B>(&self, lhs: &A, rhs: &B) -> bool
+    where
+        A: RelativeEq<B, Epsilon = E> + UlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        !self.eq(lhs, rhs)
+    }
+
+    /// Perform the equality comparison, reporting every position at which the values differ
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn report<A, B>(&self, lhs: &A, rhs: &B) -> Report
+    where
+        A: RelativeEq<B, Epsilon = E> + UlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        self.with_builder(|builder| builder.report(lhs, rhs))
+    }
+
+    /// Calls `f` with the builder corresponding to the tolerance, with the missing tolerances
+    /// replaced by the defaults of `A`.
+    fn with_builder<A, B, R>(&self, f: impl FnOnce(&dyn Comparator<A, B>) -> R) -> R
+    where
+        A: RelativeEq<B, Epsilon = E> + UlpsEq<B> + ?Sized,
+        B: ?Sized,
+    {
+        match self.clone() {
+            Tolerance::AbsDiff { epsilon, policy } => f(&AbsDiff::<A, B> {
+                epsilon: epsilon.unwrap_or_else(A::default_epsilon),
+                policy,
+            }),
+            Tolerance::Relative {
+                epsilon,
+                max_relative,
+                policy,
+            } => f(&Relative::<A, B> {
+                epsilon: epsilon.unwrap_or_else(A::default_epsilon),
+                max_relative: max_relative.unwrap_or_else(A::default_max_relative),
+                policy,
+            }),
+            Tolerance::Ulps {
+                epsilon,
+                max_ulps,
+                policy,
+            } => f(&Ulps::<A, B> {
+                epsilon: epsilon.unwrap_or_else(A::default_epsilon),
+                max_ulps: max_ulps.unwrap_or_else(A::default_max_ulps),
+                policy,
+            }),
+            Tolerance::Combined {
+                epsilon,
+                max_relative,
+                max_ulps,
+                combine,
+                policy,
+            } => f(&Combined::<A, B> {
+                epsilon: epsilon.unwrap_or_else(A::default_epsilon),
+                max_relative: max_relative.unwrap_or_else(A::default_max_relative),
+                max_ulps: max_ulps.unwrap_or_else(A::default_max_ulps),
+                combine,
+                policy,
+            }),
+        }
+    }
+}
+
+impl<A, B, E> Comparator<A, B> for Tolerance<E>
+where
+    A: RelativeEq<B, Epsilon = E> + UlpsEq<B> + ?Sized,
+    B: ?Sized,
+    E: Clone + Default,
+{
+    #[inline]
+    fn eq(&self, lhs: &A, rhs: &B) -> bool {
+        Tolerance::eq(self, lhs, rhs)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn report(&self, lhs: &A, rhs: &B) -> Report {
+        Tolerance::report(self, lhs, rhs)
+    }
+}
@@ -1,8 +1,22 @@
+#[cfg(feature = "btree_map_impl")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "linked_list_impl")]
+use alloc::collections::LinkedList;
+#[cfg(feature = "vec_deque_impl")]
+use alloc::collections::VecDeque;
 #[cfg(feature = "vec_impl")]
 use alloc::vec::Vec;
-use core::cell;
-#[cfg(feature = "indexmap_impl")]
+use core::cell::{self, OnceCell};
+#[cfg(any(
+    feature = "btree_map_impl",
+    feature = "hash_map_impl",
+    feature = "indexmap_impl"
+))]
+use core::fmt::Debug;
+#[cfg(any(feature = "indexmap_impl", feature = "hash_map_impl"))]
 use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "half")]
+use half::{bf16, f16 as half_f16};
 #[cfg(feature = "indexmap_impl")]
 use indexmap::IndexMap;
 #[cfg(feature = "num-complex")]
@@ -12,8 +26,30 @@ use num_traits::Float;
 use num_traits::Signed;
 #[cfg(feature = "ordered-float")]
 use ordered_float::{NotNan, OrderedFloat};
+#[cfg(feature = "hash_map_impl")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::sync::{Mutex, OnceLock, RwLock};
 
-use crate::AbsDiffEq;
+use crate::distance::narrow_both;
+#[cfg(feature = "alloc")]
+use crate::distance::AsF64;
+#[cfg(all(
+    feature = "alloc",
+    any(
+        feature = "tuple_impl",
+        feature = "num-complex",
+        feature = "indexmap_impl",
+        feature = "btree_map_impl",
+        feature = "hash_map_impl"
+    )
+))]
+use crate::report::PathSegment;
+#[cfg(feature = "alloc")]
+use crate::report::{Applied, Report};
+#[cfg(feature = "std")]
+use crate::sync::with_locked;
+use crate::{AbsDiffEq, Mixed, Policy, UlpsMode};
 
 /// Equality comparisons between two numbers using both the absolute difference and ULPs
 /// (Units in Last Place) based comparisons.
@@ -33,6 +69,65 @@ where
     fn ulps_ne(&self, other: &Rhs, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
         !Self::ulps_eq(self, other, epsilon, max_ulps)
     }
+
+    /// Performs the same comparison as [`UlpsEq::ulps_eq`], but returns a [`Report`] listing
+    /// every position at which the values differ.
+    ///
+    /// The default implementation reports a single mismatch without any details, so
+    /// implementations for containers should override it to report their elements.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn ulps_report(&self, other: &Rhs, epsilon: Self::Epsilon, max_ulps: u32) -> Report {
+        Report::from_eq(Self::ulps_eq(self, other, epsilon, max_ulps))
+    }
+
+    /// Performs the same comparison as [`UlpsEq::ulps_eq`], with special values compared
+    /// according to the given [`Policy`].
+    ///
+    /// The default implementation ignores the policy, so implementations for floating point
+    /// numbers and containers should override it.
+    fn ulps_eq_with(
+        &self,
+        other: &Rhs,
+        epsilon: Self::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        let _ = policy;
+        Self::ulps_eq(self, other, epsilon, max_ulps)
+    }
+
+    /// The inverse of [`UlpsEq::ulps_eq_with`].
+    ///
+    /// With the default policy, the default implementation calls [`UlpsEq::ulps_ne`], so that
+    /// implementations overriding it are respected.
+    fn ulps_ne_with(
+        &self,
+        other: &Rhs,
+        epsilon: Self::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        if policy == Policy::default() {
+            Self::ulps_ne(self, other, epsilon, max_ulps)
+        } else {
+            !Self::ulps_eq_with(self, other, epsilon, max_ulps, policy)
+        }
+    }
+
+    /// Performs the same comparison as [`UlpsEq::ulps_eq_with`], but returns a [`Report`] like
+    /// [`UlpsEq::ulps_report`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn ulps_report_with(
+        &self,
+        other: &Rhs,
+        epsilon: Self::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        Report::from_eq(Self::ulps_eq_with(self, other, epsilon, max_ulps, policy))
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -42,20 +137,53 @@ where
 // Implementation based on: [Comparing Floating Point Numbers, 2012 Edition]
 // (https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/)
 macro_rules! impl_ulps_eq {
-    ($T:ident, $U:ident) => {
+    ($T:ident, $U:ident, $default_max_ulps:expr) => {
         impl UlpsEq for $T {
             #[inline]
             fn default_max_ulps() -> u32 {
-                4
+                $default_max_ulps
             }
 
             #[inline]
             fn ulps_eq(&self, other: &$T, epsilon: $T, max_ulps: u32) -> bool {
+                Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[inline]
+            fn ulps_eq_with(&self, other: &$T, epsilon: $T, max_ulps: u32, policy: Policy) -> bool {
+                // Distinct zeros of opposite signs are only compared in ULPs
+                let opposite_zeros = policy.distinct_zeros
+                    && self == other
+                    && self.is_sign_negative() != other.is_sign_negative();
+
                 // For when the numbers are really close together
-                if $T::abs_diff_eq(self, other, epsilon) {
+                if !opposite_zeros && $T::abs_diff_eq_with(self, other, epsilon, policy) {
                     return true;
                 }
 
+                if policy.ulps == UlpsMode::Monotonic {
+                    if self.is_nan() || other.is_nan() {
+                        return false;
+                    }
+
+                    // Offset the sign-magnitude bits, so that negative numbers are below the
+                    // midpoint of the unsigned range, and positive numbers above it.
+                    let midpoint: $U = 1 << ($U::BITS - 1);
+                    let monotonic = |bits: $U| {
+                        let magnitude = bits & !midpoint;
+                        if bits & midpoint == 0 {
+                            midpoint + magnitude
+                        } else if policy.distinct_zeros {
+                            midpoint - 1 - magnitude
+                        } else {
+                            midpoint - magnitude
+                        }
+                    };
+                    let distance =
+                        $U::abs_diff(monotonic(self.to_bits()), monotonic(other.to_bits()));
+                    return u128::from(distance) <= u128::from(max_ulps);
+                }
+
                 // Trivial negative sign check
                 if self.signum() != other.signum() {
                     return false;
@@ -65,20 +193,143 @@ macro_rules! impl_ulps_eq {
                 let int_self: $U = self.to_bits();
                 let int_other: $U = other.to_bits();
 
-                // To be replaced with `abs_sub`, if
-                // https://github.com/rust-lang/rust/issues/62111 lands.
-                if int_self <= int_other {
-                    int_other - int_self <= max_ulps as $U
-                } else {
-                    int_self - int_other <= max_ulps as $U
+                // Widen the distance, since `max_ulps` does not fit into the bits of 16-bit floats
+                u128::from($U::abs_diff(int_self, int_other)) <= u128::from(max_ulps)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report(&self, other: &$T, epsilon: $T, max_ulps: u32) -> Report {
+                Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report_with(
+                &self,
+                other: &$T,
+                epsilon: $T,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon.as_f64()),
+                    max_ulps: Some(max_ulps),
+                    ..Applied::default()
+                };
+                let eq = $T::ulps_eq_with(self, other, epsilon, max_ulps, policy);
+                Report::leaf(eq, self, other, applied)
+            }
+        }
+    };
+}
+
+impl_ulps_eq!(f32, u32, scoped_default!(f32, max_ulps, 4));
+impl_ulps_eq!(f64, u64, scoped_default!(f64, max_ulps, 4));
+#[cfg(approxim_nightly_float)]
+impl_ulps_eq!(f16, u16, 4);
+#[cfg(approxim_nightly_float)]
+impl_ulps_eq!(f128, u128, 4);
+#[cfg(feature = "half")]
+impl_ulps_eq!(half_f16, u16, 4);
+#[cfg(feature = "half")]
+impl_ulps_eq!(bf16, u16, 4);
+
+// Same as `impl_mixed_abs_diff_eq!`, comparing the widened values, while ULPs are measured in the
+// lower precision. A finite `f64` out of the range of `f32` is never within any number of ULPs.
+macro_rules! impl_mixed_ulps_eq {
+    ($T:ident, $U:ident) => {
+        impl UlpsEq<$U> for Mixed<$T> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                <f32 as UlpsEq>::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$U, epsilon: f64, max_ulps: u32) -> bool {
+                Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[inline]
+            fn ulps_eq_with(
+                &self,
+                other: &$U,
+                epsilon: f64,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> bool {
+                let (a, b) = (f64::from(self.0), f64::from(*other));
+
+                // Distinct zeros of opposite signs are only compared in ULPs
+                let opposite_zeros =
+                    policy.distinct_zeros && a == b && a.is_sign_negative() != b.is_sign_negative();
+
+                // For when the numbers are really close together
+                if !opposite_zeros && f64::abs_diff_eq_with(&a, &b, epsilon, policy) {
+                    return true;
                 }
+                narrow_both(a, b)
+                    .is_some_and(|(a, b)| f32::ulps_eq_with(&a, &b, 0.0, max_ulps, policy))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report(&self, other: &$U, epsilon: f64, max_ulps: u32) -> Report {
+                Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report_with(
+                &self,
+                other: &$U,
+                epsilon: f64,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon),
+                    max_ulps: Some(max_ulps),
+                    ..Applied::default()
+                };
+                let eq = UlpsEq::ulps_eq_with(self, other, epsilon, max_ulps, policy);
+                Report::leaf(eq, self, other, applied)
             }
         }
     };
 }
 
-impl_ulps_eq!(f32, u32);
-impl_ulps_eq!(f64, u64);
+impl_mixed_ulps_eq!(f32, f64);
+impl_mixed_ulps_eq!(f64, f32);
+
+// Consecutive integers are one unit apart, so one ULP is one unit. Since integer arithmetic is
+// exact, no ULPs are tolerated by default.
+macro_rules! impl_int_ulps_eq {
+    ($($T:ident),*) => {$(
+        impl UlpsEq for $T {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                0
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$T, epsilon: $T, max_ulps: u32) -> bool {
+                $T::abs_diff_eq(self, other, epsilon)
+                    || u32::try_from($T::abs_diff(*self, *other))
+                        .is_ok_and(|ulps| ulps <= max_ulps)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report(&self, other: &$T, epsilon: $T, max_ulps: u32) -> Report {
+                let applied = Applied {
+                    epsilon: Some(epsilon as f64),
+                    max_ulps: Some(max_ulps),
+                    ..Applied::default()
+                };
+                let eq = $T::ulps_eq(self, other, epsilon, max_ulps);
+                Report::leaf(eq, self, other, applied)
+            }
+        }
+    )*};
+}
+
+impl_int_ulps_eq!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // Derived implementations
@@ -92,12 +343,44 @@ impl<T: UlpsEq> UlpsEq for Option<T> {
 
     #[inline]
     fn ulps_eq(&self, other: &Option<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &Option<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
         match (self, other) {
-            (Some(a), Some(b)) => T::ulps_eq(a, b, epsilon, max_ulps),
+            (Some(a), Some(b)) => T::ulps_eq_with(a, b, epsilon, max_ulps, policy),
             (None, None) => true,
             _ => false,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &Option<T>, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &Option<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        match (self, other) {
+            (Some(a), Some(b)) => T::ulps_report_with(a, b, epsilon, max_ulps, policy),
+            (None, None) => Report::new(),
+            (Some(_), None) => Report::variant("Some(..)", "None"),
+            (None, Some(_)) => Report::variant("None", "Some(..)"),
+        }
+    }
 }
 
 impl<T: UlpsEq, E: UlpsEq> UlpsEq for Result<T, E> {
@@ -112,13 +395,50 @@ impl<T: UlpsEq, E: UlpsEq> UlpsEq for Result<T, E> {
         other: &Result<T, E>,
         epsilon: (T::Epsilon, E::Epsilon),
         max_ulps: u32,
+    ) -> bool {
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &Result<T, E>,
+        epsilon: (T::Epsilon, E::Epsilon),
+        max_ulps: u32,
+        policy: Policy,
     ) -> bool {
         match (self, other) {
-            (Ok(a), Ok(b)) => T::ulps_eq(a, b, epsilon.0, max_ulps),
-            (Err(a), Err(b)) => E::ulps_eq(a, b, epsilon.1, max_ulps),
+            (Ok(a), Ok(b)) => T::ulps_eq_with(a, b, epsilon.0, max_ulps, policy),
+            (Err(a), Err(b)) => E::ulps_eq_with(a, b, epsilon.1, max_ulps, policy),
             _ => false,
         }
     }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(
+        &self,
+        other: &Result<T, E>,
+        epsilon: (T::Epsilon, E::Epsilon),
+        max_ulps: u32,
+    ) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &Result<T, E>,
+        epsilon: (T::Epsilon, E::Epsilon),
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        match (self, other) {
+            (Ok(a), Ok(b)) => T::ulps_report_with(a, b, epsilon.0, max_ulps, policy),
+            (Err(a), Err(b)) => E::ulps_report_with(a, b, epsilon.1, max_ulps, policy),
+            (Ok(_), Err(_)) => Report::variant("Ok(..)", "Err(..)"),
+            (Err(_), Ok(_)) => Report::variant("Err(..)", "Ok(..)"),
+        }
+    }
 }
 
 impl<'a, T: UlpsEq + ?Sized> UlpsEq for &'a T {
@@ -129,7 +449,34 @@ impl<'a, T: UlpsEq + ?Sized> UlpsEq for &'a T {
 
     #[inline]
     fn ulps_eq(&self, other: &&'a T, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(*self, *other, epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &&'a T,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        T::ulps_eq_with(*self, *other, epsilon, max_ulps, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &&'a T, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &&'a T,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        T::ulps_report_with(*self, *other, epsilon, max_ulps, policy)
     }
 }
 
@@ -141,7 +488,34 @@ impl<'a, T: UlpsEq + ?Sized> UlpsEq for &'a mut T {
 
     #[inline]
     fn ulps_eq(&self, other: &&'a mut T, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(*self, *other, epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &&'a mut T,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        T::ulps_eq_with(*self, *other, epsilon, max_ulps, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &&'a mut T, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &&'a mut T,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        T::ulps_report_with(*self, *other, epsilon, max_ulps, policy)
     }
 }
 
@@ -153,7 +527,34 @@ impl<T: UlpsEq + Copy> UlpsEq for cell::Cell<T> {
 
     #[inline]
     fn ulps_eq(&self, other: &cell::Cell<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(&self.get(), &other.get(), epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &cell::Cell<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        T::ulps_eq_with(&self.get(), &other.get(), epsilon, max_ulps, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &cell::Cell<T>, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &cell::Cell<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        T::ulps_report_with(&self.get(), &other.get(), epsilon, max_ulps, policy)
     }
 }
 
@@ -165,10 +566,202 @@ impl<T: UlpsEq + ?Sized> UlpsEq for cell::RefCell<T> {
 
     #[inline]
     fn ulps_eq(&self, other: &cell::RefCell<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(&self.borrow(), &other.borrow(), epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
     }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &cell::RefCell<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        T::ulps_eq_with(&self.borrow(), &other.borrow(), epsilon, max_ulps, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &cell::RefCell<T>, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &cell::RefCell<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        T::ulps_report_with(&self.borrow(), &other.borrow(), epsilon, max_ulps, policy)
+    }
+}
+
+// Smart pointers and owned wrappers compare the values they point to.
+macro_rules! impl_deref_ulps_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Type:ty => $T:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> UlpsEq for $Type
+        where
+            $T: UlpsEq,
+        {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                <$T as UlpsEq>::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Type, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[inline]
+            fn ulps_eq_with(
+                &self,
+                other: &$Type,
+                epsilon: Self::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> bool {
+                <$T as UlpsEq>::ulps_eq_with(&**self, &**other, epsilon, max_ulps, policy)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report(&self, other: &$Type, epsilon: Self::Epsilon, max_ulps: u32) -> Report {
+                Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report_with(
+                &self,
+                other: &$Type,
+                epsilon: Self::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> Report {
+                <$T as UlpsEq>::ulps_report_with(&**self, &**other, epsilon, max_ulps, policy)
+            }
+        }
+    )*};
+}
+
+impl_deref_ulps_eq! {
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::boxed::Box<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::rc::Rc<T> => T;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    [T: ?Sized] alloc::sync::Arc<T> => T;
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    ['a, T: alloc::borrow::ToOwned + ?Sized] alloc::borrow::Cow<'a, T> => T;
+    [P: core::ops::Deref] core::pin::Pin<P> => P::Target;
 }
 
+// Locks compare the values they protect, see `sync::with_locked` for the poisoning and deadlocks.
+#[cfg(feature = "std")]
+macro_rules! impl_lock_ulps_eq {
+    ($($Lock:ident),*) => {$(
+        #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+        impl<T: UlpsEq + ?Sized> UlpsEq for $Lock<T> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Lock<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[inline]
+            fn ulps_eq_with(
+                &self,
+                other: &$Lock<T>,
+                epsilon: T::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> bool {
+                with_locked(self, other, |a, b| T::ulps_eq_with(a, b, epsilon, max_ulps, policy))
+            }
+
+            fn ulps_report(&self, other: &$Lock<T>, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+                Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            fn ulps_report_with(
+                &self,
+                other: &$Lock<T>,
+                epsilon: T::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> Report {
+                with_locked(self, other, |a, b| {
+                    T::ulps_report_with(a, b, epsilon, max_ulps, policy)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "std")]
+impl_lock_ulps_eq!(Mutex, RwLock);
+
+// Cells which are initialized once compare like an `Option` of their value.
+macro_rules! impl_once_ulps_eq {
+    ($(#[$attr:meta])* $Once:ident) => {
+        $(#[$attr])*
+        impl<T: UlpsEq> UlpsEq for $Once<T> {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Once<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[inline]
+            fn ulps_eq_with(
+                &self,
+                other: &$Once<T>,
+                epsilon: T::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> bool {
+                Option::ulps_eq_with(&self.get(), &other.get(), epsilon, max_ulps, policy)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report(&self, other: &$Once<T>, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+                Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report_with(
+                &self,
+                other: &$Once<T>,
+                epsilon: T::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> Report {
+                Option::ulps_report_with(&self.get(), &other.get(), epsilon, max_ulps, policy)
+            }
+        }
+    };
+}
+
+impl_once_ulps_eq!(OnceCell);
+#[cfg(feature = "std")]
+impl_once_ulps_eq!(
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    OnceLock
+);
+
 impl<A, B> UlpsEq<[B]> for [A]
 where
     A: UlpsEq<B>,
@@ -181,9 +774,38 @@ where
 
     #[inline]
     fn ulps_eq(&self, other: &[B], epsilon: A::Epsilon, max_ulps: u32) -> bool {
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &[B],
+        epsilon: A::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
         self.len() == other.len()
             && Iterator::zip(self.iter(), other)
-                .all(|(x, y)| A::ulps_eq(x, y, epsilon.clone(), max_ulps))
+                .all(|(x, y)| A::ulps_eq_with(x, y, epsilon.clone(), max_ulps, policy))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &[B], epsilon: A::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &[B],
+        epsilon: A::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::ulps_report_with(x, y, epsilon.clone(), max_ulps, policy)
+        })
     }
 }
 
@@ -201,9 +823,38 @@ where
 
     #[inline]
     fn ulps_eq(&self, other: &[B; N], epsilon: A::Epsilon, max_ulps: u32) -> bool {
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &[B; N],
+        epsilon: A::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
         self.len() == other.len()
             && Iterator::zip(self.iter(), other)
-                .all(|(x, y)| A::ulps_eq(x, y, epsilon.clone(), max_ulps.clone()))
+                .all(|(x, y)| A::ulps_eq_with(x, y, epsilon.clone(), max_ulps, policy))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &[B; N], epsilon: A::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &[B; N],
+        epsilon: A::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::ulps_report_with(x, y, epsilon.clone(), max_ulps, policy)
+        })
     }
 }
 
@@ -221,9 +872,38 @@ where
 
     #[inline]
     fn ulps_eq(&self, other: &Vec<B>, epsilon: A::Epsilon, max_ulps: u32) -> bool {
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &Vec<B>,
+        epsilon: A::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
         self.len() == other.len()
             && Iterator::zip(self.iter(), other)
-                .all(|(x, y)| A::ulps_eq(x, y, epsilon.clone(), max_ulps.clone()))
+                .all(|(x, y)| A::ulps_eq_with(x, y, epsilon.clone(), max_ulps, policy))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &Vec<B>, epsilon: A::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &Vec<B>,
+        epsilon: A::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        Report::sequence(self, other, |x, y| {
+            A::ulps_report_with(x, y, epsilon.clone(), max_ulps, policy)
+        })
     }
 }
 
@@ -263,7 +943,47 @@ macro_rules! impl_ulps_eq {
                     epsilon: Self::Epsilon,
                     max_ulps: u32
                 ) -> bool {
-                    true $( && self.$idx.ulps_eq(&other.$idx, epsilon.$idx, max_ulps) )+
+                    Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+                }
+
+                fn ulps_eq_with(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    max_ulps: u32,
+                    policy: Policy
+                ) -> bool {
+                    true $(
+                        && self.$idx.ulps_eq_with(&other.$idx, epsilon.$idx, max_ulps, policy)
+                    )+
+                }
+
+                #[cfg(feature = "alloc")]
+                fn ulps_report(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    max_ulps: u32
+                ) -> Report {
+                    Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+                }
+
+                #[cfg(feature = "alloc")]
+                fn ulps_report_with(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    max_ulps: u32,
+                    policy: Policy
+                ) -> Report {
+                    let mut report = Report::new();
+                    $(
+                        report.nest(
+                            PathSegment::Tuple($idx),
+                            self.$idx.ulps_report_with(&other.$idx, epsilon.$idx, max_ulps, policy),
+                        );
+                    )+
+                    report
                 }
             }
         }
@@ -303,8 +1023,44 @@ where
 
     #[inline]
     fn ulps_eq(&self, other: &Complex<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(&self.re, &other.re, epsilon.clone(), max_ulps)
-            && T::ulps_eq(&self.im, &other.im, epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &Complex<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        T::ulps_eq_with(&self.re, &other.re, epsilon.clone(), max_ulps, policy)
+            && T::ulps_eq_with(&self.im, &other.im, epsilon, max_ulps, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &Complex<T>, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &Complex<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        let mut report = Report::new();
+        report.nest(
+            PathSegment::Field("re"),
+            T::ulps_report_with(&self.re, &other.re, epsilon.clone(), max_ulps, policy),
+        );
+        report.nest(
+            PathSegment::Field("im"),
+            T::ulps_report_with(&self.im, &other.im, epsilon, max_ulps, policy),
+        );
+        report
     }
 }
 
@@ -318,7 +1074,46 @@ impl<T: UlpsEq + Copy> UlpsEq for NotNan<T> {
 
     #[inline]
     fn ulps_eq(&self, other: &NotNan<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(&self.into_inner(), &other.into_inner(), epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &NotNan<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        T::ulps_eq_with(
+            &self.into_inner(),
+            &other.into_inner(),
+            epsilon,
+            max_ulps,
+            policy,
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &NotNan<T>, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &NotNan<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        T::ulps_report_with(
+            &self.into_inner(),
+            &other.into_inner(),
+            epsilon,
+            max_ulps,
+            policy,
+        )
     }
 }
 
@@ -332,7 +1127,28 @@ impl<T: UlpsEq + Float + ordered_float::FloatCore> UlpsEq<T> for NotNan<T> {
 
     #[inline]
     fn ulps_eq(&self, other: &T, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(&self.into_inner(), other, epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(&self, other: &T, epsilon: T::Epsilon, max_ulps: u32, policy: Policy) -> bool {
+        T::ulps_eq_with(&self.into_inner(), other, epsilon, max_ulps, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &T, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &T,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        T::ulps_report_with(&self.into_inner(), other, epsilon, max_ulps, policy)
     }
 }
 
@@ -346,7 +1162,46 @@ impl<T: UlpsEq + Float + ordered_float::FloatCore> UlpsEq for OrderedFloat<T> {
 
     #[inline]
     fn ulps_eq(&self, other: &OrderedFloat<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(&self.into_inner(), &other.into_inner(), epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &OrderedFloat<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
+        T::ulps_eq_with(
+            &self.into_inner(),
+            &other.into_inner(),
+            epsilon,
+            max_ulps,
+            policy,
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &OrderedFloat<T>, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &OrderedFloat<T>,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        T::ulps_report_with(
+            &self.into_inner(),
+            &other.into_inner(),
+            epsilon,
+            max_ulps,
+            policy,
+        )
     }
 }
 
@@ -360,7 +1215,28 @@ impl<T: UlpsEq + Float + ordered_float::FloatCore> UlpsEq<T> for OrderedFloat<T>
 
     #[inline]
     fn ulps_eq(&self, other: &T, epsilon: T::Epsilon, max_ulps: u32) -> bool {
-        T::ulps_eq(&self.into_inner(), other, epsilon, max_ulps)
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(&self, other: &T, epsilon: T::Epsilon, max_ulps: u32, policy: Policy) -> bool {
+        T::ulps_eq_with(&self.into_inner(), other, epsilon, max_ulps, policy)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(&self, other: &T, epsilon: T::Epsilon, max_ulps: u32) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &T,
+        epsilon: T::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        T::ulps_report_with(&self.into_inner(), other, epsilon, max_ulps, policy)
     }
 }
 
@@ -368,7 +1244,7 @@ impl<T: UlpsEq + Float + ordered_float::FloatCore> UlpsEq<T> for OrderedFloat<T>
 #[cfg_attr(docsrs, doc(cfg(feature = "indexmap_impl")))]
 impl<K, V1, V2, S1, S2> UlpsEq<IndexMap<K, V2, S2>> for IndexMap<K, V1, S1>
 where
-    K: Hash + Eq,
+    K: Hash + Eq + Debug,
     V1: UlpsEq<V2>,
     V1::Epsilon: Clone,
     S1: BuildHasher,
@@ -381,11 +1257,293 @@ where
 
     #[inline]
     fn ulps_eq(&self, other: &IndexMap<K, V2, S2>, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[inline]
+    fn ulps_eq_with(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        epsilon: Self::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> bool {
         self.len() == other.len()
             && self.iter().all(|(key, value)| {
-                other.get(key).map_or(false, |v| {
-                    V1::ulps_eq(value, v, epsilon.clone(), max_ulps.clone())
-                })
+                other
+                    .get(key)
+                    .is_some_and(|v| V1::ulps_eq_with(value, v, epsilon.clone(), max_ulps, policy))
             })
     }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        epsilon: Self::Epsilon,
+        max_ulps: u32,
+    ) -> Report {
+        Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ulps_report_with(
+        &self,
+        other: &IndexMap<K, V2, S2>,
+        epsilon: Self::Epsilon,
+        max_ulps: u32,
+        policy: Policy,
+    ) -> Report {
+        if self.len() != other.len() {
+            return Report::length(self.len(), other.len());
+        }
+        let mut report = Report::new();
+        for (key, value) in self {
+            let nested = match other.get(key) {
+                Some(v) => V1::ulps_report_with(value, v, epsilon.clone(), max_ulps, policy),
+                None => Report::missing_key(),
+            };
+            report.nest(PathSegment::key(key), nested);
+        }
+        report
+    }
+}
+
+// Sequences other than slices compare element-wise like `Vec`.
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+macro_rules! impl_sequence_ulps_eq {
+    ($($(#[$attr:meta])* $Seq:ident;)*) => {$(
+        $(#[$attr])*
+        impl<A, B> UlpsEq<$Seq<B>> for $Seq<A>
+        where
+            A: UlpsEq<B>,
+            A::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                A::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Seq<B>, epsilon: A::Epsilon, max_ulps: u32) -> bool {
+                Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[inline]
+            fn ulps_eq_with(
+                &self,
+                other: &$Seq<B>,
+                epsilon: A::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && Iterator::zip(self.iter(), other)
+                        .all(|(x, y)| A::ulps_eq_with(x, y, epsilon.clone(), max_ulps, policy))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report(&self, other: &$Seq<B>, epsilon: A::Epsilon, max_ulps: u32) -> Report {
+                Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report_with(
+                &self,
+                other: &$Seq<B>,
+                epsilon: A::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> Report {
+                Report::sequence(self, other, |x, y| {
+                    A::ulps_report_with(x, y, epsilon.clone(), max_ulps, policy)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "vec_deque_impl", feature = "linked_list_impl"))]
+impl_sequence_ulps_eq! {
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    VecDeque;
+    #[cfg(feature = "linked_list_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "linked_list_impl")))]
+    LinkedList;
+}
+
+// Maps compare the values of the same keys like `IndexMap`, and locate mismatches by key.
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+macro_rules! impl_map_ulps_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*, V1, V2> UlpsEq<$Right> for $Left
+        where
+            V1: UlpsEq<V2>,
+            V1::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                V1::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Right, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[inline]
+            fn ulps_eq_with(
+                &self,
+                other: &$Right,
+                epsilon: Self::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && self.iter().all(|(key, value)| {
+                        other.get(key).is_some_and(|v| {
+                            V1::ulps_eq_with(value, v, epsilon.clone(), max_ulps, policy)
+                        })
+                    })
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report(&self, other: &$Right, epsilon: Self::Epsilon, max_ulps: u32) -> Report {
+                Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report_with(
+                &self,
+                other: &$Right,
+                epsilon: Self::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> Report {
+                if self.len() != other.len() {
+                    return Report::length(self.len(), other.len());
+                }
+                let mut report = Report::new();
+                for (key, value) in self {
+                    let nested = match other.get(key) {
+                        Some(v) => {
+                            V1::ulps_report_with(value, v, epsilon.clone(), max_ulps, policy)
+                        }
+                        None => Report::missing_key(),
+                    };
+                    report.nest(PathSegment::key(key), nested);
+                }
+                report
+            }
+        }
+    )*};
+}
+
+#[cfg(any(feature = "btree_map_impl", feature = "hash_map_impl"))]
+impl_map_ulps_eq! {
+    #[cfg(feature = "btree_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "btree_map_impl")))]
+    [K: Ord + Debug] BTreeMap<K, V1> => BTreeMap<K, V2>;
+    #[cfg(feature = "hash_map_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "hash_map_impl")))]
+    [K: Hash + Eq + Debug, S1: BuildHasher, S2: BuildHasher] HashMap<K, V1, S1> => HashMap<K, V2, S2>;
+}
+
+// Different kinds of sequences compare element-wise, so that eg. a `Vec` can be compared with an
+// array literal.
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+macro_rules! impl_cross_ulps_eq {
+    ($($(#[$attr:meta])* [$($generics:tt)*] $Left:ty => $Right:ty;)*) => {$(
+        $(#[$attr])*
+        impl<$($generics)*> UlpsEq<$Right> for $Left
+        where
+            A: UlpsEq<B>,
+            A::Epsilon: Clone,
+        {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                A::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &$Right, epsilon: A::Epsilon, max_ulps: u32) -> bool {
+                Self::ulps_eq_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[inline]
+            fn ulps_eq_with(
+                &self,
+                other: &$Right,
+                epsilon: A::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> bool {
+                self.len() == other.len()
+                    && Iterator::zip(self.iter(), other.iter())
+                        .all(|(x, y)| A::ulps_eq_with(x, y, epsilon.clone(), max_ulps, policy))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report(&self, other: &$Right, epsilon: A::Epsilon, max_ulps: u32) -> Report {
+                Self::ulps_report_with(self, other, epsilon, max_ulps, Policy::default())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn ulps_report_with(
+                &self,
+                other: &$Right,
+                epsilon: A::Epsilon,
+                max_ulps: u32,
+                policy: Policy,
+            ) -> Report {
+                Report::sequence(self.iter(), other.iter(), |x, y| {
+                    A::ulps_report_with(x, y, epsilon.clone(), max_ulps, policy)
+                })
+            }
+        }
+    )*};
+}
+
+#[cfg(any(
+    feature = "vec_impl",
+    feature = "array_impl",
+    feature = "vec_deque_impl"
+))]
+impl_cross_ulps_eq! {
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] Vec<A> => [B; N];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B, const N: usize] [A; N] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] Vec<A> => [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    [A, B] [A] => Vec<B>;
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] Vec<A> => &'a [B];
+    #[cfg(feature = "vec_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_impl")))]
+    ['a, A, B] &'a [A] => Vec<B>;
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A; N] => [B];
+    #[cfg(feature = "array_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "array_impl")))]
+    [A, B, const N: usize] [A] => [B; N];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] VecDeque<A> => [B];
+    #[cfg(feature = "vec_deque_impl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "vec_deque_impl")))]
+    [A, B] [A] => VecDeque<B>;
 }
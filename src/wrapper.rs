@@ -0,0 +1,172 @@
+use crate::{AbsDiff, AbsDiffEq, NanPolicy, Relative, RelativeEq, Ulps, UlpsEq, UlpsMode};
+
+/// A value bundled with the tolerance it is compared with, for use wherever [`PartialEq`] is
+/// expected.
+///
+/// The tolerance is one of the [`AbsDiff`], [`Relative`] or [`Ulps`] builders, which decides
+/// which of [`AbsDiffEq`], [`RelativeEq`] or [`UlpsEq`] is used. A wrapper compares equal to a
+/// plain value, and to another wrapper, in which case the values must be close according to both
+/// tolerances, so that `==` stays symmetric.
+///
+/// The [`Debug`](core::fmt::Debug) output shows the tolerance next to the value, so that failing
+/// assertions tell how close the values had to be.
+///
+/// # Example
+///
+/// ```rust
+/// use approxim::Approx;
+///
+/// let values = [0.1, 0.2 + 1e-12, 0.3];
+///
+/// assert_eq!(Approx::relative(0.1 + 0.2).max_relative(1e-9), 0.3);
+/// assert_eq!(values.iter().position(|&x| Approx::abs_diff(0.2).epsilon(1e-9) == x), Some(1));
+/// assert!(values.map(Approx::ulps).contains(&Approx::ulps(0.1)));
+/// ```
+///
+/// # Operand order
+///
+/// When compared with a plain value, the wrapper must be the left operand of `==`, and of
+/// `assert_eq!`. There is no `PartialEq<Approx<T, C>>` for `T`: it cannot be implemented for
+/// every `T`, and implementing it for the primitive numbers would break type inference in
+/// comparisons such as `x == y.into()`, which rely on a primitive only being comparable with
+/// itself.
+///
+/// ```compile_fail
+/// use approxim::Approx;
+///
+/// assert_eq!(0.3, Approx::relative(0.1 + 0.2));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Approx<T, C> {
+    /// The wrapped value.
+    pub value: T,
+    /// The tolerance to compare the value with.
+    pub tolerance: C,
+}
+
+impl<T, C> Approx<T, C> {
+    /// Wrap a value with the given tolerance.
+    #[inline]
+    pub fn new(value: T, tolerance: C) -> Approx<T, C> {
+        Approx { value, tolerance }
+    }
+
+    /// Unwrap the value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: AbsDiffEq> Approx<T, AbsDiff<T>> {
+    /// Wrap a value to be compared using [`AbsDiffEq`], with the default tolerance.
+    #[inline]
+    pub fn abs_diff(value: T) -> Approx<T, AbsDiff<T>> {
+        Approx::new(value, AbsDiff::default())
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Approx<T, AbsDiff<T>> {
+        Approx::new(self.value, self.tolerance.epsilon(epsilon))
+    }
+
+    /// Replace the NaN policy with the one specified, see [`AbsDiff::nan_equal`].
+    #[inline]
+    pub fn nan_equal(self, nan_equal: impl Into<NanPolicy>) -> Approx<T, AbsDiff<T>> {
+        Approx::new(self.value, self.tolerance.nan_equal(nan_equal))
+    }
+}
+
+impl<T: RelativeEq> Approx<T, Relative<T>> {
+    /// Wrap a value to be compared using [`RelativeEq`], with the default tolerances.
+    #[inline]
+    pub fn relative(value: T) -> Approx<T, Relative<T>> {
+        Approx::new(value, Relative::default())
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Approx<T, Relative<T>> {
+        Approx::new(self.value, self.tolerance.epsilon(epsilon))
+    }
+
+    /// Replace the maximum relative value with the one specified.
+    #[inline]
+    pub fn max_relative(self, max_relative: T::Epsilon) -> Approx<T, Relative<T>> {
+        Approx::new(self.value, self.tolerance.max_relative(max_relative))
+    }
+
+    /// Replace the NaN policy with the one specified, see [`Relative::nan_equal`].
+    #[inline]
+    pub fn nan_equal(self, nan_equal: impl Into<NanPolicy>) -> Approx<T, Relative<T>> {
+        Approx::new(self.value, self.tolerance.nan_equal(nan_equal))
+    }
+}
+
+impl<T: UlpsEq> Approx<T, Ulps<T>> {
+    /// Wrap a value to be compared using [`UlpsEq`], with the default tolerances.
+    #[inline]
+    pub fn ulps(value: T) -> Approx<T, Ulps<T>> {
+        Approx::new(value, Ulps::default())
+    }
+
+    /// Replace the epsilon value with the one specified.
+    #[inline]
+    pub fn epsilon(self, epsilon: T::Epsilon) -> Approx<T, Ulps<T>> {
+        Approx::new(self.value, self.tolerance.epsilon(epsilon))
+    }
+
+    /// Replace the max ulps value with the one specified.
+    #[inline]
+    pub fn max_ulps(self, max_ulps: u32) -> Approx<T, Ulps<T>> {
+        Approx::new(self.value, self.tolerance.max_ulps(max_ulps))
+    }
+
+    /// Replace the NaN policy with the one specified, see [`Ulps::nan_equal`].
+    #[inline]
+    pub fn nan_equal(self, nan_equal: impl Into<NanPolicy>) -> Approx<T, Ulps<T>> {
+        Approx::new(self.value, self.tolerance.nan_equal(nan_equal))
+    }
+
+    /// Replace the way the distance in ULPs is measured, see [`Ulps::ulps_mode`].
+    #[inline]
+    pub fn ulps_mode(self, ulps_mode: UlpsMode) -> Approx<T, Ulps<T>> {
+        Approx::new(self.value, self.tolerance.ulps_mode(ulps_mode))
+    }
+
+    /// Specify whether `+0.0` and `-0.0` are distinct, see [`Ulps::distinct_zeros`].
+    #[inline]
+    pub fn distinct_zeros(self, distinct_zeros: bool) -> Approx<T, Ulps<T>> {
+        Approx::new(self.value, self.tolerance.distinct_zeros(distinct_zeros))
+    }
+}
+
+macro_rules! impl_approx_partial_eq {
+    ($Builder:ident, $Trait:ident) => {
+        impl<T: $Trait> PartialEq<T> for Approx<T, $Builder<T>>
+        where
+            T::Epsilon: Clone,
+        {
+            #[inline]
+            fn eq(&self, other: &T) -> bool {
+                self.tolerance.clone().eq(&self.value, other)
+            }
+        }
+
+        impl<T: $Trait> PartialEq for Approx<T, $Builder<T>>
+        where
+            T::Epsilon: Clone,
+        {
+            #[inline]
+            fn eq(&self, other: &Approx<T, $Builder<T>>) -> bool {
+                self.tolerance.clone().eq(&self.value, &other.value)
+                    && other.tolerance.clone().eq(&other.value, &self.value)
+            }
+        }
+    };
+}
+
+impl_approx_partial_eq!(AbsDiff, AbsDiffEq);
+impl_approx_partial_eq!(Relative, RelativeEq);
+impl_approx_partial_eq!(Ulps, UlpsEq);
@@ -15,6 +15,7 @@
 // Test cases derived from:
 // https://github.com/Pybonacci/puntoflotante.org/blob/master/content/errors/NearlyEqualsTest.java
 #![no_std]
+#![cfg_attr(approxim_nightly_float, feature(f16, f128))]
 
 #[macro_use]
 extern crate approxim;
@@ -390,6 +391,133 @@ mod test_f64 {
     }
 }
 
+mod test_int {
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(1u8, 1u8);
+        assert_abs_diff_eq!(3u32, 5u32, epsilon = 2);
+        assert_abs_diff_ne!(3u32, 6u32, epsilon = 2);
+        assert_abs_diff_eq!(-3i64, 2i64, epsilon = 5);
+        assert_abs_diff_ne!(-3i64, 2i64, epsilon = -5);
+    }
+
+    #[test]
+    fn test_i128() {
+        assert_abs_diff_eq!(-1i128, 1i128, epsilon = 2);
+        assert_abs_diff_ne!(-1i128, 1i128, epsilon = 1);
+        assert_abs_diff_eq!(i128::MAX, i128::MAX - 7, epsilon = 7);
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_abs_diff_ne!(i8::MIN, i8::MAX, epsilon = i8::MAX);
+        assert_abs_diff_ne!(i128::MIN, i128::MAX, epsilon = i128::MAX);
+        assert_abs_diff_ne!(i128::MAX, i128::MIN, epsilon = i128::MAX);
+        assert_abs_diff_eq!(i128::MIN, -1i128, epsilon = i128::MAX);
+        assert_abs_diff_eq!(0u128, u128::MAX, epsilon = u128::MAX);
+        assert_abs_diff_ne!(u128::MAX, 0u128, epsilon = u128::MAX - 1);
+    }
+}
+
+mod test_wrapping {
+    use core::num::Wrapping;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(Wrapping(7u32), Wrapping(7u32));
+        assert_abs_diff_eq!(Wrapping(7u32), Wrapping(9u32), epsilon = 2);
+        assert_abs_diff_ne!(Wrapping(7u32), Wrapping(10u32), epsilon = 2);
+        assert_abs_diff_eq!(Wrapping(-2i16), Wrapping(2i16), epsilon = 4);
+    }
+
+    #[test]
+    fn test_wrap_around() {
+        assert_abs_diff_eq!(Wrapping(u8::MAX), Wrapping(0u8), epsilon = 1);
+        assert_abs_diff_eq!(Wrapping(1u64), Wrapping(u64::MAX - 1), epsilon = 3);
+        assert_abs_diff_ne!(Wrapping(1u64), Wrapping(u64::MAX - 1), epsilon = 2);
+        assert_abs_diff_eq!(Wrapping(i128::MIN), Wrapping(i128::MAX), epsilon = 1);
+        assert_abs_diff_ne!(Wrapping(i32::MIN), Wrapping(0i32), epsilon = i32::MAX);
+        assert_abs_diff_ne!(Wrapping(1i32), Wrapping(1i32), epsilon = -1);
+    }
+}
+
+mod test_saturating {
+    use core::num::Saturating;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(Saturating(7u32), Saturating(9u32), epsilon = 2);
+        assert_abs_diff_ne!(Saturating(u8::MAX), Saturating(0u8), epsilon = 1);
+        assert_abs_diff_eq!(Saturating(1.0f64), Saturating(1.0f64));
+        assert_abs_diff_ne!(Saturating(i128::MIN), Saturating(i128::MAX), epsilon = i128::MAX);
+    }
+}
+
+mod test_non_zero {
+    use core::num::{NonZeroI128, NonZeroU32};
+
+    #[test]
+    fn test_basic() {
+        let a = NonZeroU32::new(7).unwrap();
+        let b = NonZeroU32::new(9).unwrap();
+        assert_abs_diff_eq!(a, a);
+        assert_abs_diff_eq!(a, b, epsilon = 2);
+        assert_abs_diff_ne!(a, b, epsilon = 1);
+    }
+
+    #[test]
+    fn test_extremes() {
+        let a = NonZeroI128::new(i128::MIN).unwrap();
+        let b = NonZeroI128::new(i128::MAX).unwrap();
+        assert_abs_diff_ne!(a, b, epsilon = i128::MAX);
+    }
+}
+
+mod test_mixed {
+    use approxim::Mixed;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(Mixed(0.1f32), 0.1f64);
+        assert_abs_diff_eq!(Mixed(0.1f64), 0.1f32);
+        assert_abs_diff_ne!(Mixed(0.1f32), 0.1f64, epsilon = 1e-10);
+        assert_abs_diff_ne!(Mixed(1.0f64), 1.5f32, epsilon = 0.25);
+        assert_abs_diff_ne!(Mixed(1.0f32), 1.00000001f64, epsilon = 0.0);
+    }
+
+    #[test]
+    fn test_literal_inference() {
+        let x = 0.1f32;
+        assert_abs_diff_eq!(x, 0.1, epsilon = 0.0);
+        assert!(approxim::abs_diff_eq!(x, 0.1, epsilon = 0.0));
+        assert_abs_diff_eq!(x, 0.1, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_slice() {
+        assert_abs_diff_eq!([0.1f32, 0.2].map(Mixed)[..], [0.1f64, 0.2][..]);
+        assert_abs_diff_ne!([0.1f32, 0.2].map(Mixed)[..], [0.1f64, 0.3][..]);
+        assert_abs_diff_ne!([0.1f32, 0.2].map(Mixed)[..], [0.1f64, 0.2][..], epsilon = 0.0);
+    }
+
+    #[cfg(feature = "array_impl")]
+    #[test]
+    fn test_array() {
+        assert_abs_diff_eq!([0.1f64, 0.2].map(Mixed), [0.1f32, 0.2]);
+    }
+
+    #[cfg(feature = "vec_impl")]
+    #[test]
+    fn test_vec() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let computed = Vec::from([Mixed(0.1f32), Mixed(0.2)]);
+        assert_abs_diff_eq!(computed, Vec::from([0.1f64, 0.2]));
+        assert_abs_diff_ne!(computed, Vec::from([0.1f64]));
+    }
+}
+
 mod test_option {
     mod test_f32 {
         #[test]
@@ -519,6 +647,65 @@ mod test_vec {
     }
 }
 
+mod test_pin {
+    use core::pin::Pin;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(Pin::new(&1.0f64), Pin::new(&1.0f64));
+        assert_abs_diff_ne!(Pin::new(&1.0f64), Pin::new(&2.0f64));
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_pointer {
+    extern crate alloc;
+    use alloc::borrow::Cow;
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+    use core::pin::Pin;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(Box::new(1.0f64), Box::new(1.0f64));
+        assert_abs_diff_ne!(Box::new(1.0f64), Box::new(2.0f64));
+        assert_abs_diff_eq!(Rc::new(1.0f32), Rc::new(1.0f32));
+        assert_abs_diff_ne!(Rc::new(1.0f32), Rc::new(2.0f32));
+        assert_abs_diff_eq!(Arc::new(Some(1.0f64)), Arc::new(Some(1.0f64)));
+        assert_abs_diff_ne!(Arc::new(Some(1.0f64)), Arc::new(None));
+        assert_abs_diff_eq!(Box::pin(1.0f64), Box::pin(1.0f64));
+        assert_abs_diff_ne!(Box::pin(1.0f64), Box::pin(2.0f64));
+    }
+
+    #[test]
+    fn test_slice() {
+        let a: Box<[f64]> = Box::new([1.0, 2.0]);
+        let b: Arc<[f64]> = Arc::from([1.0, 2.0]);
+        assert_abs_diff_eq!(a, Box::from([1.0, 2.0]));
+        assert_abs_diff_ne!(a, Box::from([1.0]));
+        assert_abs_diff_eq!(b, Arc::from([1.0, 2.0]));
+        assert_abs_diff_ne!(b, Arc::from([2.0, 1.0]));
+        assert_abs_diff_eq!(Rc::new(Box::new(1.0f64)), Rc::new(Box::new(1.0f64)));
+        assert_abs_diff_eq!(Pin::new(Box::new(1.0f64)), Pin::new(Box::new(1.0f64)));
+    }
+
+    #[test]
+    fn test_cow() {
+        let borrowed: Cow<'_, [f64]> = Cow::Borrowed(&[1.0, 2.0]);
+        let owned: Cow<'_, [f64]> = Cow::Owned([1.0, 2.0].into());
+        assert_abs_diff_eq!(borrowed, owned);
+        assert_abs_diff_ne!(borrowed, Cow::Borrowed(&[1.0, 3.0][..]));
+        assert_abs_diff_eq!(Cow::<f64>::Owned(1.0), Cow::Borrowed(&1.0));
+    }
+
+    #[test]
+    fn test_tolerance() {
+        assert_abs_diff_eq!(Arc::new(1.0f64), Arc::new(1.5f64), epsilon = 0.5);
+        assert_abs_diff_ne!(Arc::new(1.0f64), Arc::new(1.5f64), epsilon = 0.4);
+    }
+}
+
 #[cfg(feature = "tuple_impl")]
 mod test_tuple{
     use approxim::AbsDiffEq;
@@ -591,6 +778,85 @@ mod test_complex {
     }
 }
 
+#[cfg(approxim_nightly_float)]
+mod test_nightly_float {
+    mod test_f16 {
+        #[test]
+        fn test_basic() {
+            assert_abs_diff_eq!(1.0f16, 1.0f16);
+            assert_abs_diff_ne!(1.0f16, 2.0f16);
+            assert_abs_diff_eq!(1.0f16, 1.0f16 + f16::EPSILON);
+            assert_abs_diff_eq!(100.0f16, 100.5f16, epsilon = 0.5);
+            assert_abs_diff_ne!(f16::NAN, f16::NAN);
+        }
+    }
+
+    mod test_f128 {
+        #[test]
+        fn test_basic() {
+            assert_abs_diff_eq!(1.0f128, 1.0f128);
+            assert_abs_diff_ne!(1.0f128, 2.0f128);
+            assert_abs_diff_eq!(1.0f128, 1.0f128 + f128::EPSILON);
+            assert_abs_diff_ne!(1.0f128, 1.0f128 + 1e-30, epsilon = 1e-31);
+            assert_abs_diff_ne!(f128::NAN, f128::NAN);
+        }
+    }
+}
+
+#[cfg(feature = "half")]
+mod test_half {
+    extern crate half;
+    pub use self::half::{bf16, f16};
+
+    mod test_f16 {
+        use super::f16;
+
+        #[test]
+        fn test_basic() {
+            assert_abs_diff_eq!(f16::from_f32(1.0), f16::from_f32(1.0));
+            assert_abs_diff_ne!(f16::from_f32(1.0), f16::from_f32(2.0));
+        }
+
+        #[test]
+        fn test_default_epsilon() {
+            let next = f16::from_bits(f16::ONE.to_bits() + 1);
+            assert_abs_diff_eq!(f16::ONE, next);
+            assert_abs_diff_ne!(f16::ONE, next, epsilon = f16::ZERO);
+        }
+
+        #[test]
+        fn test_epsilon() {
+            let a = f16::from_f32(100.0);
+            let b = f16::from_f32(100.5);
+            assert_abs_diff_eq!(a, b, epsilon = f16::from_f32(0.5));
+            assert_abs_diff_ne!(a, b, epsilon = f16::from_f32(0.25));
+        }
+
+        #[test]
+        fn test_nan() {
+            assert_abs_diff_ne!(f16::NAN, f16::NAN);
+            assert_abs_diff_ne!(f16::NAN, f16::ZERO);
+        }
+    }
+
+    mod test_bf16 {
+        use super::bf16;
+
+        #[test]
+        fn test_basic() {
+            assert_abs_diff_eq!(bf16::from_f32(1.0), bf16::from_f32(1.0));
+            assert_abs_diff_ne!(bf16::from_f32(1.0), bf16::from_f32(2.0));
+        }
+
+        #[test]
+        fn test_default_epsilon() {
+            let next = bf16::from_bits(bf16::ONE.to_bits() + 1);
+            assert_abs_diff_eq!(bf16::ONE, next);
+            assert_abs_diff_ne!(bf16::ONE, next, epsilon = bf16::ZERO);
+        }
+    }
+}
+
 #[cfg(feature = "ordered-float")]
 mod test_ordered_float {
     extern crate ordered_float;
@@ -665,3 +931,91 @@ mod test_indexmap {
         );
     }
 }
+
+#[cfg(feature = "vec_deque_impl")]
+mod test_vec_deque {
+    extern crate alloc;
+    use alloc::collections::VecDeque;
+
+    #[test]
+    fn test_basic() {
+        let mut a = VecDeque::from([2.0f64, 3.0]);
+        a.push_front(1.0);
+        assert_abs_diff_eq!(a, VecDeque::from([1.0f64, 2.0, 3.0]));
+        assert_abs_diff_ne!(a, VecDeque::from([1.0f64, 2.0, 4.0]));
+        assert_abs_diff_ne!(a, VecDeque::from([1.0f64, 2.0]));
+    }
+}
+
+#[cfg(feature = "linked_list_impl")]
+mod test_linked_list {
+    extern crate alloc;
+    use alloc::collections::LinkedList;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(LinkedList::from([1.0f32, 2.0]), LinkedList::from([1.0f32, 2.0]));
+        assert_abs_diff_ne!(LinkedList::from([1.0f32, 2.0]), LinkedList::from([2.0f32, 1.0]));
+        assert_abs_diff_ne!(LinkedList::from([1.0f32, 2.0]), LinkedList::<f32>::new());
+    }
+}
+
+#[cfg(feature = "btree_map_impl")]
+mod test_btree_map {
+    extern crate alloc;
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn test_basic() {
+        let a = BTreeMap::from([("energy", 1.0f64), ("time", 2.0)]);
+        assert_abs_diff_eq!(a, BTreeMap::from([("time", 2.0f64), ("energy", 1.0)]));
+        assert_abs_diff_ne!(a, BTreeMap::from([("energy", 1.0f64), ("time", 3.0)]));
+        assert_abs_diff_ne!(a, BTreeMap::from([("energy", 1.0f64), ("mass", 2.0)]));
+        assert_abs_diff_ne!(a, BTreeMap::from([("energy", 1.0f64)]));
+    }
+}
+
+#[cfg(feature = "hash_map_impl")]
+mod test_hash_map {
+    extern crate std;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_basic() {
+        let a = HashMap::from([(1, 1.0f64), (2, 2.0)]);
+        assert_abs_diff_eq!(a, HashMap::from([(2, 2.0f64), (1, 1.0)]));
+        assert_abs_diff_eq!(a, HashMap::from([(1, 1.0f64), (2, 2.5)]), epsilon = 0.5);
+        assert_abs_diff_ne!(a, HashMap::from([(1, 1.0f64), (2, 2.5)]), epsilon = 0.4);
+        assert_abs_diff_ne!(a, HashMap::from([(1, 1.0f64), (3, 2.0)]));
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_cross {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_vec_array() {
+        let computed = Vec::from([1.0f32, 2.0]);
+        assert_abs_diff_eq!(computed, [1.0f32, 2.25], epsilon = 0.5);
+        assert_abs_diff_ne!([1.0f32, 3.0], computed, epsilon = 0.5);
+        assert_abs_diff_ne!(computed, [1.0f32, 2.0, 3.0], epsilon = 0.5);
+    }
+
+    #[test]
+    fn test_vec_slice() {
+        let computed = Vec::from([1.0f64, 2.0]);
+        assert_abs_diff_eq!(computed, [1.0f64, 2.0][..]);
+        assert_abs_diff_eq!(&[1.0f64, 2.0][..], computed);
+    }
+
+    #[cfg(feature = "vec_deque_impl")]
+    #[test]
+    fn test_vec_deque_slice() {
+        use alloc::collections::VecDeque;
+
+        assert_abs_diff_eq!(VecDeque::from([1.0f64, 2.0]), [1.0f64, 2.0][..]);
+        assert_abs_diff_ne!([1.0f64, 2.0][..], VecDeque::from([1.0f64, 2.5]));
+    }
+}
@@ -0,0 +1,133 @@
+//! Tests for the non-panicking checks
+#![no_std]
+
+extern crate approxim;
+
+use approxim::{AbsDiff, ApproxError, Relative, Ulps};
+
+mod test_builder {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(AbsDiff::default().epsilon(0.5).check(&1.0, &1.25), Ok(()));
+        assert_eq!(Relative::default().check_ne(&1.0, &2.0), Ok(()));
+        assert!(Ulps::default().max_ulps(0).check(&1.0, &1.1).is_err());
+    }
+
+    #[test]
+    fn test_error() {
+        let error = Relative::default().check(&1.0, &2.0).unwrap_err();
+        assert_eq!(error.comparison(), "relative_eq");
+        assert_eq!(error.expressions(), None);
+        assert_eq!(error.options(), "");
+
+        let error = AbsDiff::default().check_ne(&1.0, &1.0).unwrap_err();
+        assert_eq!(error.comparison(), "abs_diff_ne");
+    }
+}
+
+mod test_macros {
+    use super::*;
+
+    #[test]
+    fn test_error() {
+        let a = 1.0f32;
+        let error = approxim::check_ulps_eq!(a, a + 0.5, max_ulps = 1).unwrap_err();
+        assert_eq!(error.comparison(), "ulps_eq");
+        assert_eq!(error.expressions(), Some(("a", "a + 0.5")));
+        assert_eq!(error.options(), "max_ulps = 1");
+    }
+
+    #[test]
+    fn test_question_mark() {
+        fn check(a: f64, b: f64) -> Result<(), ApproxError> {
+            approxim::check_abs_diff_eq!(a, b, epsilon = 0.1)?;
+            approxim::check_relative_ne!(a, b + 1.0)?;
+            Ok(())
+        }
+        assert!(check(1.0, 1.05).is_ok());
+        assert_eq!(check(1.0, 1.5).unwrap_err().comparison(), "abs_diff_eq");
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_alloc {
+    extern crate std;
+
+    use super::*;
+    use std::{boxed::Box, error::Error, string::ToString};
+
+    #[test]
+    fn test_values() {
+        let error = Relative::default()
+            .max_relative(0.1)
+            .check(&1.0, &1.5)
+            .unwrap_err();
+        assert_eq!(error.left(), "1.0");
+        assert_eq!(error.right(), "1.5");
+        assert!(error.tolerance().starts_with("Relative { epsilon: "));
+        assert!(error.tolerance().contains("max_relative: 0.1"));
+        assert_eq!(error.report().len(), 1);
+        assert_eq!(error.report().first().unwrap().max_relative, Some(0.1));
+    }
+
+    #[test]
+    fn test_ne_values() {
+        let error = Ulps::default()
+            .check_ne(&[1.0, 2.0][..], &[1.0, 2.0][..])
+            .unwrap_err();
+        assert_eq!(error.left(), "[1.0, 2.0]");
+        assert!(error.report().is_eq());
+    }
+
+    #[test]
+    fn test_display() {
+        let error = approxim::check_relative_eq!(1.0, 1.5, max_relative = 0.1).unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with(
+            "check_relative_eq!(1.0, 1.5, max_relative = 0.1) failed\n\n    left      = 1.0\n    \
+             right     = 1.5\n    tolerance = Relative { "
+        ));
+        assert!(message.contains("\n\n    first of 1 mismatch(es):\n    values differ"));
+        assert!(!message.ends_with('\n'));
+
+        let error = AbsDiff::default().check_ne(&1.0, &1.0).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("abs_diff_ne failed\n\n    left      = 1.0\n"));
+    }
+
+    #[test]
+    fn test_error_trait() {
+        let error: Box<dyn Error> = Box::new(AbsDiff::default().check(&1.0, &2.0).unwrap_err());
+        assert!(error.to_string().starts_with("abs_diff_eq failed"));
+        assert!(error.source().is_none());
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+mod test_no_alloc {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        struct Buffer([u8; 64], usize);
+
+        impl core::fmt::Write for Buffer {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+                self.1 += s.len();
+                Ok(())
+            }
+        }
+
+        let error = approxim::check_abs_diff_eq!(1.0, 2.0).unwrap_err();
+        let mut buffer = Buffer([0; 64], 0);
+        core::fmt::write(&mut buffer, format_args!("{}", error)).unwrap();
+        assert_eq!(
+            &buffer.0[..buffer.1],
+            b"check_abs_diff_eq!(1.0, 2.0) failed"
+        );
+    }
+}
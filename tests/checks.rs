@@ -0,0 +1,90 @@
+//! Tests for the soft assertion collector
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approxim;
+
+use approxim::{AbsDiff, Checks, IsClose, MathIsClose, Relative, Ulps};
+
+#[test]
+fn test_success() {
+    let mut checks = Checks::new();
+    assert!(checks.check(check_relative_eq!(0.1 + 0.2, 0.3)));
+    assert!(checks.check(check_ulps_ne!(1.0, 2.0)));
+    assert!(checks.abs_diff_eq(&1.0, &1.1, AbsDiff::default().epsilon(0.2)));
+    assert!(checks.relative_eq(&1.0, &1.05, Relative::default().max_relative(0.1)));
+    assert!(checks.ulps_eq(&1.0, &1.0, Ulps::default()));
+    assert!(checks.isclose(&1.0, &1.05, IsClose::default().rtol(0.1)));
+    assert!(checks.math_isclose(&1.0, &1.05, MathIsClose::default().rel_tol(0.1)));
+    assert_eq!(checks.total(), 7);
+    assert!(checks.failures().is_empty());
+    checks.finish();
+}
+
+#[test]
+fn test_failures() {
+    let mut checks = Checks::new();
+    assert!(!checks.check(check_abs_diff_eq!(1.0, 2.0)));
+    assert!(checks.check(check_abs_diff_eq!(1.0, 1.0)));
+    assert!(!checks.relative_eq(&1.0, &1.5, Relative::default()));
+    let line = line!();
+
+    let failures = checks.failures();
+    assert_eq!(checks.total(), 3);
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].error.comparison(), "abs_diff_eq");
+    assert_eq!(failures[0].error.expressions(), Some(("1.0", "2.0")));
+    assert_eq!(failures[1].error.comparison(), "relative_eq");
+    assert_eq!(failures[1].location.file(), file!());
+    assert_eq!(failures[1].location.line(), line - 1);
+
+    // Forget the failures, so that dropping the collector does not panic.
+    std::mem::forget(checks);
+}
+
+#[test]
+#[should_panic(expected = "2 of 3 approximate comparison(s) failed\n\n    location")]
+fn test_finish() {
+    let mut checks = Checks::new();
+    checks.check(check_relative_eq!(1.0, 1.5, max_relative = 0.1));
+    checks.check(check_relative_eq!(1.0, 1.05, max_relative = 0.1));
+    checks.check(check_ulps_ne!(1.0, 1.0));
+    checks.finish();
+}
+
+#[test]
+fn test_table() {
+    let mut checks = Checks::new();
+    checks.check(check_relative_eq!(1.0, 1.5, max_relative = 0.1));
+    checks.check(check_ulps_ne!([1.0, 2.0][..], [1.0, 2.0][..]));
+    checks.abs_diff_eq(&[1.0, 2.0][..], &[1.0, 2.5][..], AbsDiff::default());
+
+    let message = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| checks.finish()))
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+    let lines: Vec<&str> = message.lines().collect();
+
+    assert_eq!(lines.len(), 6);
+    assert_eq!(lines[0], "3 of 3 approximate comparison(s) failed");
+    assert!(lines[2].starts_with("    location"));
+    assert!(lines[3].contains("  check_relative_eq!(1.0, 1.5, max_relative = 0.1)  1.0"));
+    assert!(lines[3].contains("  values differ (abs_diff = 0.5, rel_diff = 0.3333333333333333, "));
+    assert!(lines[4].contains("  check_ulps_ne!([1.0, 2.0][..], [1.0, 2.0][..])"));
+    assert!(lines[4].ends_with("  [1.0, 2.0]  values are equal"));
+    assert!(lines[5].contains("  values differ at [1] (abs_diff = 0.5, rel_diff = 0.2, ulps = "));
+    let column = lines[2].find("comparison").unwrap();
+    assert!(lines[3..]
+        .iter()
+        .all(|line| line[column - 2..].starts_with("  ")));
+    assert!(lines[3..]
+        .iter()
+        .all(|line| !line[column..].starts_with(' ')));
+}
+
+#[test]
+#[should_panic(expected = "1 of 1 approximate comparison(s) failed")]
+fn test_drop() {
+    let mut checks = Checks::new();
+    checks.check(check_isclose!(1.0, 2.0));
+}
@@ -0,0 +1,192 @@
+//! Tests for the comparison combining several criteria
+#![no_std]
+
+#[macro_use]
+extern crate approxim;
+
+use approxim::{Combine, Combined, UlpsMode};
+
+fn next(x: f64, ulps: u64) -> f64 {
+    f64::from_bits(x.to_bits() + ulps)
+}
+
+mod test_any {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert!(approx_eq!(1.0f64, 1.0));
+        assert!(approx_eq!(1.0f64, next(1.0, 3)));
+        assert!(approx_ne!(1.0f64, next(1.0, 3), max_ulps = 2));
+        assert!(approx_eq!(
+            1.0f64,
+            next(1.0, 3),
+            max_ulps = 2,
+            max_relative = 1e-15
+        ));
+        assert!(approx_eq!(1e-300f64, -1e-300, epsilon = 1e-200));
+        assert!(approx_ne!(1.0f64, 1.1));
+    }
+
+    #[test]
+    fn test_f32() {
+        assert!(approx_eq!(1.0f32, f32::from_bits(1.0f32.to_bits() + 4)));
+        assert!(approx_eq!(100.0f32, 101.0, max_relative = 0.01));
+        assert!(approx_ne!(100.0f32, 102.0, max_relative = 0.01));
+    }
+
+    #[test]
+    fn test_slice() {
+        let a = [1.0f64, 2.0];
+        assert!(approx_eq!(a[..], [1.0, next(2.0, 3)][..]));
+        assert!(approx_ne!(a[..], [1.0, 2.1][..]));
+        assert!(approx_ne!(a[..], [1.0][..]));
+    }
+}
+
+mod test_all {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert!(approx_eq!(1.0f64, 1.0, combine = Combine::All));
+        assert!(approx_eq!(
+            1.0f64,
+            next(1.0, 1),
+            epsilon = 1e-15,
+            max_relative = 1e-15,
+            max_ulps = 4,
+            combine = Combine::All
+        ));
+        assert!(approx_ne!(1.0f64, next(1.0, 3), combine = Combine::All));
+        assert!(approx_ne!(
+            1e-300f64,
+            -1e-300,
+            epsilon = 1e-200,
+            combine = Combine::All
+        ));
+    }
+
+    #[test]
+    fn test_each_criterion() {
+        let all = Combined::default()
+            .epsilon(1e-15)
+            .max_relative(1e-15)
+            .max_ulps(4)
+            .combine(Combine::All);
+        assert!(all.eq(&1.0f64, &next(1.0, 2)));
+        assert!(all.epsilon(1e-16).ne(&1.0f64, &next(1.0, 2)));
+        assert!(all.max_relative(1e-16).ne(&1.0f64, &next(1.0, 2)));
+        assert!(all.max_ulps(1).ne(&1.0f64, &next(1.0, 2)));
+    }
+
+    #[test]
+    fn test_slice() {
+        let a = [1.0f64, 2.0];
+        assert!(approx_eq!(a[..], a[..], combine = Combine::All));
+        assert!(approx_ne!(
+            a[..],
+            [1.0, next(2.0, 3)][..],
+            combine = Combine::All
+        ));
+    }
+}
+
+mod test_policy {
+    use super::*;
+
+    #[test]
+    fn test_nan() {
+        assert!(approx_ne!(f64::NAN, f64::NAN));
+        assert!(approx_eq!(f64::NAN, f64::NAN, nan_equal = true));
+        assert!(approx_eq!(
+            f64::NAN,
+            f64::NAN,
+            nan_equal = true,
+            combine = Combine::All
+        ));
+    }
+
+    #[test]
+    fn test_ulps() {
+        let tiny = f64::from_bits(1);
+        let any = Combined::default().epsilon(0.0);
+        assert!(any.ne(&tiny, &-tiny));
+        assert!(any.ulps_mode(UlpsMode::Monotonic).eq(&tiny, &-tiny));
+        assert!(approx_eq!(0.0f64, -0.0, combine = Combine::All));
+        assert!(approx_ne!(
+            0.0f64,
+            -0.0,
+            distinct_zeros = true,
+            combine = Combine::All
+        ));
+    }
+}
+
+mod test_macros {
+    use super::*;
+
+    #[test]
+    fn test_assert() {
+        assert_approx_eq!(1.0f64, next(1.0, 3));
+        assert_approx_ne!(1.0f64, next(1.0, 3), combine = Combine::All,);
+        assert_approx_eq!(1.0f64, 1.0, "p = {}", 1);
+        debug_assert_approx_eq!(1.0f64, 1.0);
+        debug_assert_approx_ne!(1.0f64, 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_approx_eq!(1.0f64, next(1.0, 3), combine = Combine::All)")]
+    fn test_assert_panic() {
+        assert_approx_eq!(1.0f64, next(1.0, 3), combine = Combine::All);
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check_approx_eq!(1.0f64, next(1.0, 3)), Ok(()));
+        let error = check_approx_eq!(1.0f64, 2.0, combine = Combine::All).unwrap_err();
+        assert_eq!(error.comparison(), "approx_eq");
+        assert_eq!(error.options(), "combine = Combine::All");
+        let error = check_approx_ne!(1.0f64, 1.0).unwrap_err();
+        assert_eq!(error.comparison(), "approx_ne");
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_report {
+    use super::*;
+
+    #[test]
+    fn test_any() {
+        let any = Combined::default().max_relative(0.1);
+        assert!(any.report(&1.0f64, &1.05).is_eq());
+        assert!(any.report(&1.0f64, &next(1.0, 3)).is_eq());
+
+        let report = any.report(&1.0f64, &1.5);
+        let mismatch = report.first().unwrap();
+        assert_eq!(mismatch.max_relative, Some(0.1));
+        assert_eq!(mismatch.abs_diff, Some(0.5));
+    }
+
+    #[test]
+    fn test_all() {
+        let all = Combined::default()
+            .epsilon(1e-15)
+            .max_relative(1e-15)
+            .max_ulps(1)
+            .combine(Combine::All);
+        assert!(all.report(&1.0f64, &next(1.0, 1)).is_eq());
+        assert_eq!(
+            all.epsilon(1e-16)
+                .report(&1.0f64, &next(1.0, 2))
+                .first()
+                .unwrap()
+                .epsilon,
+            Some(1e-16)
+        );
+        assert_eq!(
+            all.report(&1.0f64, &next(1.0, 2)).first().unwrap().max_ulps,
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Tests for the composable comparators
+#![no_std]
+
+#[macro_use]
+extern crate approxim;
+
+use approxim::{
+    from_fn, AbsDiff, And, Combined, Comparator, FromFn, IsClose, MathIsClose, Not, Or, Relative,
+    Ulps,
+};
+
+type FnCmp = FromFn<fn(&f64, &f64) -> bool>;
+
+const SAME_SIGN: FnCmp = from_fn(|a, b| a.is_sign_negative() == b.is_sign_negative());
+
+const NEAR_ZERO: Or<FnCmp, FnCmp> = Or::new(from_fn(|a, _| *a == 0.0), from_fn(|_, b| *b == 0.0));
+
+const OPPOSITE_SIGN: And<Not<FnCmp>, Not<Or<FnCmp, FnCmp>>> =
+    And::new(Not::new(SAME_SIGN), Not::new(NEAR_ZERO));
+
+fn close() -> impl Comparator<f64> {
+    AbsDiff::default()
+        .epsilon(1e-12)
+        .or(Relative::default().max_relative(1e-9))
+}
+
+fn count_eq<C: Comparator<f64>>(a: &[f64], b: &[f64], cmp: C) -> usize {
+    a.iter().zip(b).filter(|(a, b)| cmp.eq(a, b)).count()
+}
+
+mod test_builders {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert!(Comparator::eq(
+            &AbsDiff::default().epsilon(0.5),
+            &1.0,
+            &1.25
+        ));
+        assert!(Comparator::ne(&AbsDiff::default(), &1.0, &1.25));
+        assert!(Comparator::eq(
+            &Relative::default().max_relative(0.1),
+            &1.0,
+            &1.05
+        ));
+        assert!(Comparator::eq(&Ulps::default(), &1.0, &1.0));
+        assert!(Comparator::eq(&IsClose::default().rtol(0.1), &1.0, &1.05));
+        assert!(Comparator::eq(
+            &MathIsClose::default().rel_tol(0.1),
+            &1.0,
+            &1.05
+        ));
+        assert!(Comparator::eq(&Combined::default(), &1.0, &1.0));
+    }
+
+    #[test]
+    fn test_slice() {
+        let cmp = Relative::default().max_relative(0.1);
+        assert!(Comparator::eq(&cmp, &[1.0, 2.0][..], &[1.05, 2.0][..]));
+        assert!(Comparator::ne(&cmp, &[1.0, 2.0][..], &[1.05][..]));
+    }
+
+    #[test]
+    fn test_reuse() {
+        let cmp = close();
+        assert_eq!(count_eq(&[1.0, 2.0, 3.0], &[1.0, 2.5, 3.0], &cmp), 2);
+        assert_eq!(count_eq(&[1.0, 2.0, 3.0], &[1.0, 2.5, 3.0], cmp.not()), 1);
+    }
+}
+
+mod test_combinators {
+    use super::*;
+
+    #[test]
+    fn test_and() {
+        let cmp = AbsDiff::default()
+            .epsilon(0.5)
+            .and(Relative::default().max_relative(0.1));
+        assert!(cmp.eq(&10.0, &10.4));
+        assert!(cmp.ne(&1.0, &1.4));
+        assert!(cmp.ne(&100.0, &101.0));
+    }
+
+    #[test]
+    fn test_or() {
+        let cmp = close();
+        assert!(cmp.eq(&1e-13, &0.0));
+        assert!(cmp.eq(&1e6, &(1e6 + 1e-4)));
+        assert!(cmp.ne(&1.0, &1.1));
+    }
+
+    #[test]
+    fn test_not() {
+        let cmp = Ulps::default().max_ulps(1).not();
+        assert!(cmp.eq(&1.0, &2.0));
+        assert!(cmp.ne(&1.0, &1.0));
+        assert!(cmp.not().eq(&1.0, &1.0));
+    }
+
+    #[test]
+    fn test_from_fn() {
+        assert!(SAME_SIGN.eq(&-1.0, &-2.0));
+        assert!(SAME_SIGN.ne(&-1.0, &2.0));
+
+        let cmp = from_fn(|a: &f32, b: &f64| (*a as f64 - b).abs() < 1e-6);
+        assert!(cmp.eq(&0.1, &0.1));
+        assert!(cmp
+            .and(from_fn(|a: &f32, _: &f64| *a > 0.0))
+            .ne(&-0.1, &-0.1));
+    }
+
+    #[test]
+    fn test_const() {
+        assert!(OPPOSITE_SIGN.eq(&1.0, &-1.0));
+        assert!(OPPOSITE_SIGN.ne(&1.0, &1.0));
+        assert!(OPPOSITE_SIGN.ne(&0.0, &-1.0));
+        assert_approx!(-2.0, 2.0, using = OPPOSITE_SIGN);
+    }
+
+    #[test]
+    fn test_named() {
+        let cmp = close().and(SAME_SIGN);
+        assert!(cmp.eq(&1e-13, &1e-14));
+        assert!(cmp.ne(&1e-13, &-1e-14));
+    }
+}
+
+mod test_macros {
+    use super::*;
+
+    #[test]
+    fn test_assert() {
+        assert_approx!(0.1 + 0.2, 0.3, using = close());
+        assert_approx!(1.0, 1.1, using = close().not(),);
+        assert_approx!(-1.0, -2.0, using = SAME_SIGN, "sign of {}", -1.0);
+        assert_approx!(
+            [1.0, 2.0][..],
+            [1.05, 2.0][..],
+            using = Relative::default().max_relative(0.1)
+        );
+        debug_assert_approx!(1.0, 1.0, using = close());
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_approx!(1.0, 1.1, using = close())")]
+    fn test_assert_panic() {
+        assert_approx!(1.0, 1.1, using = close());
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_approx!(1.0, -1.0, using = SAME_SIGN): p = 3")]
+    fn test_assert_message_panic() {
+        assert_approx!(1.0, -1.0, using = SAME_SIGN, "p = {}", 3);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_report {
+    use super::*;
+
+    #[test]
+    fn test_builders() {
+        let cmp = Relative::default().max_relative(0.1);
+        let report = Comparator::report(&cmp, &[1.0, 2.0][..], &[1.0, 2.5][..]);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.first().unwrap().max_relative, Some(0.1));
+    }
+
+    #[test]
+    fn test_combinators() {
+        let abs = AbsDiff::default().epsilon(0.1);
+        let rel = Relative::default().max_relative(0.1);
+
+        let report = abs.and(rel).report(&0.1, &0.15);
+        assert_eq!(report.first().unwrap().max_relative, Some(0.1));
+        let report = abs.and(rel).report(&1.0, &1.5);
+        assert_eq!(report.first().unwrap().epsilon, Some(0.1));
+        assert!(abs.or(rel).report(&1.0, &1.05).is_eq());
+        assert_eq!(
+            abs.or(rel).report(&1.0, &1.5).first().unwrap().epsilon,
+            Some(0.1)
+        );
+        assert_eq!(abs.not().report(&1.0, &1.0).len(), 1);
+        assert!(SAME_SIGN.report(&1.0, &2.0).is_eq());
+    }
+
+    #[test]
+    #[should_panic(expected = "first of 1 mismatch(es):\n    values differ at [1]")]
+    fn test_assert_details() {
+        assert_approx!(
+            [1.0, 2.0][..],
+            [1.0, 2.5][..],
+            using = AbsDiff::default().epsilon(0.1)
+        );
+    }
+}
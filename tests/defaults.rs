@@ -0,0 +1,91 @@
+//! Tests for the scoped overrides of the default tolerances
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approxim;
+
+use approxim::{with_defaults, AbsDiff, AbsDiffEq, Defaults, Relative, RelativeEq, Ulps, UlpsEq};
+use std::panic;
+use std::thread;
+
+#[test]
+fn test_builders() {
+    let defaults = Defaults::f64().epsilon(1e-3).max_relative(1e-9).max_ulps(8);
+    with_defaults(defaults, || {
+        assert_eq!(AbsDiff::<f64>::default().epsilon, 1e-3);
+        let relative = Relative::<f64>::default();
+        assert_eq!((relative.epsilon, relative.max_relative), (1e-3, 1e-9));
+        let ulps = Ulps::<f64>::default();
+        assert_eq!((ulps.epsilon, ulps.max_ulps), (1e-3, 8));
+    });
+    assert_eq!(AbsDiff::<f64>::default().epsilon, f64::EPSILON);
+    assert_eq!(Relative::<f64>::default().max_relative, f64::EPSILON);
+    assert_eq!(Ulps::<f64>::default().max_ulps, 4);
+}
+
+#[test]
+fn test_macros() {
+    let result = with_defaults(Defaults::f64().max_relative(1e-9), || {
+        assert_relative_eq!(1.0, 1.0 + 1e-10);
+        assert_relative_ne!(1.0, 1.0 + 1e-8);
+        assert_relative_eq!(1.0, 1.0 + 1e-10, max_relative = 1e-9);
+        assert_relative_ne!(1.0, 1.0 + 1e-10, max_relative = 1e-12);
+        42
+    });
+    assert_eq!(result, 42);
+    assert_relative_ne!(1.0, 1.0 + 1e-10);
+}
+
+#[test]
+fn test_containers() {
+    with_defaults(Defaults::f64().max_ulps(16), || {
+        assert_eq!(<[f64] as UlpsEq>::default_max_ulps(), 16);
+        assert_ulps_eq!([1.0, 2.0][..], [1.0 + 16.0 * f64::EPSILON, 2.0][..]);
+        assert_ulps_eq!(Some(1.0), Some(1.0 + 16.0 * f64::EPSILON));
+    });
+}
+
+#[test]
+fn test_types() {
+    with_defaults(Defaults::f32().epsilon(0.5), || {
+        assert_eq!(<f32 as AbsDiffEq>::default_epsilon(), 0.5);
+        assert_eq!(<f64 as AbsDiffEq>::default_epsilon(), f64::EPSILON);
+        assert_abs_diff_eq!(1.0f32, 1.25f32);
+        assert_abs_diff_ne!(1.0f64, 1.25f64);
+    });
+}
+
+#[test]
+fn test_nested() {
+    with_defaults(Defaults::f64().epsilon(1e-3).max_relative(1e-6), || {
+        with_defaults(Defaults::f64().max_relative(1e-9), || {
+            assert_eq!(<f64 as AbsDiffEq>::default_epsilon(), 1e-3);
+            assert_eq!(<f64 as RelativeEq>::default_max_relative(), 1e-9);
+        });
+        assert_eq!(<f64 as AbsDiffEq>::default_epsilon(), 1e-3);
+        assert_eq!(<f64 as RelativeEq>::default_max_relative(), 1e-6);
+    });
+    assert_eq!(<f64 as AbsDiffEq>::default_epsilon(), f64::EPSILON);
+}
+
+#[test]
+fn test_restored_on_panic() {
+    let result = panic::catch_unwind(|| {
+        with_defaults(Defaults::f64().max_relative(1e-9), || {
+            assert_relative_eq!(1.0, 1.0 + 1e-8);
+        })
+    });
+    assert!(result.is_err());
+    assert_eq!(<f64 as RelativeEq>::default_max_relative(), f64::EPSILON);
+}
+
+#[test]
+fn test_other_threads() {
+    with_defaults(Defaults::f64().max_ulps(100), || {
+        let max_ulps = thread::spawn(<f64 as UlpsEq>::default_max_ulps)
+            .join()
+            .unwrap();
+        assert_eq!(max_ulps, 4);
+        assert_eq!(<f64 as UlpsEq>::default_max_ulps(), 100);
+    });
+}
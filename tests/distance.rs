@@ -0,0 +1,362 @@
+//! Tests for the error metrics of the `Distance` trait
+#![no_std]
+#![cfg_attr(approxim_nightly_float, feature(f16, f128))]
+
+extern crate approxim;
+
+mod common;
+
+use approxim::{abs_diff, relative_diff, ulps_distance, Distance, Mixed};
+
+mod test_f32 {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(abs_diff(&1.0f32, &1.5f32), 0.5);
+        assert_eq!(relative_diff(&1.0f32, &2.0f32), 0.5);
+        assert_eq!(ulps_distance(&1.0f32, &1.0f32), 0);
+        assert_eq!(
+            ulps_distance(&1.0f32, &f32::from_bits(1.0f32.to_bits() + 7)),
+            7
+        );
+    }
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(abs_diff(&0.0f32, &-0.0f32), 0.0);
+        assert_eq!(relative_diff(&0.0f32, &0.0f32), 0.0);
+        assert_eq!(ulps_distance(&0.0f32, &-0.0f32), 0);
+    }
+
+    #[test]
+    fn test_infinity() {
+        assert_eq!(abs_diff(&f32::INFINITY, &f32::INFINITY), 0.0);
+        assert_eq!(relative_diff(&f32::INFINITY, &1.0f32), f32::INFINITY.into());
+        assert_eq!(ulps_distance(&f32::INFINITY, &f32::MAX), 1);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert!(abs_diff(&f32::NAN, &1.0f32).is_nan());
+        assert!(relative_diff(&f32::NAN, &1.0f32).is_nan());
+        assert_eq!(ulps_distance(&f32::NAN, &f32::NAN), u64::MAX);
+    }
+
+    #[test]
+    fn test_opposite_signs() {
+        assert_eq!(abs_diff(&-1.0f32, &1.0f32), 2.0);
+        assert_eq!(ulps_distance(&-1.0f32, &1.0f32), u64::MAX);
+    }
+}
+
+mod test_f64 {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(abs_diff(&1.0f64, &1.5f64), 0.5);
+        assert_eq!(relative_diff(&-1.0f64, &-2.0f64), 0.5);
+        assert_eq!(
+            ulps_distance(&f64::from_bits(1.0f64.to_bits() + 9), &1.0f64),
+            9
+        );
+    }
+
+    #[test]
+    fn test_method() {
+        assert_eq!(Distance::abs_diff(&1.0f64, &3.0f64), 2.0);
+        assert_eq!(1.0f64.relative_diff(&4.0), 0.75);
+        assert_eq!(1.0f64.ulps_distance(&1.0), 0);
+    }
+}
+
+mod test_int {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(abs_diff(&3u8, &250u8), 247.0);
+        assert_eq!(abs_diff(&i64::MIN, &i64::MAX), u64::MAX as f64);
+        assert_eq!(relative_diff(&5i32, &10i32), 0.5);
+        assert_eq!(ulps_distance(&-3i32, &4i32), 7);
+        assert_eq!(ulps_distance(&0u128, &u128::MAX), u64::MAX);
+        assert_eq!(ulps_distance(&i128::MIN, &i128::MAX), u64::MAX);
+        assert_eq!(abs_diff(&-1i128, &1i128), 2.0);
+    }
+}
+
+mod test_mixed {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(abs_diff(&Mixed(0.5f32), &0.75f64), 0.25);
+        assert_eq!(relative_diff(&Mixed(1.0f64), &2.0f32), 0.5);
+        assert_eq!(ulps_distance(&Mixed(0.1f32), &0.1f64), 0);
+        assert_ne!(abs_diff(&Mixed(0.1f32), &0.1f64), 0.0);
+        assert_eq!(abs_diff(&Mixed(0.1f32), &0.1f64), f64::from(0.1f32) - 0.1);
+    }
+
+    #[test]
+    fn test_ulps() {
+        let a = 1.0f32;
+        let b = f64::from(f32::from_bits(a.to_bits() + 3));
+        assert_eq!(ulps_distance(&Mixed(a), &b), 3);
+        assert_eq!(ulps_distance(&Mixed(b), &a), 3);
+        assert_eq!(ulps_distance(&Mixed(f32::MAX), &1e300f64), u64::MAX);
+    }
+
+    #[test]
+    fn test_slice() {
+        assert_eq!(abs_diff(&[0.5f32, 1.0].map(Mixed)[..], &[0.5f64, 1.5][..]), 0.5);
+    }
+}
+
+mod test_option {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(abs_diff(&Some(1.0f64), &Some(2.0f64)), 1.0);
+        assert_eq!(abs_diff(&None::<f64>, &None), 0.0);
+        assert_eq!(abs_diff(&Some(1.0f64), &None), f64::INFINITY);
+        assert_eq!(ulps_distance(&None, &Some(1.0f64)), u64::MAX);
+    }
+}
+
+mod test_result {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        let a: Result<f64, f32> = Ok(1.0);
+        let b: Result<f64, f32> = Err(1.0);
+        assert_eq!(abs_diff(&a, &a), 0.0);
+        assert_eq!(relative_diff(&a, &b), f64::INFINITY);
+    }
+}
+
+mod test_slice {
+    use super::*;
+
+    #[test]
+    fn test_max() {
+        let a = [1.0f64, 2.0, 3.0];
+        let b = [1.5f64, 2.0, 2.0];
+        assert_eq!(abs_diff(&a[..], &b[..]), 1.0);
+        assert_eq!(relative_diff(&a[..], &b[..]), 1.0 / 3.0);
+        assert_eq!(ulps_distance(&a[..], &a[..]), 0);
+    }
+
+    #[test]
+    fn test_empty() {
+        let a: [f64; 0] = [];
+        assert_eq!(abs_diff(&a[..], &a[..]), 0.0);
+    }
+
+    #[test]
+    fn test_length() {
+        assert_eq!(abs_diff(&[1.0f64][..], &[1.0f64, 2.0][..]), f64::INFINITY);
+        assert_eq!(ulps_distance(&[1.0f64][..], &[0.0f64; 0][..]), u64::MAX);
+    }
+
+    #[test]
+    fn test_nan() {
+        let a = [f64::NAN, 1.0];
+        let b = [1.0f64, 5.0];
+        assert!(abs_diff(&a[..], &b[..]).is_nan());
+    }
+}
+
+#[cfg(feature = "array_impl")]
+mod test_array {
+    use super::*;
+
+    #[test]
+    fn test_max() {
+        assert_eq!(abs_diff(&[1.0f32, 2.0], &[1.0f32, 2.5]), 0.5);
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_vec {
+    extern crate std;
+    use super::*;
+    use std::vec;
+
+    #[test]
+    fn test_max() {
+        let a = vec![vec![1.0f64, 2.0], vec![3.0]];
+        let b = vec![vec![1.0f64, 2.5], vec![5.0]];
+        assert_eq!(abs_diff(&a, &b), 2.0);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_pointer {
+    extern crate alloc;
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::sync::Arc;
+    use core::pin::Pin;
+
+    #[test]
+    fn test_max() {
+        let a: Box<[f64]> = Box::new([1.0, 2.0]);
+        let b: Box<[f64]> = Box::new([1.5, 2.0]);
+        assert_eq!(abs_diff(&a, &b), 0.5);
+        assert_eq!(ulps_distance(&Arc::new(1.0f64), &Arc::new(1.0f64)), 0);
+        assert_eq!(relative_diff(&Pin::new(&1.0f64), &Pin::new(&2.0f64)), 0.5);
+    }
+}
+
+#[cfg(feature = "tuple_impl")]
+mod test_tuple {
+    use super::*;
+
+    #[test]
+    fn test_max() {
+        assert_eq!(abs_diff(&(1.0f64, 2u8), &(1.5f64, 4u8)), 2.0);
+        assert_eq!(ulps_distance(&(), &()), 0);
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod test_complex {
+    extern crate num_complex;
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_max() {
+        let a = Complex::new(1.0f64, 2.0);
+        let b = Complex::new(1.25f64, 3.0);
+        assert_eq!(abs_diff(&a, &b), 1.0);
+    }
+}
+
+#[cfg(approxim_nightly_float)]
+mod test_nightly_float {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(abs_diff(&1.0f16, &1.5f16), 0.5);
+        assert_eq!(relative_diff(&1.0f128, &2.0f128), 0.5);
+        assert_eq!(ulps_distance(&1.0f128, &f128::from_bits(1.0f128.to_bits() + 3)), 3);
+        assert_eq!(ulps_distance(&f128::MIN_POSITIVE, &f128::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn test_f128_below_f64_precision() {
+        let diff = abs_diff(&1.0f128, &(1.0f128 + 1e-30));
+        assert!(diff > 0.99e-30 && diff < 1.01e-30);
+        let diff = relative_diff(&1.0f128, &(1.0f128 + 1e-30));
+        assert!(diff > 0.99e-30 && diff < 1.01e-30);
+    }
+}
+
+#[cfg(feature = "half")]
+mod test_half {
+    extern crate half;
+    use super::*;
+    use half::{bf16, f16};
+
+    #[test]
+    fn test_basic() {
+        assert_eq!(abs_diff(&f16::from_f32(1.0), &f16::from_f32(1.5)), 0.5);
+        assert_eq!(relative_diff(&bf16::from_f32(1.0), &bf16::from_f32(2.0)), 0.5);
+        assert_eq!(
+            ulps_distance(&f16::ONE, &f16::from_bits(f16::ONE.to_bits() + 3)),
+            3
+        );
+        assert_eq!(ulps_distance(&bf16::ONE, &bf16::NEG_ONE), u64::MAX);
+    }
+}
+
+#[cfg(feature = "indexmap_impl")]
+mod test_indexmap {
+    use super::common::indexmap::IndexMap;
+    use super::*;
+
+    #[test]
+    fn test_max() {
+        let mut a = IndexMap::default();
+        a.insert("a", 1.0f64);
+        a.insert("b", 2.0f64);
+        let mut b = IndexMap::default();
+        b.insert("b", 2.5f64);
+        b.insert("a", 1.0f64);
+        assert_eq!(abs_diff(&a, &b), 0.5);
+
+        b.swap_remove("b");
+        b.insert("c", 2.0f64);
+        assert_eq!(abs_diff(&a, &b), f64::INFINITY);
+    }
+}
+
+#[cfg(feature = "vec_deque_impl")]
+mod test_vec_deque {
+    extern crate alloc;
+    use super::*;
+    use alloc::collections::{LinkedList, VecDeque};
+
+    #[test]
+    fn test_max() {
+        let a = VecDeque::from([1.0f64, 2.0]);
+        assert_eq!(abs_diff(&a, &VecDeque::from([1.5f64, 2.0])), 0.5);
+        assert_eq!(ulps_distance(&a, &VecDeque::from([1.0f64])), u64::MAX);
+    }
+
+    #[cfg(feature = "linked_list_impl")]
+    #[test]
+    fn test_linked_list() {
+        let a = LinkedList::from([1.0f64, 2.0]);
+        assert_eq!(relative_diff(&a, &LinkedList::from([1.0f64, 4.0])), 0.5);
+    }
+}
+
+#[cfg(feature = "btree_map_impl")]
+mod test_btree_map {
+    extern crate alloc;
+    use super::*;
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn test_max() {
+        let a = BTreeMap::from([("a", 1.0f64), ("b", 2.0)]);
+        assert_eq!(abs_diff(&a, &BTreeMap::from([("b", 2.5f64), ("a", 1.0)])), 0.5);
+        let b = BTreeMap::from([("a", 1.0f64), ("c", 2.0)]);
+        assert_eq!(abs_diff(&a, &b), f64::INFINITY);
+    }
+}
+
+#[cfg(feature = "hash_map_impl")]
+mod test_hash_map {
+    extern crate std;
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_max() {
+        let a = HashMap::from([(1, 1.0f64), (2, 2.0)]);
+        assert_eq!(abs_diff(&a, &HashMap::from([(2, 2.5f64), (1, 1.0)])), 0.5);
+        assert_eq!(ulps_distance(&a, &HashMap::from([(1, 1.0f64)])), u64::MAX);
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_cross {
+    extern crate alloc;
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_max() {
+        let a = Vec::from([1.0f64, 2.0]);
+        assert_eq!(abs_diff(&a, &[1.5f64, 2.0]), 0.5);
+        assert_eq!(abs_diff(&a[..], &a), 0.0);
+        assert_eq!(ulps_distance(&a, &[1.0f64]), u64::MAX);
+    }
+}
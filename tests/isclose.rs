@@ -0,0 +1,364 @@
+//! Tests for the `numpy.isclose` and `math.isclose` comparisons
+#![no_std]
+#![cfg_attr(approxim_nightly_float, feature(f16, f128))]
+
+#[macro_use]
+extern crate approxim;
+
+mod common;
+
+mod test_numpy {
+    #[test]
+    fn test_basic() {
+        assert_isclose!(1.0f64, 1.0f64);
+        assert_not_isclose!(1.0f64, 2.0f64);
+        assert_isclose!(1.0f32, 1.0f32 + 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_eq() {
+        assert_isclose!(1.0f64, 2.0f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_basic_panic_ne() {
+        assert_not_isclose!(1.0f64, 1.0f64);
+    }
+
+    #[test]
+    fn test_defaults() {
+        // numpy.isclose(1e-9, 0.0) and numpy.isclose(1e5, 1e5 + 1.0)
+        assert_isclose!(1e-9f64, 0.0f64);
+        assert_isclose!(1e5f64, 1e5f64 + 1.0);
+        assert_not_isclose!(1e-7f64, 0.0f64);
+        assert_not_isclose!(1e5f64, 1e5f64 + 1.1);
+    }
+
+    #[test]
+    fn test_additive() {
+        // Both tolerances contribute: 0.15 <= 0.05 + 0.1 * 1.0
+        assert_isclose!(1.15f64, 1.0f64, atol = 0.05, rtol = 0.1);
+        assert_not_isclose!(1.15f64, 1.0f64, atol = 0.0, rtol = 0.1);
+        assert_not_isclose!(1.15f64, 1.0f64, atol = 0.05, rtol = 0.0);
+        assert_relative_ne!(1.15f64, 1.0f64, epsilon = 0.05, max_relative = 0.1);
+    }
+
+    #[test]
+    fn test_asymmetric() {
+        assert_isclose!(1.0f64, 1.1f64, atol = 0.0, rtol = 0.095);
+        assert_not_isclose!(1.1f64, 1.0f64, atol = 0.0, rtol = 0.095);
+    }
+
+    #[test]
+    fn test_infinity() {
+        assert_isclose!(f64::INFINITY, f64::INFINITY);
+        assert_not_isclose!(f64::INFINITY, f64::NEG_INFINITY);
+        assert_not_isclose!(f64::INFINITY, f64::MAX, rtol = 1.0);
+        assert_not_isclose!(1.0f64, f64::INFINITY, atol = f64::INFINITY);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_not_isclose!(f64::NAN, f64::NAN);
+        assert_not_isclose!(f32::NAN, 1.0f32, atol = f32::INFINITY);
+    }
+}
+
+mod test_math {
+    #[test]
+    fn test_basic() {
+        assert_math_isclose!(1.0f64, 1.0f64);
+        assert_not_math_isclose!(1.0f64, 2.0f64);
+        assert_math_isclose!(1.0f64, 1.0f64 + 1e-10);
+    }
+
+    #[test]
+    fn test_defaults() {
+        // math.isclose(1e-10, 0.0) is false, since abs_tol defaults to zero
+        assert_not_math_isclose!(1e-10f64, 0.0f64);
+        assert_math_isclose!(1e-10f64, 0.0f64, abs_tol = 1e-9);
+        assert_not_math_isclose!(1.0f64, 1.0f64 + 1e-8);
+    }
+
+    #[test]
+    fn test_symmetric() {
+        assert_math_isclose!(1.0f64, 1.1f64, rel_tol = 0.095);
+        assert_math_isclose!(1.1f64, 1.0f64, rel_tol = 0.095);
+        assert_not_math_isclose!(1.0f64, 1.1f64, rel_tol = 0.09);
+    }
+
+    #[test]
+    fn test_max_of_tolerances() {
+        // The tolerances are not added: 0.15 > max(0.1 * 1.15, 0.05)
+        assert_not_math_isclose!(1.15f64, 1.0f64, rel_tol = 0.1, abs_tol = 0.05);
+        assert_math_isclose!(1.15f64, 1.0f64, rel_tol = 0.1, abs_tol = 0.15);
+    }
+
+    #[test]
+    fn test_negative_tolerances() {
+        assert_math_isclose!(1.0f64, 1.0f64, rel_tol = -1.0, abs_tol = -1.0);
+        assert_not_math_isclose!(1.0f64, 1.5f64, rel_tol = -1.0, abs_tol = -1.0);
+    }
+
+    #[test]
+    fn test_infinity() {
+        assert_math_isclose!(f64::INFINITY, f64::INFINITY);
+        assert_not_math_isclose!(f64::INFINITY, f64::NEG_INFINITY);
+        assert_not_math_isclose!(f64::INFINITY, 1.0f64, abs_tol = f64::INFINITY);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_not_math_isclose!(f64::NAN, f64::NAN);
+    }
+}
+
+mod test_mixed {
+    use approxim::Mixed;
+
+    #[test]
+    fn test_basic() {
+        assert_isclose!(Mixed(0.1f32), 0.1f64);
+        assert_isclose!(Mixed(0.1f64), 0.1f32);
+        assert_not_math_isclose!(Mixed(0.1f32), 0.1f64);
+        assert_math_isclose!(Mixed(0.1f32), 0.1f64, rel_tol = 1e-7);
+    }
+
+    #[test]
+    fn test_literal_inference() {
+        let x = 0.1f32;
+        assert_math_isclose!(x, 0.1, rel_tol = 0.0);
+    }
+}
+
+mod test_option {
+    #[test]
+    fn test_basic() {
+        assert_isclose!(Some(1.0f64), Some(1.0f64));
+        assert_not_isclose!(Some(1.0f64), None);
+        assert_math_isclose!(None::<f64>, None);
+    }
+}
+
+mod test_result {
+    #[test]
+    fn test_basic() {
+        let a: Result<f64, f32> = Ok(1.0);
+        let b: Result<f64, f32> = Err(1.0);
+        assert_isclose!(a, a);
+        assert_not_isclose!(a, b);
+        assert_math_isclose!(b, b, rel_tol = (0.0, 0.1));
+    }
+}
+
+mod test_slice {
+    #[test]
+    fn test_basic() {
+        assert_isclose!([1.0f64, 100.0][..], [1.0f64, 100.0005][..]);
+        assert_not_isclose!([1.0f64, 100.0][..], [1.0f64, 100.002][..]);
+        assert_not_isclose!([1.0f64, 2.0][..], [1.0f64][..]);
+        assert_math_isclose!([1.0f32, 2.0][..], [1.0f32, 2.0][..]);
+    }
+}
+
+#[cfg(feature = "array_impl")]
+mod test_array {
+    #[test]
+    fn test_basic() {
+        assert_isclose!([1.0f64, 100.0], [1.0f64, 100.0005]);
+        assert_not_math_isclose!([1.0f64, 100.0], [1.0f64, 100.0005]);
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_vec {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_basic() {
+        let computed: Vec<_> = (1..4).map(|i| approxim::Mixed(1.0 / i as f32)).collect();
+        let reference: Vec<f64> = (1..4).map(|i| 1.0 / i as f64).collect();
+        assert_isclose!(computed, reference);
+        assert_isclose!(computed, reference, rtol = 1e-7, atol = 0.0);
+        assert_not_isclose!(computed, reference, rtol = 1e-9, atol = 0.0);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_pointer {
+    extern crate alloc;
+    use alloc::borrow::Cow;
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+
+    #[test]
+    fn test_basic() {
+        assert_isclose!(Box::new(1.0f64), Box::new(1.0f64));
+        assert_not_isclose!(Rc::new(1.0f64), Rc::new(1.1f64));
+        let a: Arc<[f64]> = Arc::from([1.0, 100.0]);
+        assert_isclose!(a, Arc::from([1.0, 100.0005]));
+        assert_math_isclose!(Cow::<f64>::Owned(1.0), Cow::Borrowed(&1.0));
+    }
+}
+
+#[cfg(feature = "tuple_impl")]
+mod test_tuple {
+    #[test]
+    fn test_basic() {
+        assert_isclose!((1.0f64, 2.0f32), (1.0f64, 2.0f32));
+        assert_not_isclose!((1.0f64, 2.0f32), (1.0f64, 2.5f32));
+        assert_math_isclose!((), ());
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod test_complex {
+    use num_complex::Complex;
+
+    #[test]
+    fn test_basic() {
+        assert_isclose!(Complex::new(1.0f64, 2.0), Complex::new(1.0f64, 2.0));
+        assert_not_isclose!(Complex::new(1.0f64, 2.0), Complex::new(1.0f64, 2.5));
+    }
+}
+
+#[cfg(approxim_nightly_float)]
+mod test_nightly_float {
+    #[test]
+    fn test_basic() {
+        assert_isclose!(1.0f16, 1.0f16);
+        assert_not_isclose!(1.0f16, 1.5f16);
+        assert_math_isclose!(1.0f128, 1.0f128 + 1e-12, rel_tol = 1e-9);
+    }
+}
+
+#[cfg(feature = "half")]
+mod test_half {
+    use half::{bf16, f16};
+
+    #[test]
+    fn test_basic() {
+        assert_isclose!(f16::from_f32(1.0), f16::from_f32(1.0));
+        assert_not_isclose!(f16::from_f32(1.0), f16::from_f32(1.5));
+        assert_math_isclose!(bf16::from_f32(1.0), bf16::from_f32(1.0));
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+mod test_ordered_float {
+    use ordered_float::{NotNan, OrderedFloat};
+
+    #[test]
+    fn test_basic() {
+        assert_isclose!(OrderedFloat(1.0f64), OrderedFloat(1.0f64));
+        assert_isclose!(OrderedFloat(1.0f64), 1.0f64);
+        assert_isclose!(1.0f64, OrderedFloat(1.0f64));
+        assert_not_isclose!(NotNan::new(1.0f32).unwrap(), 2.0f32);
+    }
+}
+
+#[cfg(feature = "indexmap_impl")]
+mod test_indexmap {
+    use super::common::indexmap::IndexMap;
+
+    #[test]
+    fn test_basic() {
+        let mut a = IndexMap::default();
+        a.insert("a", 1.0f64);
+        let mut b = IndexMap::default();
+        b.insert("a", 1.0f64 + 1e-6);
+        assert_isclose!(a, b);
+        assert_not_math_isclose!(a, b);
+    }
+}
+
+#[cfg(feature = "vec_deque_impl")]
+mod test_vec_deque {
+    extern crate alloc;
+    use alloc::collections::VecDeque;
+
+    #[test]
+    fn test_basic() {
+        assert_isclose!(VecDeque::from([1.0f64, 100.0]), VecDeque::from([1.0f64, 100.0005]));
+        assert_not_isclose!(VecDeque::from([1.0f64]), VecDeque::from([1.0f64, 2.0]));
+    }
+}
+
+#[cfg(feature = "linked_list_impl")]
+mod test_linked_list {
+    extern crate alloc;
+    use alloc::collections::LinkedList;
+
+    #[test]
+    fn test_basic() {
+        assert_isclose!(LinkedList::from([1.0f64]), LinkedList::from([1.0f64 + 1e-9]));
+        assert_not_math_isclose!(LinkedList::from([1.0f64]), LinkedList::from([1.0f64 + 1e-6]));
+    }
+}
+
+#[cfg(feature = "btree_map_impl")]
+mod test_btree_map {
+    extern crate alloc;
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn test_basic() {
+        let a = BTreeMap::from([("a", 1.0f64)]);
+        assert_isclose!(a, BTreeMap::from([("a", 1.0f64 + 1e-6)]));
+        assert_not_isclose!(a, BTreeMap::from([("b", 1.0f64)]));
+    }
+}
+
+#[cfg(feature = "hash_map_impl")]
+mod test_hash_map {
+    extern crate std;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_basic() {
+        let a = HashMap::from([("a", 1.0f64)]);
+        assert_isclose!(a, HashMap::from([("a", 1.0f64 + 1e-6)]));
+        assert_not_math_isclose!(a, HashMap::from([("a", 1.0f64 + 1e-6)]));
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_cross {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_basic() {
+        let computed: Vec<f64> = (1..4).map(|i| 1.0 / i as f64).collect();
+        assert_isclose!(computed, [1.0, 0.5, 0.333_333_333_333]);
+        assert_not_isclose!(computed, [1.0, 0.5, 0.3]);
+        assert_isclose!(computed[..], computed);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_report {
+    use approxim::{IsCloseEq, IsCloseMode, PathSegment};
+
+    #[test]
+    fn test_tolerances() {
+        let report = [1.0f64, 2.0].isclose_report(&[1.0, 2.5], 0.1, 0.01, IsCloseMode::NumPy);
+        let mismatch = report.first().unwrap();
+
+        assert_eq!(report.len(), 1);
+        assert_eq!(mismatch.path, [PathSegment::Index(1)]);
+        assert_eq!(mismatch.abs_diff, Some(0.5));
+        assert_eq!(mismatch.epsilon, Some(0.1));
+        assert_eq!(mismatch.max_relative, Some(0.01));
+    }
+
+    #[test]
+    #[should_panic(expected = "assert_not_isclose!(1.0, 1.0)")]
+    fn test_message() {
+        assert_not_isclose!(1.0, 1.0);
+    }
+}
@@ -2,8 +2,10 @@ extern crate approxim;
 
 mod test_macro_import {
     use approxim::{
-        assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_relative_ne,
-        assert_ulps_eq, assert_ulps_ne,
+        assert_abs_diff_eq, assert_abs_diff_ne, assert_isclose, assert_math_isclose,
+        assert_not_isclose, assert_not_math_isclose, assert_relative_eq, assert_relative_ne,
+        assert_ulps_eq, assert_ulps_ne, check_relative_eq, check_ulps_ne, debug_assert_abs_diff_eq,
+        debug_assert_ulps_ne,
     };
 
     #[test]
@@ -14,5 +16,27 @@ mod test_macro_import {
         assert_relative_ne!(1.0f32, 2.0f32);
         assert_ulps_eq!(1.0f32, 1.0f32);
         assert_ulps_ne!(1.0f32, 2.0f32);
+        assert_isclose!(1.0f32, 1.0f32);
+        assert_not_isclose!(1.0f32, 2.0f32);
+        assert_math_isclose!(1.0f32, 1.0f32);
+        assert_not_math_isclose!(1.0f32, 2.0f32);
+    }
+
+    #[test]
+    fn test_message() {
+        assert_abs_diff_eq!(1.0f32, 1.0f32, "message");
+        assert_relative_ne!(1.0f32, 2.0f32, max_relative = 0.1, "{}", 1);
+    }
+
+    #[test]
+    fn test_debug() {
+        debug_assert_abs_diff_eq!(1.0f32, 1.0f32);
+        debug_assert_ulps_ne!(1.0f32, 2.0f32, max_ulps = 1);
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(check_relative_eq!(1.0f32, 1.0f32).is_ok());
+        assert!(check_ulps_ne!(1.0f32, 1.0f32, max_ulps = 1).is_err());
     }
 }
@@ -97,9 +97,125 @@ fn test_ulps_ne_trailing_commas() {
     let _: bool = ulps_ne!(1.0, 1.0, epsilon = 1.0, max_ulps = 1,);
 }
 
+#[test]
+fn test_approx_eq() {
+    let _: bool = approx_eq!(1.0, 1.0);
+    let _: bool = approx_eq!(1.0, 1.0, epsilon = 1.0, max_relative = 1.0, max_ulps = 1);
+    let _: bool = approx_eq!(1.0, 1.0, max_ulps = 1,);
+    let _: bool = approx_ne!(1.0, 1.0);
+    let _: bool = approx_ne!(1.0, 1.0, epsilon = 1.0,);
+}
+
+#[test]
+fn test_isclose() {
+    let _: bool = isclose!(1.0, 1.0);
+    let _: bool = isclose!(1.0, 1.0, atol = 1.0);
+    let _: bool = isclose!(1.0, 1.0, rtol = 1.0);
+    let _: bool = isclose!(1.0, 1.0, atol = 1.0, rtol = 1.0,);
+    let _: bool = not_isclose!(1.0, 1.0, rtol = 1.0, atol = 1.0);
+}
+
+#[test]
+fn test_math_isclose() {
+    let _: bool = math_isclose!(1.0, 1.0);
+    let _: bool = math_isclose!(1.0, 1.0, rel_tol = 1.0);
+    let _: bool = math_isclose!(1.0, 1.0, abs_tol = 1.0);
+    let _: bool = math_isclose!(1.0, 1.0, rel_tol = 1.0, abs_tol = 1.0,);
+    let _: bool = not_math_isclose!(1.0, 1.0, abs_tol = 1.0, rel_tol = 1.0);
+}
+
 #[test]
 fn test_rvalue_arguments() {
     assert_abs_diff_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
     assert_relative_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
     assert_ulps_eq!(vec![0.0].as_slice(), vec![0.0].as_slice());
+    assert_isclose!(vec![0.0].as_slice(), vec![0.0].as_slice());
+    assert_math_isclose!(vec![0.0].as_slice(), vec![0.0].as_slice());
+}
+
+#[test]
+fn test_assert_messages() {
+    let p = 3;
+    assert_abs_diff_eq!(1.0, 1.0, "p = {}", p);
+    assert_abs_diff_ne!(1.0, 2.0, epsilon = 0.5, "p = {}", p);
+    assert_relative_eq!(1.0, 1.0, "p = {p}");
+    assert_relative_ne!(1.0, 2.0, max_relative = 0.1, "p = {}", p,);
+    assert_ulps_eq!(1.0, 1.0, max_ulps = 1, "sweep");
+    assert_ulps_ne!(1.0, 2.0, "p = {}, q = {}", p, p + 1);
+    assert_isclose!(1.0, 1.0, rtol = 0.1, "p = {}", p);
+    assert_not_isclose!(1.0, 2.0, "p = {}", p);
+    assert_math_isclose!(1.0, 1.0, "p = {}", p);
+    assert_not_math_isclose!(1.0, 2.0, rel_tol = 0.1, abs_tol = 0.1, "p = {}", p);
+}
+
+#[test]
+#[should_panic(expected = "assert_relative_eq!(1.0, 1.1): sweep with p = 3")]
+fn test_assert_message_panic() {
+    let p = 3;
+    assert_relative_eq!(1.0, 1.1, "sweep with p = {}", p);
+}
+
+#[test]
+#[should_panic(expected = "assert_ulps_eq!(1.0, 1.1, max_ulps = 1): sweep with p = 3\n\n    left")]
+fn test_assert_message_options_panic() {
+    let p = 3;
+    assert_ulps_eq!(1.0, 1.1, max_ulps = 1, "sweep with p = {p}");
+}
+
+#[test]
+fn test_assert_options_evaluated_once() {
+    use std::cell::Cell;
+    use std::panic;
+
+    let calls = Cell::new(0);
+    let epsilon = || {
+        calls.set(calls.get() + 1);
+        0.5
+    };
+    assert_abs_diff_eq!(1.0, 1.25, epsilon = epsilon());
+    assert_eq!(calls.get(), 1);
+
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        assert_relative_eq!(1.0, 2.0, epsilon = epsilon(), max_relative = epsilon() / 10.0, "p");
+    }));
+    assert!(result.is_err());
+    assert_eq!(calls.get(), 3);
+}
+
+#[test]
+fn test_debug_asserts() {
+    debug_assert_abs_diff_eq!(1.0, 1.0);
+    debug_assert_abs_diff_ne!(1.0, 2.0, epsilon = 0.5);
+    debug_assert_relative_eq!(1.0, 1.0, epsilon = 1.0, max_relative = 1.0,);
+    debug_assert_relative_ne!(1.0, 2.0, "p = {}", 3);
+    debug_assert_ulps_eq!(1.0, 1.0, max_ulps = 1);
+    debug_assert_ulps_ne!(1.0, 2.0);
+    debug_assert_isclose!(1.0, 1.0, atol = 1.0);
+    debug_assert_not_isclose!(1.0, 2.0);
+    debug_assert_math_isclose!(1.0, 1.0, rel_tol = 1.0);
+    debug_assert_not_math_isclose!(1.0, 2.0);
+}
+
+#[test]
+#[cfg_attr(debug_assertions, should_panic(expected = "assert_ulps_eq!(1.0, 1.1, max_ulps = 1)"))]
+fn test_debug_assert_panic() {
+    debug_assert_ulps_eq!(1.0, 1.1, max_ulps = 1);
+}
+
+#[test]
+fn test_checks() {
+    assert!(check_abs_diff_eq!(1.0, 1.0).is_ok());
+    assert!(check_abs_diff_ne!(1.0, 2.0, epsilon = 0.5).is_ok());
+    assert!(check_relative_eq!(1.0, 1.0, epsilon = 1.0, max_relative = 1.0,).is_ok());
+    assert!(check_relative_ne!(1.0, 2.0).is_ok());
+    assert!(check_ulps_eq!(1.0, 1.0, max_ulps = 1).is_ok());
+    assert!(check_ulps_ne!(1.0, 2.0,).is_ok());
+    assert!(check_isclose!(1.0, 1.0, atol = 1.0).is_ok());
+    assert!(check_not_isclose!(1.0, 2.0).is_ok());
+    assert!(check_math_isclose!(1.0, 1.0, rel_tol = 1.0).is_ok());
+    assert!(check_not_math_isclose!(1.0, 2.0).is_ok());
+
+    assert!(check_abs_diff_eq!(1.0, 2.0).is_err());
+    assert!(check_relative_ne!(1.0, 1.0, max_relative = 0.1).is_err());
+    assert!(check_isclose!(vec![0.0].as_slice(), vec![1.0].as_slice()).is_err());
 }
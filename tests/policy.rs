@@ -0,0 +1,525 @@
+//! Tests for the comparison policies
+#![no_std]
+#![cfg_attr(approxim_nightly_float, feature(f16, f128))]
+
+#[macro_use]
+extern crate approxim;
+
+mod common;
+
+use approxim::{AbsDiffEq, Mixed, NanPolicy, Policy, RelativeEq, UlpsEq, UlpsMode};
+
+const NAN_EQUAL: Policy = Policy::new().nan(NanPolicy::Equal);
+
+mod test_nan {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        assert_abs_diff_ne!(f64::NAN, f64::NAN);
+        assert_relative_ne!(f64::NAN, f64::NAN);
+        assert_ulps_ne!(f64::NAN, f64::NAN);
+        assert_abs_diff_ne!(f64::NAN, f64::NAN, nan_equal = false);
+        assert_eq!(Policy::new(), Policy::default());
+    }
+
+    #[test]
+    fn test_equal() {
+        assert_abs_diff_eq!(f64::NAN, f64::NAN, nan_equal = true);
+        assert_relative_eq!(f32::NAN, f32::NAN, nan_equal = true);
+        assert_ulps_eq!(f64::NAN, -f64::NAN, nan_equal = true);
+        assert_relative_eq!(f64::NAN, f64::NAN, max_relative = 0.0, nan_equal = true);
+    }
+
+    #[test]
+    fn test_number() {
+        assert_abs_diff_ne!(f64::NAN, 1.0, nan_equal = true);
+        assert_relative_ne!(1.0, f64::NAN, nan_equal = true);
+        assert_ulps_ne!(f64::NAN, f64::INFINITY, nan_equal = true);
+        assert_abs_diff_eq!(1.0, 1.0 + 1e-16, nan_equal = true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_equal_panic() {
+        assert_relative_ne!(f64::NAN, f64::NAN, nan_equal = true);
+    }
+
+    #[test]
+    fn test_same_payload() {
+        let nan = f64::from_bits(0x7ff8_0000_0000_0001);
+        let other = f64::from_bits(0x7ff8_0000_0000_0002);
+
+        assert_ulps_eq!(nan, nan, nan_equal = NanPolicy::SamePayload);
+        assert_ulps_ne!(nan, other, nan_equal = NanPolicy::SamePayload);
+        assert_ulps_eq!(nan, other, nan_equal = NanPolicy::Equal);
+        assert_abs_diff_ne!(f32::NAN, -f32::NAN, nan_equal = NanPolicy::SamePayload);
+    }
+
+    #[test]
+    fn test_with() {
+        assert!(f64::NAN.abs_diff_eq_with(&f64::NAN, 0.0, NAN_EQUAL));
+        assert!(f32::NAN.relative_eq_with(&f32::NAN, 0.0, 0.0, NAN_EQUAL));
+        assert!(f64::NAN.ulps_eq_with(&f64::NAN, 0.0, 0, NAN_EQUAL));
+        assert!(!f64::NAN.ulps_eq_with(&f64::NAN, 0.0, 0, Policy::default()));
+    }
+
+    #[test]
+    fn test_builder() {
+        assert!(approxim::Relative::default()
+            .nan_equal(true)
+            .eq(&f64::NAN, &f64::NAN));
+        assert!(approxim::Ulps::default()
+            .nan_equal(true)
+            .nan_equal(false)
+            .ne(&f64::NAN, &f64::NAN));
+        assert!(approxim::AbsDiff::default()
+            .policy(NAN_EQUAL)
+            .eq(&f64::NAN, &f64::NAN));
+        assert!(approxim::Combined::default()
+            .policy(NAN_EQUAL)
+            .nan_equal(false)
+            .ne(&f64::NAN, &f64::NAN));
+        assert_ulps_eq!(f64::NAN, f64::NAN, policy = NAN_EQUAL);
+    }
+
+    #[test]
+    fn test_mixed() {
+        assert_relative_eq!(Mixed(f32::NAN), f64::NAN, nan_equal = true);
+        assert_ulps_eq!(Mixed(f64::NAN), f32::NAN, nan_equal = true);
+        assert_abs_diff_ne!(Mixed(f32::NAN), 1.0f64, nan_equal = true);
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_abs_diff_eq!(1i32, 1i32, nan_equal = true);
+        assert_ulps_ne!(1u8, 2u8, nan_equal = true);
+    }
+}
+
+mod test_ulps_mode {
+    use super::*;
+
+    #[test]
+    fn test_sign_magnitude() {
+        let tiny = f32::from_bits(3);
+        assert_ulps_ne!(tiny, -tiny, epsilon = 0.0, max_ulps = u32::MAX);
+        assert_ulps_ne!(
+            tiny,
+            -tiny,
+            epsilon = 0.0,
+            ulps_mode = UlpsMode::SignMagnitude
+        );
+    }
+
+    #[test]
+    fn test_monotonic() {
+        let tiny = f32::from_bits(3);
+        assert_ulps_eq!(
+            tiny,
+            -tiny,
+            epsilon = 0.0,
+            ulps_mode = UlpsMode::Monotonic,
+            max_ulps = 6
+        );
+        assert_ulps_ne!(
+            tiny,
+            -tiny,
+            epsilon = 0.0,
+            ulps_mode = UlpsMode::Monotonic,
+            max_ulps = 5
+        );
+        assert_ulps_eq!(
+            5e-324,
+            -5e-324,
+            epsilon = 0.0,
+            ulps_mode = UlpsMode::Monotonic,
+            max_ulps = 2
+        );
+        assert_ulps_ne!(
+            -1e-300,
+            1e-300,
+            epsilon = 0.0,
+            ulps_mode = UlpsMode::Monotonic
+        );
+    }
+
+    #[test]
+    fn test_monotonic_same_sign() {
+        let a = f64::from_bits(1.0f64.to_bits() + 4);
+        assert_ulps_eq!(1.0, a, ulps_mode = UlpsMode::Monotonic);
+        assert_ulps_ne!(
+            1.0,
+            f64::from_bits(a.to_bits() + 1),
+            ulps_mode = UlpsMode::Monotonic
+        );
+        assert_ulps_eq!(-1.0, -a, ulps_mode = UlpsMode::Monotonic);
+        assert_ulps_eq!(f32::MAX, f32::INFINITY, ulps_mode = UlpsMode::Monotonic);
+    }
+
+    #[test]
+    fn test_monotonic_nan() {
+        let nan = f32::from_bits(f32::INFINITY.to_bits() + 1);
+        assert_ulps_ne!(f32::INFINITY, nan, ulps_mode = UlpsMode::Monotonic);
+        assert_ulps_ne!(
+            nan,
+            nan,
+            ulps_mode = UlpsMode::Monotonic,
+            max_ulps = u32::MAX
+        );
+        assert_ulps_eq!(nan, nan, ulps_mode = UlpsMode::Monotonic, nan_equal = true);
+    }
+
+    #[test]
+    fn test_distinct_zeros() {
+        assert_ulps_eq!(0.0, -0.0);
+        assert_ulps_ne!(0.0, -0.0, distinct_zeros = true);
+        assert_ulps_ne!(0.0f32, -0.0f32, epsilon = 1.0, distinct_zeros = true);
+        assert_ulps_eq!(-0.0, -0.0, distinct_zeros = true);
+    }
+
+    #[test]
+    fn test_monotonic_distinct_zeros() {
+        assert_ulps_eq!(0.0, -0.0, ulps_mode = UlpsMode::Monotonic, max_ulps = 0);
+        assert_ulps_ne!(
+            0.0,
+            -0.0,
+            ulps_mode = UlpsMode::Monotonic,
+            distinct_zeros = true,
+            max_ulps = 0
+        );
+        assert_ulps_eq!(
+            0.0,
+            -0.0,
+            ulps_mode = UlpsMode::Monotonic,
+            distinct_zeros = true,
+            max_ulps = 1
+        );
+
+        let tiny = f64::from_bits(1);
+        assert_ulps_ne!(
+            tiny,
+            -tiny,
+            epsilon = 0.0,
+            ulps_mode = UlpsMode::Monotonic,
+            distinct_zeros = true,
+            max_ulps = 2
+        );
+        assert_ulps_eq!(
+            tiny,
+            -tiny,
+            epsilon = 0.0,
+            ulps_mode = UlpsMode::Monotonic,
+            distinct_zeros = true,
+            max_ulps = 3
+        );
+    }
+
+    #[test]
+    fn test_with() {
+        let policy = Policy::new().ulps_mode(UlpsMode::Monotonic);
+        assert!(1e-45f32.ulps_eq_with(&-1e-45, 0.0, 2, policy));
+        assert!(!1e-45f32.ulps_eq(&-1e-45, 0.0, 2));
+    }
+
+    #[test]
+    fn test_mixed() {
+        assert_ulps_eq!(
+            Mixed(1e-45f32),
+            -1e-45f64,
+            epsilon = 0.0,
+            ulps_mode = UlpsMode::Monotonic
+        );
+        assert_ulps_ne!(Mixed(1e-45f32), -1e-45f64, epsilon = 0.0);
+        assert_ulps_ne!(Mixed(0.0f64), -0.0f32, distinct_zeros = true);
+        assert_ulps_eq!(
+            Mixed(0.0f64),
+            -0.0f32,
+            distinct_zeros = true,
+            ulps_mode = UlpsMode::Monotonic,
+            max_ulps = 1
+        );
+    }
+
+    #[test]
+    fn test_slice() {
+        let a = [1.0f64, 5e-324, 0.0];
+        let b = [1.0f64, -5e-324, -0.0];
+
+        assert_ulps_ne!(a[..], b[..], epsilon = 0.0);
+        assert_ulps_eq!(a[..], b[..], epsilon = 0.0, ulps_mode = UlpsMode::Monotonic);
+        assert_ulps_ne!(a[..], b[..], distinct_zeros = true);
+    }
+
+    #[cfg(feature = "tuple_impl")]
+    #[test]
+    fn test_tuple() {
+        assert_ulps_eq!(
+            (1e-45f32, 1.0f64),
+            (-1e-45f32, 1.0f64),
+            epsilon = (0.0, 0.0),
+            ulps_mode = UlpsMode::Monotonic
+        );
+        assert_ulps_ne!(
+            (1e-45f32, 1.0f64),
+            (-1e-45f32, 1.0f64),
+            epsilon = (0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_relative_ignored() {
+        assert_relative_eq!(0.0, -0.0, nan_equal = true);
+        let policy = Policy::new().distinct_zeros(true);
+        assert!(0.0f64.relative_eq_with(&-0.0, 0.0, 0.0, policy));
+    }
+}
+
+#[cfg(feature = "half")]
+mod test_half_ulps_mode {
+    use approxim::UlpsMode;
+    use half::{bf16, f16};
+
+    #[test]
+    fn test_monotonic() {
+        let tiny = f16::from_bits(1);
+        assert_ulps_eq!(
+            tiny,
+            -tiny,
+            epsilon = f16::ZERO,
+            ulps_mode = UlpsMode::Monotonic,
+            max_ulps = 2
+        );
+        assert_ulps_ne!(tiny, -tiny, epsilon = f16::ZERO, max_ulps = 2);
+        assert_ulps_eq!(
+            f16::MIN,
+            f16::MAX,
+            ulps_mode = UlpsMode::Monotonic,
+            max_ulps = 1 << 16
+        );
+        assert_ulps_ne!(bf16::ZERO, bf16::NEG_ZERO, distinct_zeros = true);
+    }
+}
+
+mod test_containers {
+    use super::*;
+
+    #[test]
+    fn test_slice() {
+        let a = [1.0f64, f64::NAN, 3.0];
+        let b = [1.0f64, f64::NAN, 3.0];
+
+        assert_relative_ne!(a[..], b[..]);
+        assert_relative_eq!(a[..], b[..], nan_equal = true);
+        assert_ulps_ne!(a[..], [1.0f64, f64::NAN, 4.0][..], nan_equal = true);
+    }
+
+    #[test]
+    fn test_option() {
+        assert_abs_diff_eq!(Some(f32::NAN), Some(f32::NAN), nan_equal = true);
+        assert_abs_diff_ne!(Some(f32::NAN), None, nan_equal = true);
+    }
+
+    #[test]
+    fn test_result() {
+        let a: Result<f64, f32> = Err(f32::NAN);
+        assert!(a.relative_eq_with(&Err(f32::NAN), (0.0, 0.0), (0.0, 0.0), NAN_EQUAL));
+        assert!(a.relative_ne(&Err(f32::NAN), (0.0, 0.0), (0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_ref() {
+        let nan = f64::NAN;
+        assert_ulps_eq!(&nan, &nan, nan_equal = true);
+        assert_ulps_ne!(&nan, &nan);
+    }
+
+    #[cfg(feature = "array_impl")]
+    #[test]
+    fn test_array() {
+        assert_abs_diff_eq!([f64::NAN; 3], [f64::NAN; 3], nan_equal = true);
+        assert_abs_diff_ne!([f64::NAN; 3], [f64::NAN; 3]);
+    }
+
+    #[cfg(feature = "vec_impl")]
+    #[test]
+    fn test_vec() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let a = Vec::from([Vec::from([1.0f32, f32::NAN]), Vec::from([f32::NAN])]);
+        let b = Vec::from([Vec::from([1.0f32, f32::NAN]), Vec::from([f32::NAN])]);
+        assert_relative_eq!(a, b, nan_equal = true);
+        assert_relative_ne!(a, b);
+    }
+
+    #[cfg(feature = "tuple_impl")]
+    #[test]
+    fn test_tuple() {
+        assert_ulps_eq!((1.0f64, f32::NAN), (1.0f64, f32::NAN), nan_equal = true);
+        assert_ulps_ne!((1.0f64, f32::NAN), (1.0f64, f32::NAN));
+        assert_relative_eq!((f64::NAN, 2.0f32), (f64::NAN, 2.0f32), nan_equal = true);
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_complex() {
+        use num_complex::Complex;
+
+        let a = Complex::new(1.0f64, f64::NAN);
+        assert_abs_diff_eq!(a, a, nan_equal = true);
+        assert_relative_ne!(a, a);
+    }
+
+    #[cfg(feature = "ordered-float")]
+    #[test]
+    fn test_ordered_float() {
+        use ordered_float::OrderedFloat;
+
+        assert_ulps_eq!(OrderedFloat(f64::NAN), f64::NAN, nan_equal = true);
+        assert_relative_eq!(f32::NAN, OrderedFloat(f32::NAN), nan_equal = true);
+    }
+
+    #[cfg(feature = "indexmap_impl")]
+    #[test]
+    fn test_indexmap() {
+        use super::common::indexmap::IndexMap;
+
+        let mut a = IndexMap::default();
+        a.insert("a", 1.0f64);
+        a.insert("b", f64::NAN);
+        let mut b = IndexMap::default();
+        b.insert("b", f64::NAN);
+        b.insert("a", 1.0f64);
+
+        assert_abs_diff_eq!(a, b, nan_equal = true);
+        assert_relative_eq!(a, b, nan_equal = true);
+        assert_ulps_eq!(a, b, nan_equal = true);
+        assert_ulps_ne!(a, b);
+    }
+}
+
+#[cfg(feature = "half")]
+mod test_half {
+    use half::{bf16, f16};
+
+    #[test]
+    fn test_nan() {
+        assert_relative_eq!(f16::NAN, f16::NAN, nan_equal = true);
+        assert_ulps_eq!(bf16::NAN, bf16::NAN, nan_equal = true);
+        assert_abs_diff_ne!(bf16::NAN, bf16::NAN);
+    }
+}
+
+#[cfg(approxim_nightly_float)]
+mod test_nightly_float {
+    #[test]
+    fn test_nan() {
+        assert_relative_eq!(f16::NAN, f16::NAN, nan_equal = true);
+        assert_abs_diff_eq!(f128::NAN, f128::NAN, nan_equal = true);
+        assert_ulps_ne!(f128::NAN, f128::NAN);
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_report {
+    extern crate std;
+
+    use super::*;
+
+    #[test]
+    fn test_slice() {
+        let a = [f64::NAN, 1.0, f64::NAN];
+        let b = [f64::NAN, 2.0, 1.0];
+        let report = a[..].abs_diff_report_with(&b[..], 0.0, NAN_EQUAL);
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(
+            report.mismatches()[0].path,
+            [approxim::PathSegment::Index(1)]
+        );
+        assert_eq!(
+            report.mismatches()[1].path,
+            [approxim::PathSegment::Index(2)]
+        );
+        assert_eq!(a[..].abs_diff_report(&b[..], 0.0).len(), 3);
+    }
+
+    #[test]
+    fn test_builder() {
+        let report = approxim::Ulps::default()
+            .nan_equal(true)
+            .report(&[f32::NAN, 1.0][..], &[f32::NAN, 1.0][..]);
+        assert!(report.is_eq());
+    }
+}
+
+mod test_ne_override {
+    use super::*;
+
+    /// Equal to itself, but overrides the inequalities to tell whether they are called.
+    struct Probe;
+
+    impl AbsDiffEq for Probe {
+        type Epsilon = ();
+
+        fn default_epsilon() {}
+
+        fn abs_diff_eq(&self, _: &Probe, _: ()) -> bool {
+            true
+        }
+
+        fn abs_diff_ne(&self, _: &Probe, _: ()) -> bool {
+            true
+        }
+    }
+
+    impl RelativeEq for Probe {
+        fn default_max_relative() {}
+
+        fn relative_eq(&self, _: &Probe, _: (), _: ()) -> bool {
+            true
+        }
+
+        fn relative_ne(&self, _: &Probe, _: (), _: ()) -> bool {
+            true
+        }
+    }
+
+    impl UlpsEq for Probe {
+        fn default_max_ulps() -> u32 {
+            0
+        }
+
+        fn ulps_eq(&self, _: &Probe, _: (), _: u32) -> bool {
+            true
+        }
+
+        fn ulps_ne(&self, _: &Probe, _: (), _: u32) -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn test_default_policy() {
+        assert!(abs_diff_ne!(Probe, Probe));
+        assert!(relative_ne!(Probe, Probe));
+        assert!(ulps_ne!(Probe, Probe));
+        assert!(Probe.abs_diff_ne_with(&Probe, (), Policy::default()));
+    }
+
+    #[test]
+    fn test_other_policy() {
+        assert!(!abs_diff_ne!(Probe, Probe, nan_equal = true));
+        assert!(!relative_ne!(Probe, Probe, nan_equal = true));
+        assert!(!ulps_ne!(Probe, Probe, nan_equal = true));
+        assert!(!Probe.ulps_ne_with(&Probe, (), 0, NAN_EQUAL));
+    }
+
+    #[test]
+    fn test_combined() {
+        assert!(approx_ne!(Probe, Probe));
+        assert!(approx_ne!(Probe, Probe, combine = approxim::Combine::All));
+        assert!(!approx_ne!(Probe, Probe, nan_equal = true));
+    }
+}
@@ -15,6 +15,7 @@
 // Test cases derived from:
 // https://github.com/Pybonacci/puntoflotante.org/blob/master/content/errors/NearlyEqualsTest.java
 #![no_std]
+#![cfg_attr(approxim_nightly_float, feature(f16, f128))]
 
 #[macro_use]
 extern crate approxim;
@@ -396,6 +397,57 @@ mod test_f64 {
     }
 }
 
+mod test_mixed {
+    use approxim::Mixed;
+
+    #[test]
+    fn test_basic() {
+        assert_relative_eq!(Mixed(0.1f32), 0.1f64);
+        assert_relative_eq!(Mixed(0.1f64), 0.1f32);
+        assert_relative_ne!(Mixed(0.1f32), 0.1f64, epsilon = 0.0, max_relative = 1e-10);
+        assert_relative_eq!(Mixed(100.0f32), 101.0f64, max_relative = 0.01);
+        assert_relative_ne!(Mixed(f32::INFINITY), f64::NEG_INFINITY);
+        assert_relative_eq!(Mixed(f64::INFINITY), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_literal_inference() {
+        let x = 0.1f32;
+        assert_relative_eq!(x, 0.1, epsilon = 0.0, max_relative = 0.0);
+        assert_relative_eq!(x, 0.1, epsilon = f32::EPSILON);
+        assert_relative_eq!(x, 0.1, max_relative = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_slice() {
+        assert_relative_eq!([0.1f32, 0.2].map(Mixed)[..], [0.1f64, 0.2][..]);
+        assert_relative_ne!([0.1f32, 0.2].map(Mixed)[..], [0.1f64, 0.3][..]);
+        assert_relative_ne!(
+            [Mixed(1.0f32)][..],
+            [1.00000001f64][..],
+            epsilon = 0.0,
+            max_relative = 0.0
+        );
+    }
+
+    #[cfg(feature = "array_impl")]
+    #[test]
+    fn test_array() {
+        assert_relative_eq!([0.1f64, 0.2].map(Mixed), [0.1f32, 0.2]);
+    }
+
+    #[cfg(feature = "vec_impl")]
+    #[test]
+    fn test_vec() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        let computed: Vec<_> = (1..4).map(|i| Mixed(1.0 / i as f32)).collect();
+        let reference: Vec<f64> = (1..4).map(|i| 1.0 / i as f64).collect();
+        assert_relative_eq!(computed, reference);
+    }
+}
+
 mod test_option {
     mod test_f32 {
         #[test]
@@ -525,6 +577,65 @@ mod test_vec {
     }
 }
 
+mod test_pin {
+    use core::pin::Pin;
+
+    #[test]
+    fn test_basic() {
+        assert_relative_eq!(Pin::new(&1.0f64), Pin::new(&1.0f64));
+        assert_relative_ne!(Pin::new(&1.0f64), Pin::new(&2.0f64));
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_pointer {
+    extern crate alloc;
+    use alloc::borrow::Cow;
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+    use core::pin::Pin;
+
+    #[test]
+    fn test_basic() {
+        assert_relative_eq!(Box::new(1.0f64), Box::new(1.0f64));
+        assert_relative_ne!(Box::new(1.0f64), Box::new(2.0f64));
+        assert_relative_eq!(Rc::new(1.0f32), Rc::new(1.0f32));
+        assert_relative_ne!(Rc::new(1.0f32), Rc::new(2.0f32));
+        assert_relative_eq!(Arc::new(Some(1.0f64)), Arc::new(Some(1.0f64)));
+        assert_relative_ne!(Arc::new(Some(1.0f64)), Arc::new(None));
+        assert_relative_eq!(Box::pin(1.0f64), Box::pin(1.0f64));
+        assert_relative_ne!(Box::pin(1.0f64), Box::pin(2.0f64));
+    }
+
+    #[test]
+    fn test_slice() {
+        let a: Box<[f64]> = Box::new([1.0, 2.0]);
+        let b: Arc<[f64]> = Arc::from([1.0, 2.0]);
+        assert_relative_eq!(a, Box::from([1.0, 2.0]));
+        assert_relative_ne!(a, Box::from([1.0]));
+        assert_relative_eq!(b, Arc::from([1.0, 2.0]));
+        assert_relative_ne!(b, Arc::from([2.0, 1.0]));
+        assert_relative_eq!(Rc::new(Box::new(1.0f64)), Rc::new(Box::new(1.0f64)));
+        assert_relative_eq!(Pin::new(Box::new(1.0f64)), Pin::new(Box::new(1.0f64)));
+    }
+
+    #[test]
+    fn test_cow() {
+        let borrowed: Cow<'_, [f64]> = Cow::Borrowed(&[1.0, 2.0]);
+        let owned: Cow<'_, [f64]> = Cow::Owned([1.0, 2.0].into());
+        assert_relative_eq!(borrowed, owned);
+        assert_relative_ne!(borrowed, Cow::Borrowed(&[1.0, 3.0][..]));
+        assert_relative_eq!(Cow::<f64>::Owned(1.0), Cow::Borrowed(&1.0));
+    }
+
+    #[test]
+    fn test_tolerance() {
+        assert_relative_eq!(Arc::new(1.0f64), Arc::new(1.05f64), max_relative = 0.1);
+        assert_relative_ne!(Arc::new(1.0f64), Arc::new(1.05f64), max_relative = 0.01);
+    }
+}
+
 #[cfg(feature = "tuple_impl")]
 mod test_tuple{
     use approxim::RelativeEq;
@@ -597,6 +708,92 @@ mod test_complex {
     }
 }
 
+#[cfg(approxim_nightly_float)]
+mod test_nightly_float {
+    mod test_f16 {
+        #[test]
+        fn test_basic() {
+            assert_relative_eq!(1.0f16, 1.0f16);
+            assert_relative_ne!(1.0f16, 2.0f16);
+            assert_relative_eq!(1000.0f16, 1000.5f16);
+            assert_relative_ne!(1000.0f16, 1002.0f16);
+            assert_relative_ne!(f16::INFINITY, f16::MAX);
+        }
+    }
+
+    mod test_f128 {
+        #[test]
+        fn test_basic() {
+            assert_relative_eq!(1.0f128, 1.0f128);
+            assert_relative_ne!(1.0f128, 2.0f128);
+            // Beyond the precision of f64
+            assert_relative_ne!(1.0e30f128, 1.0e30f128 + 1.0e-3, epsilon = 0.0);
+            assert_relative_eq!(
+                1.0e30f128,
+                1.0e30f128 + 1.0e-3,
+                epsilon = 0.0,
+                max_relative = 1e-32
+            );
+        }
+    }
+}
+
+#[cfg(feature = "half")]
+mod test_half {
+    extern crate half;
+    pub use self::half::{bf16, f16};
+
+    mod test_f16 {
+        use super::f16;
+
+        #[test]
+        fn test_basic() {
+            assert_relative_eq!(f16::from_f32(1.0), f16::from_f32(1.0));
+            assert_relative_ne!(f16::from_f32(1.0), f16::from_f32(2.0));
+        }
+
+        #[test]
+        fn test_big() {
+            assert_relative_eq!(f16::from_f32(1000.0), f16::from_f32(1000.5));
+            assert_relative_ne!(f16::from_f32(1000.0), f16::from_f32(1002.0));
+            assert_relative_eq!(
+                f16::from_f32(1000.0),
+                f16::from_f32(1002.0),
+                max_relative = f16::from_f32(0.002)
+            );
+        }
+
+        #[test]
+        fn test_infinity() {
+            assert_relative_eq!(f16::INFINITY, f16::INFINITY);
+            assert_relative_ne!(f16::INFINITY, f16::MAX);
+            assert_relative_ne!(f16::NEG_INFINITY, f16::INFINITY);
+        }
+
+        #[test]
+        fn test_nan() {
+            assert_relative_ne!(f16::NAN, f16::NAN);
+        }
+    }
+
+    mod test_bf16 {
+        use super::bf16;
+
+        #[test]
+        fn test_basic() {
+            assert_relative_eq!(bf16::from_f32(1.0), bf16::from_f32(1.0));
+            assert_relative_ne!(bf16::from_f32(1.0), bf16::from_f32(2.0));
+        }
+
+        #[test]
+        fn test_big() {
+            // bf16 has the range of f32, but only 8 bits of precision
+            assert_relative_eq!(bf16::from_f32(1.0e30), bf16::from_f32(1.003e30));
+            assert_relative_ne!(bf16::from_f32(1.0e30), bf16::from_f32(1.02e30));
+        }
+    }
+}
+
 #[cfg(feature = "ordered-float")]
 mod test_ordered_float {
     extern crate ordered_float;
@@ -671,3 +868,105 @@ mod test_indexmap {
         );
     }
 }
+
+#[cfg(feature = "vec_deque_impl")]
+mod test_vec_deque {
+    extern crate alloc;
+    use alloc::collections::VecDeque;
+
+    #[test]
+    fn test_basic() {
+        let mut a = VecDeque::from([2.0f64, 3.0]);
+        a.push_front(1.0);
+        assert_relative_eq!(a, VecDeque::from([1.0f64, 2.0, 3.0]));
+        assert_relative_ne!(a, VecDeque::from([1.0f64, 2.0, 4.0]));
+        assert_relative_ne!(a, VecDeque::from([1.0f64, 2.0]));
+    }
+}
+
+#[cfg(feature = "linked_list_impl")]
+mod test_linked_list {
+    extern crate alloc;
+    use alloc::collections::LinkedList;
+
+    #[test]
+    fn test_basic() {
+        assert_relative_eq!(LinkedList::from([1.0f32, 2.0]), LinkedList::from([1.0f32, 2.0]));
+        assert_relative_ne!(LinkedList::from([1.0f32, 2.0]), LinkedList::from([2.0f32, 1.0]));
+        assert_relative_ne!(LinkedList::from([1.0f32, 2.0]), LinkedList::<f32>::new());
+    }
+}
+
+#[cfg(feature = "btree_map_impl")]
+mod test_btree_map {
+    extern crate alloc;
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn test_basic() {
+        let a = BTreeMap::from([("energy", 1.0f64), ("time", 2.0)]);
+        assert_relative_eq!(a, BTreeMap::from([("time", 2.0f64), ("energy", 1.0)]));
+        assert_relative_ne!(a, BTreeMap::from([("energy", 1.0f64), ("time", 3.0)]));
+        assert_relative_ne!(a, BTreeMap::from([("energy", 1.0f64), ("mass", 2.0)]));
+        assert_relative_ne!(a, BTreeMap::from([("energy", 1.0f64)]));
+    }
+}
+
+#[cfg(feature = "hash_map_impl")]
+mod test_hash_map {
+    extern crate std;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_basic() {
+        let a = HashMap::from([(1, 1.0f64), (2, 2.0)]);
+        assert_relative_eq!(a, HashMap::from([(2, 2.0f64), (1, 1.0)]));
+        assert_relative_eq!(a, HashMap::from([(1, 1.0f64), (2, 2.2)]), max_relative = 0.1);
+        assert_relative_ne!(a, HashMap::from([(1, 1.0f64), (2, 2.2)]), max_relative = 0.05);
+        assert_relative_ne!(a, HashMap::from([(1, 1.0f64), (3, 2.0)]));
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_cross {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_vec_array() {
+        let computed: Vec<f64> = (1..4).map(|i| i as f64 / 3.0 * 3.0).collect();
+        assert_relative_eq!(computed, [1.0, 2.0, 3.0]);
+        assert_relative_eq!([1.0, 2.0, 3.0], computed);
+        assert_relative_ne!(computed, [1.0, 2.0, 3.5]);
+        assert_relative_ne!(computed, [1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_vec_slice() {
+        let computed = Vec::from([1.0f32, 2.0]);
+        assert_relative_eq!(computed, [1.0f32, 2.0][..]);
+        assert_relative_eq!(computed[..], computed);
+        assert_relative_eq!(computed, &[1.0f32, 2.0][..]);
+        assert_relative_ne!(&[1.0f32][..], computed);
+    }
+
+    #[cfg(feature = "array_impl")]
+    #[test]
+    fn test_array_slice() {
+        assert_relative_eq!([1.0f64, 2.0], [1.0f64, 2.0][..]);
+        assert_relative_eq!([1.0f64, 2.0][..], [1.0f64, 2.0]);
+        assert_relative_ne!([1.0f64, 2.0][..], [1.0f64, 2.0, 3.0]);
+    }
+
+    #[cfg(feature = "vec_deque_impl")]
+    #[test]
+    fn test_vec_deque_slice() {
+        use alloc::collections::VecDeque;
+
+        let mut a = VecDeque::from([2.0f64, 3.0]);
+        a.push_front(1.0);
+        assert_relative_eq!(a, [1.0f64, 2.0, 3.0][..]);
+        assert_relative_eq!([1.0f64, 2.0, 3.0][..], a);
+        assert_relative_ne!(a, [1.0f64, 2.0][..]);
+    }
+}
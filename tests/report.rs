@@ -0,0 +1,434 @@
+//! Tests for the structured comparison reports
+#![cfg(feature = "alloc")]
+
+extern crate approxim;
+
+mod common;
+
+use approxim::{AbsDiffEq, MismatchKind, Mixed, PathSegment, RelativeEq, UlpsEq};
+
+mod test_f64 {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert!(1.0f64.abs_diff_report(&1.0, 0.1).is_eq());
+        assert!(1.0f64.relative_report(&1.0, 0.0, 0.0).is_eq());
+        assert!(1.0f64.ulps_report(&1.0, 0.0, 0).is_eq());
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        let report = 1.0f64.abs_diff_report(&1.5, 0.1);
+        let mismatch = report.first().unwrap();
+
+        assert_eq!(report.len(), 1);
+        assert!(mismatch.path.is_empty());
+        assert_eq!(mismatch.kind, MismatchKind::Value);
+        assert_eq!(mismatch.left.as_deref(), Some("1.0"));
+        assert_eq!(mismatch.right.as_deref(), Some("1.5"));
+        assert_eq!(mismatch.abs_diff, Some(0.5));
+        assert_eq!(mismatch.rel_diff, Some(0.5 / 1.5));
+        assert_eq!(mismatch.epsilon, Some(0.1));
+        assert_eq!(mismatch.max_relative, None);
+        assert_eq!(mismatch.max_ulps, None);
+    }
+
+    #[test]
+    fn test_relative() {
+        let report = 1.0f64.relative_report(&2.0, 0.0, 0.25);
+        let mismatch = report.first().unwrap();
+
+        assert_eq!(mismatch.rel_diff, Some(0.5));
+        assert_eq!(mismatch.epsilon, Some(0.0));
+        assert_eq!(mismatch.max_relative, Some(0.25));
+    }
+
+    #[test]
+    fn test_ulps() {
+        let b = f64::from_bits(1.0f64.to_bits() + 5);
+        let report = 1.0f64.ulps_report(&b, 0.0, 4);
+        let mismatch = report.first().unwrap();
+
+        assert_eq!(mismatch.ulps, Some(5));
+        assert_eq!(mismatch.max_ulps, Some(4));
+        assert!(1.0f64.ulps_report(&b, 0.0, 5).is_eq());
+    }
+
+    #[test]
+    fn test_ulps_sign() {
+        let report = 1.0f64.ulps_report(&-1.0, 0.0, 4);
+        assert_eq!(report.first().unwrap().ulps, None);
+    }
+}
+
+mod test_mixed {
+    use super::*;
+
+    #[test]
+    fn test_values() {
+        let report = Mixed(0.5f32).relative_report(&0.75f64, 0.0, 0.1);
+        let mismatch = report.first().unwrap();
+
+        assert_eq!(mismatch.left.as_deref(), Some("0.5"));
+        assert_eq!(mismatch.right.as_deref(), Some("0.75"));
+        assert_eq!(mismatch.abs_diff, Some(0.25));
+        assert_eq!(mismatch.max_relative, Some(0.1));
+    }
+
+    #[test]
+    fn test_widened() {
+        let report = Mixed(0.1f32).abs_diff_report(&0.1f64, 0.0);
+        let mismatch = report.first().unwrap();
+
+        assert_eq!(mismatch.abs_diff, Some(f64::from(0.1f32) - 0.1));
+    }
+}
+
+mod test_slice {
+    use super::*;
+
+    #[test]
+    fn test_all_positions() {
+        let a = [1.0f32, 2.0, 3.0, 4.0];
+        let b = [1.0f32, 2.5, 3.0, 5.0];
+        let report = a[..].relative_report(&b[..], 0.0, 0.01);
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.mismatches()[0].path, [PathSegment::Index(1)]);
+        assert_eq!(report.mismatches()[1].path, [PathSegment::Index(3)]);
+        assert_eq!(report.mismatches()[1].abs_diff, Some(1.0));
+    }
+
+    #[test]
+    fn test_length() {
+        let report = [1.0f64, 2.0][..].abs_diff_report(&[1.0f64][..], 0.0);
+        let mismatch = report.first().unwrap();
+
+        assert!(mismatch.path.is_empty());
+        assert_eq!(mismatch.kind, MismatchKind::Length { left: 2, right: 1 });
+    }
+}
+
+mod test_option {
+    use super::*;
+
+    #[test]
+    fn test_variant() {
+        let report = Some(1.0f64).abs_diff_report(&None, 0.0);
+        let mismatch = report.first().unwrap();
+
+        assert_eq!(mismatch.kind, MismatchKind::Variant);
+        assert_eq!(mismatch.left.as_deref(), Some("Some(..)"));
+        assert_eq!(mismatch.right.as_deref(), Some("None"));
+    }
+
+    #[test]
+    fn test_value() {
+        let report = Some(1.0f64).ulps_report(&Some(2.0), 0.0, 4);
+        assert_eq!(report.len(), 1);
+        assert!(Some(1.0f64).ulps_report(&Some(1.0), 0.0, 4).is_eq());
+    }
+}
+
+mod test_result {
+    use super::*;
+
+    #[test]
+    fn test_variant() {
+        let a: Result<f64, f64> = Ok(1.0);
+        let b: Result<f64, f64> = Err(1.0);
+        let report = a.relative_report(&b, (0.0, 0.0), (0.0, 0.0));
+        assert_eq!(report.first().unwrap().kind, MismatchKind::Variant);
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_vec {
+    use super::*;
+
+    #[test]
+    fn test_nested() {
+        let a = vec![vec![1.0f64, 2.0], vec![3.0, 4.0]];
+        let b = vec![vec![1.0f64, 2.0], vec![3.0, 4.5]];
+        let report = approxim::Relative::default().report(&a, &b);
+
+        assert_eq!(report.len(), 1);
+        assert_eq!(
+            report.first().unwrap().path,
+            [PathSegment::Index(1), PathSegment::Index(1)]
+        );
+    }
+}
+
+#[cfg(feature = "tuple_impl")]
+mod test_tuple {
+    use super::*;
+
+    #[test]
+    fn test_field() {
+        let report = (1.0f64, 2.0f32).abs_diff_report(&(1.0, 3.0), (0.0, 0.0));
+        let mismatch = report.first().unwrap();
+
+        assert_eq!(mismatch.path, [PathSegment::Tuple(1)]);
+        assert_eq!(mismatch.abs_diff, Some(1.0));
+    }
+}
+
+#[cfg(feature = "num-complex")]
+mod test_complex {
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn test_field() {
+        let a = Complex::new(1.0f64, 2.0);
+        let b = Complex::new(1.0f64, 2.5);
+        let report = a.ulps_report(&b, 0.0, 4);
+
+        assert_eq!(report.first().unwrap().path, [PathSegment::Field("im")]);
+    }
+}
+
+#[cfg(feature = "indexmap_impl")]
+mod test_indexmap {
+    use super::common::indexmap::IndexMap;
+    use super::*;
+
+    #[test]
+    fn test_missing_key() {
+        let mut a = IndexMap::default();
+        a.insert("a", 1.0f64);
+        a.insert("b", 2.0f64);
+        let mut b = IndexMap::default();
+        b.insert("a", 1.5f64);
+        b.insert("c", 2.0f64);
+        let report = a.abs_diff_report(&b, 0.0);
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.mismatches()[0].path, [PathSegment::Key("\"a\"".into())]);
+        assert_eq!(report.mismatches()[0].abs_diff, Some(0.5));
+        assert_eq!(report.mismatches()[1].path, [PathSegment::Key("\"b\"".into())]);
+        assert_eq!(report.mismatches()[1].kind, MismatchKind::MissingKey);
+        assert_eq!(report.mismatches()[1].display_path().to_string(), "[\"b\"]");
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_cross {
+    use super::*;
+
+    #[test]
+    fn test_vec_array() {
+        let a = vec![1.0f64, 2.0, 3.0];
+        let report = a.relative_report(&[1.0, 2.5, 3.0], 0.0, 0.0);
+
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.first().unwrap().path, [PathSegment::Index(1)]);
+    }
+}
+
+#[cfg(feature = "vec_deque_impl")]
+mod test_vec_deque {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_all_positions() {
+        let a = VecDeque::from([1.0f64, 2.0, 3.0]);
+        let report = a.relative_report(&VecDeque::from([1.5, 2.0, 3.5]), 0.0, 0.0);
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.mismatches()[0].path, [PathSegment::Index(0)]);
+        assert_eq!(report.mismatches()[1].path, [PathSegment::Index(2)]);
+    }
+}
+
+#[cfg(feature = "btree_map_impl")]
+mod test_btree_map {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_missing_key() {
+        let a = BTreeMap::from([("a", 1.0f64), ("b", 2.0)]);
+        let b = BTreeMap::from([("a", 1.5f64), ("c", 2.0)]);
+        let report = a.ulps_report(&b, 0.0, 0);
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.mismatches()[0].path, [PathSegment::Key("\"a\"".into())]);
+        assert_eq!(report.mismatches()[0].kind, MismatchKind::Value);
+        assert_eq!(report.mismatches()[1].path, [PathSegment::Key("\"b\"".into())]);
+        assert_eq!(report.mismatches()[1].kind, MismatchKind::MissingKey);
+    }
+
+    #[test]
+    fn test_length() {
+        let a = BTreeMap::from([("a", 1.0f64)]);
+        let report = a.abs_diff_report(&BTreeMap::<&str, f64>::new(), 0.0);
+        assert_eq!(report.first().unwrap().kind, MismatchKind::Length { left: 1, right: 0 });
+    }
+}
+
+#[cfg(feature = "hash_map_impl")]
+mod test_hash_map {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_key() {
+        let a: HashMap<_, _> = (0..16).map(|i| (i, f64::from(i))).collect();
+        let mut b = a.clone();
+        b.insert(7, 7.5);
+        let report = a.relative_report(&b, 0.0, 0.0);
+
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.first().unwrap().path, [PathSegment::Key("7".into())]);
+        assert_eq!(report.first().unwrap().display_path().to_string(), "[7]");
+    }
+}
+
+#[cfg(feature = "derive")]
+mod test_derive {
+    use super::*;
+    use approxim::{NanPolicy, Policy};
+
+    #[derive(RelativeEq, UlpsEq, PartialEq, Debug)]
+    struct Position(f64, f64);
+
+    #[derive(RelativeEq, UlpsEq, PartialEq, Debug)]
+    struct Particle {
+        position: Position,
+        mass: f64,
+        #[approx(equal)]
+        id: u32,
+        #[approx(skip)]
+        #[allow(dead_code)]
+        label: &'static str,
+    }
+
+    #[derive(RelativeEq, PartialEq, Debug)]
+    enum Shape {
+        Circle { radius: f64 },
+        Rectangle(f64, f64),
+    }
+
+    fn particle(mass: f64, id: u32) -> Particle {
+        Particle {
+            position: Position(0.0, 1.0),
+            mass,
+            id,
+            label: "",
+        }
+    }
+
+    #[test]
+    fn test_fields() {
+        let report = particle(1.0, 1).relative_report(&particle(1.5, 2), 0.0, 0.1);
+
+        assert_eq!(report.len(), 2);
+        assert_eq!(report.mismatches()[0].path, [PathSegment::Field("mass")]);
+        assert_eq!(report.mismatches()[0].abs_diff, Some(0.5));
+        assert_eq!(report.mismatches()[0].max_relative, Some(0.1));
+        assert_eq!(report.mismatches()[1].path, [PathSegment::Field("id")]);
+        assert_eq!(report.mismatches()[1].left, None);
+    }
+
+    #[test]
+    fn test_nested() {
+        let mut other = particle(1.0, 1);
+        other.position.1 = 2.0;
+        let report = particle(1.0, 1).ulps_report(&other, 0.0, 4);
+
+        assert_eq!(report.len(), 1);
+        assert_eq!(
+            report.first().unwrap().path,
+            [PathSegment::Field("position"), PathSegment::Tuple(1)]
+        );
+        assert_eq!(
+            report.first().unwrap().display_path().to_string(),
+            ".position.1"
+        );
+    }
+
+    #[test]
+    fn test_policy() {
+        let (a, b) = (particle(f64::NAN, 1), particle(f64::NAN, 1));
+        let policy = Policy::new().nan(NanPolicy::Equal);
+
+        assert_eq!(a.abs_diff_report(&b, 0.0).len(), 1);
+        assert!(a.abs_diff_report_with(&b, 0.0, policy).is_eq());
+    }
+
+    #[test]
+    fn test_enum() {
+        let a = Shape::Rectangle(1.0, 2.0);
+        let report = a.abs_diff_report(&Shape::Rectangle(1.0, 2.5), 0.1);
+        assert_eq!(report.first().unwrap().path, [PathSegment::Tuple(1)]);
+
+        let report = Shape::Circle { radius: 1.0 }.abs_diff_report(&a, 0.1);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.first().unwrap().kind, MismatchKind::Variant);
+    }
+}
+
+mod test_display {
+    use super::*;
+
+    #[test]
+    fn test_path() {
+        let mut mismatch = approxim::Mismatch::new(MismatchKind::Value);
+        mismatch.path = vec![
+            PathSegment::Index(3),
+            PathSegment::Tuple(1),
+            PathSegment::Field("re"),
+        ];
+        assert_eq!(mismatch.display_path().to_string(), "[3].1.re");
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let report = [1.0f64, 2.0][..].abs_diff_report(&[1.0, 2.5][..], 0.1);
+        assert_eq!(
+            report.first().unwrap().to_string(),
+            "values differ at [1]
+    left         = 2.0
+    right        = 2.5
+    abs_diff     = 0.5
+    rel_diff     = 0.2
+    ulps         = 1125899906842624
+    epsilon      = 0.1"
+        );
+    }
+
+    #[test]
+    fn test_length() {
+        let report = [1.0f64, 2.0][..].abs_diff_report(&[1.0][..], 0.1);
+        assert_eq!(report.to_string(), "first of 1 mismatch(es):\nlengths differ (2 != 1)");
+    }
+}
+
+mod test_assert {
+    #[test]
+    #[should_panic(expected = "values differ at [1]")]
+    fn test_slice() {
+        approxim::assert_relative_eq!([1.0f64, 2.0][..], [1.0f64, 2.5][..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_ulps     = 2")]
+    fn test_options() {
+        approxim::assert_ulps_eq!([1.0f64, 2.0][..], [1.0f64, 2.5][..], max_ulps = 2);
+    }
+
+    #[cfg(all(feature = "vec_impl", feature = "tuple_impl", feature = "num-complex"))]
+    #[test]
+    #[should_panic(expected = "values differ at [1].1.im")]
+    fn test_nested() {
+        use num_complex::Complex;
+
+        let a = vec![(1.0f64, Complex::new(1.0, 2.0)), (2.0, Complex::new(1.0, 2.0))];
+        let b = vec![(1.0f64, Complex::new(1.0, 2.0)), (2.0, Complex::new(1.0, 2.5))];
+        approxim::assert_abs_diff_eq!(a, b, epsilon = (1e-9, 1e-9));
+    }
+}
@@ -0,0 +1,164 @@
+//! Tests for the implementations on locks and once-initialized cells
+#![cfg(feature = "std")]
+
+#[macro_use]
+extern crate approxim;
+
+use approxim::{abs_diff, AbsDiffEq, RelativeEq};
+use std::cell::OnceCell;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::{panic, thread};
+
+mod test_mutex {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(Mutex::new(1.0f64), Mutex::new(1.0f64));
+        assert_abs_diff_ne!(Mutex::new(1.0f64), Mutex::new(2.0f64));
+        let a: Box<[f64]> = Box::new([1.0, 2.0]);
+        assert_relative_eq!(Mutex::new(a.clone()), Mutex::new(a));
+        assert_relative_ne!(Mutex::new(1.0f64), Mutex::new(1.1f64));
+        assert_ulps_eq!(Mutex::new(Some(1.0f32)), Mutex::new(Some(1.0f32)));
+        assert_isclose!(Mutex::new(1.0f64), Mutex::new(1.0f64 + 1e-9));
+        assert_eq!(abs_diff(&Mutex::new(1.0f64), &Mutex::new(1.5f64)), 0.5);
+    }
+
+    #[test]
+    fn test_self() {
+        let a = Mutex::new(1.0f64);
+        assert_relative_eq!(a, a);
+        let nan = Mutex::new(f64::NAN);
+        assert_relative_ne!(nan, nan);
+    }
+
+    #[test]
+    fn test_poisoned() {
+        let a = Arc::new(Mutex::new(1.0f64));
+        let poisoner = Arc::clone(&a);
+        let _ = thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poison the lock");
+        })
+        .join();
+        assert!(a.is_poisoned());
+        assert_relative_eq!(*a, Mutex::new(1.0f64));
+        assert_relative_ne!(*a, Mutex::new(2.0f64));
+    }
+
+    #[test]
+    fn test_concurrent() {
+        let a = Arc::new(Mutex::new(1.0f64));
+        let b = Arc::new(Mutex::new(1.0f64));
+        let threads: Vec<_> = (0..4)
+            .map(|i| {
+                let (a, b) = (Arc::clone(&a), Arc::clone(&b));
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        if i % 2 == 0 {
+                            assert!(a.abs_diff_eq(&b, 0.0));
+                        } else {
+                            assert!(b.abs_diff_eq(&a, 0.0));
+                        }
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+}
+
+mod test_rwlock {
+    use super::*;
+
+    #[test]
+    fn test_basic() {
+        assert_abs_diff_eq!(RwLock::new(1.0f64), RwLock::new(1.0f64));
+        assert_relative_ne!(RwLock::new(1.0f64), RwLock::new(1.1f64));
+        assert_ulps_eq!(RwLock::new(1.0f64), RwLock::new(1.0f64));
+        assert_not_isclose!(RwLock::new(1.0f64), RwLock::new(1.1f64));
+    }
+
+    #[test]
+    fn test_self() {
+        let grid: RwLock<Box<[f64]>> = RwLock::new(Box::new([0.5, 1.5]));
+        let _reader = grid.read().unwrap();
+        assert_relative_eq!(grid, grid);
+    }
+
+    #[test]
+    fn test_poisoned() {
+        let a = RwLock::new(1.0f64);
+        let _ = panic::catch_unwind(|| {
+            let _guard = a.write().unwrap();
+            panic!("poison the lock");
+        });
+        assert!(a.is_poisoned());
+        assert_ulps_eq!(a, RwLock::new(1.0f64));
+    }
+
+    #[test]
+    fn test_report() {
+        let a: RwLock<Box<[f64]>> = RwLock::new(Box::new([1.0, 2.0]));
+        let b: RwLock<Box<[f64]>> = RwLock::new(Box::new([1.0, 2.5]));
+        let report = a.relative_report(&b, f64::EPSILON, f64::EPSILON);
+        assert_eq!(report.len(), 1);
+    }
+}
+
+mod test_once {
+    use super::*;
+
+    #[test]
+    fn test_once_cell() {
+        let (a, b) = (OnceCell::new(), OnceCell::new());
+        assert_relative_eq!(a, b);
+        a.set(1.0f64).unwrap();
+        assert_relative_ne!(a, b);
+        b.set(1.0f64 + 1e-17).unwrap();
+        assert_relative_eq!(a, b);
+        assert_abs_diff_ne!(a, OnceCell::from(2.0), epsilon = 0.5);
+        assert_ulps_eq!(a, a);
+    }
+
+    #[test]
+    fn test_once_lock() {
+        let (a, b) = (OnceLock::new(), OnceLock::new());
+        assert_relative_eq!(a, b);
+        a.set(1.0f64).unwrap();
+        assert_relative_ne!(a, b);
+        b.set(1.1f64).unwrap();
+        assert_relative_eq!(a, b, max_relative = 0.1);
+        assert_isclose!(a, OnceLock::from(1.0f64));
+        assert_eq!(abs_diff(&a, &OnceLock::from(1.5)), 0.5);
+    }
+}
+
+#[cfg(feature = "derive")]
+mod test_derive {
+    use super::*;
+
+    #[derive(RelativeEq, Debug)]
+    struct Simulation {
+        state: Mutex<f64>,
+        grid: RwLock<Box<[f64]>>,
+    }
+
+    #[test]
+    fn test_fields() {
+        let a = Simulation {
+            state: Mutex::new(1.0),
+            grid: RwLock::new(Box::new([0.5, 1.5])),
+        };
+        let b = Simulation {
+            state: Mutex::new(1.0 + 1e-17),
+            grid: RwLock::new(Box::new([0.5, 1.5])),
+        };
+        assert_relative_eq!(a, b);
+        assert_relative_eq!(a, a);
+        *b.grid.write().unwrap() = Box::new([0.5, 2.5]);
+        assert_relative_ne!(a, b);
+    }
+}
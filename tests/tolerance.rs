@@ -0,0 +1,233 @@
+//! Tests for the runtime tolerances
+#![no_std]
+
+#[macro_use]
+extern crate approxim;
+
+use approxim::{Combine, NanPolicy, Policy, Tolerance};
+
+fn next(x: f64, ulps: u64) -> f64 {
+    f64::from_bits(x.to_bits() + ulps)
+}
+
+mod test_eq {
+    use super::*;
+
+    #[test]
+    fn test_abs_diff() {
+        let tolerance = Tolerance::AbsDiff {
+            epsilon: Some(0.5),
+            policy: Policy::default(),
+        };
+        assert!(tolerance.eq(&1.0, &1.25));
+        assert!(tolerance.ne(&1.0, &2.0));
+
+        let tolerance = Tolerance::AbsDiff {
+            epsilon: None,
+            policy: Policy::default(),
+        };
+        assert!(tolerance.eq(&1.0, &(1.0 + f64::EPSILON)));
+        assert!(tolerance.ne(&1.0, &1.25));
+    }
+
+    #[test]
+    fn test_relative() {
+        let tolerance = Tolerance::Relative {
+            epsilon: None,
+            max_relative: Some(0.1),
+            policy: Policy::default(),
+        };
+        assert!(tolerance.eq(&100.0, &105.0));
+        assert!(tolerance.ne(&100.0, &115.0));
+    }
+
+    #[test]
+    fn test_ulps() {
+        let tolerance = Tolerance::Ulps {
+            epsilon: None,
+            max_ulps: Some(2),
+            policy: Policy::default(),
+        };
+        assert!(tolerance.eq(&1.0, &next(1.0, 2)));
+        assert!(tolerance.ne(&1.0, &next(1.0, 3)));
+    }
+
+    #[test]
+    fn test_combined() {
+        let mut tolerance = Tolerance::Combined {
+            epsilon: None,
+            max_relative: None,
+            max_ulps: Some(4),
+            combine: Combine::Any,
+            policy: Policy::default(),
+        };
+        assert!(tolerance.eq(&1.0, &next(1.0, 3)));
+        if let Tolerance::Combined { combine, .. } = &mut tolerance {
+            *combine = Combine::All;
+        }
+        assert!(tolerance.ne(&1.0, &next(1.0, 3)));
+    }
+
+    #[test]
+    fn test_policy() {
+        let tolerance = Tolerance::Relative {
+            epsilon: None,
+            max_relative: None,
+            policy: Policy::new().nan(NanPolicy::Equal),
+        };
+        assert!(tolerance.eq(&f64::NAN, &f64::NAN));
+    }
+
+    #[test]
+    fn test_f32() {
+        let tolerance = Tolerance::Relative {
+            epsilon: None,
+            max_relative: Some(0.1f32),
+            policy: Policy::default(),
+        };
+        assert!(tolerance.eq(&1.0f32, &1.05f32));
+        assert!(tolerance.ne(&1.0f32, &1.5f32));
+    }
+
+    #[test]
+    fn test_containers() {
+        let tolerance = Tolerance::AbsDiff {
+            epsilon: Some(0.1),
+            policy: Policy::default(),
+        };
+        assert!(tolerance.eq(&[1.0, 2.0][..], &[1.05, 2.0][..]));
+        assert!(tolerance.ne(&[1.0, 2.0][..], &[1.5, 2.0][..]));
+        assert!(tolerance.eq(&Some(1.0), &Some(1.05)));
+    }
+
+    #[test]
+    fn test_assert() {
+        let tolerance = Tolerance::Relative {
+            epsilon: None,
+            max_relative: Some(1e-9),
+            policy: Policy::default(),
+        };
+        assert_approx!(0.1 + 0.2, 0.3, using = tolerance);
+        assert_approx!(
+            1.0,
+            1.1,
+            using = approxim::Comparator::<f64>::not(tolerance)
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_report {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let tolerance = Tolerance::Ulps {
+            epsilon: Some(0.0),
+            max_ulps: Some(1),
+            policy: Policy::default(),
+        };
+        let report = tolerance.report(&[1.0, 2.0][..], &[1.0, 2.5][..]);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report.first().unwrap().max_ulps, Some(1));
+        assert!(tolerance.report(&1.0, &next(1.0, 1)).is_eq());
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+mod test_serde {
+    extern crate std;
+
+    use super::*;
+    use approxim::UlpsMode;
+    use std::collections::BTreeMap;
+    use std::string::ToString;
+
+    #[test]
+    fn test_deserialize() {
+        let tolerance: Tolerance =
+            serde_json::from_str(r#"{ "mode": "relative", "max_relative": 1e-9 }"#).unwrap();
+        assert_eq!(
+            tolerance,
+            Tolerance::Relative {
+                epsilon: None,
+                max_relative: Some(1e-9),
+                policy: Policy::default(),
+            }
+        );
+
+        let tolerance: Tolerance = serde_json::from_str(
+            r#"{
+                "mode": "combined",
+                "epsilon": 1e-12,
+                "max_ulps": 8,
+                "combine": "all",
+                "policy": { "nan": "same_payload", "ulps": "monotonic" }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            tolerance,
+            Tolerance::Combined {
+                epsilon: Some(1e-12),
+                max_relative: None,
+                max_ulps: Some(8),
+                combine: Combine::All,
+                policy: Policy::new()
+                    .nan(NanPolicy::SamePayload)
+                    .ulps_mode(UlpsMode::Monotonic),
+            }
+        );
+    }
+
+    #[test]
+    fn test_manifest() {
+        let manifest: BTreeMap<std::string::String, Tolerance> = serde_json::from_str(
+            r#"{
+                "energy": { "mode": "relative", "max_relative": 1e-6 },
+                "positions": { "mode": "abs_diff", "epsilon": 1e-3 },
+                "steps": { "mode": "ulps" }
+            }"#,
+        )
+        .unwrap();
+        assert!(manifest["energy"].eq(&-1.0, &-1.0000005));
+        assert!(manifest["positions"].eq(&[1.0, 2.0][..], &[1.0005, 2.0][..]));
+        assert!(manifest["steps"].eq(&1.0, &next(1.0, 4)));
+        assert!(manifest["steps"].ne(&1.0, &next(1.0, 5)));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(serde_json::from_str::<Tolerance>(r#"{ "epsilon": 0.1 }"#).is_err());
+        assert!(serde_json::from_str::<Tolerance>(r#"{ "mode": "exact" }"#).is_err());
+        assert!(
+            serde_json::from_str::<Tolerance>(r#"{ "mode": "ulps", "max_ulps": -1 }"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        let misspelled = r#"{ "mode": "relative", "max_relatve": 1e-9 }"#;
+        let error = serde_json::from_str::<Tolerance>(misspelled).unwrap_err();
+        assert!(error.to_string().contains("max_relatve"), "{}", error);
+        let other_mode = r#"{ "mode": "abs_diff", "max_ulps": 4 }"#;
+        assert!(serde_json::from_str::<Tolerance>(other_mode).is_err());
+        let policy = r#"{ "mode": "ulps", "policy": { "nan_equal": true } }"#;
+        assert!(serde_json::from_str::<Tolerance>(policy).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let tolerance = Tolerance::Ulps {
+            epsilon: None,
+            max_ulps: Some(4),
+            policy: Policy::default(),
+        };
+        let json = serde_json::to_string(&tolerance).unwrap();
+        assert_eq!(
+            json,
+            r#"{"mode":"ulps","max_ulps":4,"policy":{"nan":"unequal","ulps":"sign_magnitude","distinct_zeros":false}}"#
+        );
+        assert_eq!(serde_json::from_str::<Tolerance>(&json).unwrap(), tolerance);
+    }
+}
@@ -15,6 +15,7 @@
 // Test cases derived from:
 // https://github.com/Pybonacci/puntoflotante.org/blob/master/content/errors/NearlyEqualsTest.java
 #![no_std]
+#![cfg_attr(approxim_nightly_float, feature(f16, f128))]
 
 #[macro_use]
 extern crate approxim;
@@ -359,6 +360,80 @@ mod test_f64 {
     }
 }
 
+mod test_int {
+    #[test]
+    fn test_basic() {
+        assert_ulps_eq!(3u32, 3u32);
+        assert_ulps_ne!(3u32, 4u32);
+        assert_ulps_eq!(3u32, 7u32, max_ulps = 4);
+        assert_ulps_ne!(3u32, 8u32, max_ulps = 4);
+        assert_ulps_eq!(3u32, 8u32, epsilon = 5, max_ulps = 0);
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_ulps_eq!(-1i32, 1i32, max_ulps = 2);
+        assert_ulps_ne!(-1i32, 1i32, max_ulps = 1);
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_ulps_ne!(i128::MIN, i128::MAX, max_ulps = u32::MAX);
+        assert_ulps_ne!(0u64, u64::MAX, max_ulps = u32::MAX);
+        assert_ulps_eq!(0u64, u32::MAX as u64, max_ulps = u32::MAX);
+        assert_ulps_eq!(i8::MIN, i8::MAX, max_ulps = 255);
+    }
+}
+
+mod test_mixed {
+    use approxim::Mixed;
+
+    #[test]
+    fn test_basic() {
+        assert_ulps_eq!(Mixed(0.1f32), 0.1f64);
+        assert_ulps_eq!(Mixed(0.1f64), 0.1f32);
+        assert_ulps_eq!(Mixed(0.1f32), 0.1f64, epsilon = 0.0, max_ulps = 0);
+        assert_ulps_ne!(Mixed(-1.0f32), 1.0f64);
+    }
+
+    #[test]
+    fn test_literal_inference() {
+        let x = 0.1f32;
+        assert_ulps_eq!(x, 0.1, epsilon = 0.0, max_ulps = 0);
+        assert_ulps_eq!(x, 0.1, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_lower_precision() {
+        let a = 1.0f32;
+        let b = f64::from(f32::from_bits(a.to_bits() + 3));
+        assert_ulps_eq!(Mixed(a), b, epsilon = 0.0, max_ulps = 3);
+        assert_ulps_ne!(Mixed(a), b, epsilon = 0.0, max_ulps = 2);
+        assert_ulps_ne!(Mixed(b), a, epsilon = 0.0, max_ulps = 2);
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_ulps_ne!(Mixed(f32::MAX), 1e300f64, epsilon = 0.0, max_ulps = u32::MAX);
+        assert_ulps_eq!(Mixed(f32::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_slice() {
+        assert_ulps_eq!([0.1f32, 0.2].map(Mixed)[..], [0.1f64, 0.2][..]);
+        assert_ulps_ne!([0.1f32, 0.2].map(Mixed)[..], [0.1f64, 0.3][..]);
+    }
+
+    #[cfg(feature = "vec_impl")]
+    #[test]
+    fn test_vec() {
+        extern crate alloc;
+        use alloc::vec::Vec;
+
+        assert_ulps_eq!(Vec::from([0.1f64, 0.2].map(Mixed)), Vec::from([0.1f32, 0.2]));
+    }
+}
+
 mod test_option {
     mod test_f32 {
         #[test]
@@ -488,6 +563,66 @@ mod test_vec {
     }
 }
 
+mod test_pin {
+    use core::pin::Pin;
+
+    #[test]
+    fn test_basic() {
+        assert_ulps_eq!(Pin::new(&1.0f64), Pin::new(&1.0f64));
+        assert_ulps_ne!(Pin::new(&1.0f64), Pin::new(&2.0f64));
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod test_pointer {
+    extern crate alloc;
+    use alloc::borrow::Cow;
+    use alloc::boxed::Box;
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+    use core::pin::Pin;
+
+    #[test]
+    fn test_basic() {
+        assert_ulps_eq!(Box::new(1.0f64), Box::new(1.0f64));
+        assert_ulps_ne!(Box::new(1.0f64), Box::new(2.0f64));
+        assert_ulps_eq!(Rc::new(1.0f32), Rc::new(1.0f32));
+        assert_ulps_ne!(Rc::new(1.0f32), Rc::new(2.0f32));
+        assert_ulps_eq!(Arc::new(Some(1.0f64)), Arc::new(Some(1.0f64)));
+        assert_ulps_ne!(Arc::new(Some(1.0f64)), Arc::new(None));
+        assert_ulps_eq!(Box::pin(1.0f64), Box::pin(1.0f64));
+        assert_ulps_ne!(Box::pin(1.0f64), Box::pin(2.0f64));
+    }
+
+    #[test]
+    fn test_slice() {
+        let a: Box<[f64]> = Box::new([1.0, 2.0]);
+        let b: Arc<[f64]> = Arc::from([1.0, 2.0]);
+        assert_ulps_eq!(a, Box::from([1.0, 2.0]));
+        assert_ulps_ne!(a, Box::from([1.0]));
+        assert_ulps_eq!(b, Arc::from([1.0, 2.0]));
+        assert_ulps_ne!(b, Arc::from([2.0, 1.0]));
+        assert_ulps_eq!(Rc::new(Box::new(1.0f64)), Rc::new(Box::new(1.0f64)));
+        assert_ulps_eq!(Pin::new(Box::new(1.0f64)), Pin::new(Box::new(1.0f64)));
+    }
+
+    #[test]
+    fn test_cow() {
+        let borrowed: Cow<'_, [f64]> = Cow::Borrowed(&[1.0, 2.0]);
+        let owned: Cow<'_, [f64]> = Cow::Owned([1.0, 2.0].into());
+        assert_ulps_eq!(borrowed, owned);
+        assert_ulps_ne!(borrowed, Cow::Borrowed(&[1.0, 3.0][..]));
+        assert_ulps_eq!(Cow::<f64>::Owned(1.0), Cow::Borrowed(&1.0));
+    }
+
+    #[test]
+    fn test_tolerance() {
+        let (a, b) = (Arc::new(1.0f64), Arc::new(1.0f64 + 8.0 * f64::EPSILON));
+        assert_ulps_eq!(a, b, max_ulps = 8);
+        assert_ulps_ne!(a, b);
+    }
+}
+
 #[cfg(feature = "tuple_impl")]
 mod test_tuple{
     use approxim::UlpsEq;
@@ -560,6 +695,107 @@ mod test_complex {
     }
 }
 
+#[cfg(approxim_nightly_float)]
+mod test_nightly_float {
+    mod test_f16 {
+        #[test]
+        fn test_basic() {
+            assert_ulps_eq!(1.0f16, 1.0f16);
+            assert_ulps_ne!(1.0f16, 2.0f16);
+            assert_ulps_eq!(1000.0f16, f16::from_bits(1000.0f16.to_bits() + 4));
+            assert_ulps_ne!(1000.0f16, f16::from_bits(1000.0f16.to_bits() + 5));
+            assert_ulps_ne!(1.0f16, -1.0f16);
+        }
+
+        #[test]
+        fn test_large_max_ulps() {
+            assert_ulps_eq!(1.0f16, f16::MAX, max_ulps = 1 << 16);
+        }
+    }
+
+    mod test_f128 {
+        #[test]
+        fn test_basic() {
+            let a = 1.0e30f128;
+            assert_ulps_eq!(a, a);
+            assert_ulps_ne!(1.0f128, 2.0f128);
+            assert_ulps_eq!(a, f128::from_bits(a.to_bits() + 4));
+            assert_ulps_ne!(a, f128::from_bits(a.to_bits() + 5));
+            assert_ulps_eq!(a, f128::from_bits(a.to_bits() + 5), max_ulps = 5);
+        }
+    }
+}
+
+#[cfg(feature = "half")]
+mod test_half {
+    extern crate half;
+    pub use self::half::{bf16, f16};
+
+    mod test_f16 {
+        use super::f16;
+
+        #[test]
+        fn test_basic() {
+            assert_ulps_eq!(f16::from_f32(1.0), f16::from_f32(1.0));
+            assert_ulps_ne!(f16::from_f32(1.0), f16::from_f32(2.0));
+        }
+
+        #[test]
+        fn test_max_ulps() {
+            let a = f16::from_f32(1000.0);
+            let b = f16::from_bits(a.to_bits() + 4);
+            let c = f16::from_bits(a.to_bits() + 5);
+            assert_ulps_eq!(a, b);
+            assert_ulps_ne!(a, c);
+            assert_ulps_eq!(a, c, max_ulps = 5);
+        }
+
+        #[test]
+        fn test_large_max_ulps() {
+            // Exceeds every distance between two `f16` of the same sign
+            assert_ulps_eq!(f16::from_f32(1.0), f16::MAX, max_ulps = 1 << 16);
+            assert_ulps_eq!(f16::ZERO, f16::MAX, max_ulps = u32::MAX);
+            assert_ulps_eq!(f16::from_f32(1.0), f16::MAX, max_ulps = (1 << 16) + 4);
+        }
+
+        #[test]
+        fn test_opposite_signs() {
+            assert_ulps_ne!(f16::from_f32(1.0), f16::from_f32(-1.0));
+            assert_ulps_eq!(f16::ZERO, f16::NEG_ZERO);
+        }
+
+        #[test]
+        fn test_nan() {
+            assert_ulps_ne!(f16::NAN, f16::NAN);
+            assert_ulps_ne!(f16::NAN, f16::ZERO);
+        }
+    }
+
+    mod test_bf16 {
+        use super::bf16;
+
+        #[test]
+        fn test_basic() {
+            assert_ulps_eq!(bf16::from_f32(1.0), bf16::from_f32(1.0));
+            assert_ulps_ne!(bf16::from_f32(1.0), bf16::from_f32(2.0));
+        }
+
+        #[test]
+        fn test_max_ulps() {
+            let a = bf16::from_f32(1.0e30);
+            let b = bf16::from_bits(a.to_bits() + 4);
+            let c = bf16::from_bits(a.to_bits() + 5);
+            assert_ulps_eq!(a, b);
+            assert_ulps_ne!(a, c);
+        }
+
+        #[test]
+        fn test_large_max_ulps() {
+            assert_ulps_eq!(bf16::from_f32(1.0), bf16::MAX, max_ulps = 1 << 16);
+        }
+    }
+}
+
 #[cfg(feature = "ordered-float")]
 mod test_ordered_float {
     extern crate ordered_float;
@@ -634,3 +870,82 @@ mod test_indexmap {
         );
     }
 }
+
+#[cfg(feature = "vec_deque_impl")]
+mod test_vec_deque {
+    extern crate alloc;
+    use alloc::collections::VecDeque;
+
+    #[test]
+    fn test_basic() {
+        let mut a = VecDeque::from([2.0f64, 3.0]);
+        a.push_front(1.0);
+        assert_ulps_eq!(a, VecDeque::from([1.0f64, 2.0, 3.0]));
+        assert_ulps_ne!(a, VecDeque::from([1.0f64, 2.0, 4.0]));
+        assert_ulps_ne!(a, VecDeque::from([1.0f64, 2.0]));
+    }
+}
+
+#[cfg(feature = "linked_list_impl")]
+mod test_linked_list {
+    extern crate alloc;
+    use alloc::collections::LinkedList;
+
+    #[test]
+    fn test_basic() {
+        assert_ulps_eq!(LinkedList::from([1.0f32, 2.0]), LinkedList::from([1.0f32, 2.0]));
+        assert_ulps_ne!(LinkedList::from([1.0f32, 2.0]), LinkedList::from([2.0f32, 1.0]));
+        assert_ulps_ne!(LinkedList::from([1.0f32, 2.0]), LinkedList::<f32>::new());
+    }
+}
+
+#[cfg(feature = "btree_map_impl")]
+mod test_btree_map {
+    extern crate alloc;
+    use alloc::collections::BTreeMap;
+
+    #[test]
+    fn test_basic() {
+        let a = BTreeMap::from([("energy", 1.0f64), ("time", 2.0)]);
+        assert_ulps_eq!(a, BTreeMap::from([("time", 2.0f64), ("energy", 1.0)]));
+        assert_ulps_ne!(a, BTreeMap::from([("energy", 1.0f64), ("time", 3.0)]));
+        assert_ulps_ne!(a, BTreeMap::from([("energy", 1.0f64), ("mass", 2.0)]));
+        assert_ulps_ne!(a, BTreeMap::from([("energy", 1.0f64)]));
+    }
+}
+
+#[cfg(feature = "hash_map_impl")]
+mod test_hash_map {
+    extern crate std;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_basic() {
+        let a = HashMap::from([(1, 1.0f64), (2, 2.0)]);
+        assert_ulps_eq!(a, HashMap::from([(2, 2.0f64), (1, 1.0)]));
+        let b = HashMap::from([(1, 1.0f64), (2, 2.0 + 16.0 * f64::EPSILON)]);
+        assert_ulps_eq!(a, b, max_ulps = 8);
+        assert_ulps_ne!(a, b);
+        assert_ulps_ne!(a, HashMap::from([(1, 1.0f64), (3, 2.0)]));
+    }
+}
+
+#[cfg(feature = "vec_impl")]
+mod test_cross {
+    extern crate alloc;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_vec_array() {
+        let computed = Vec::from([1.0f64, 2.0]);
+        assert_ulps_eq!(computed, [1.0, 2.0 + f64::EPSILON]);
+        assert_ulps_ne!(computed, [1.0, 2.0 + 1e-12]);
+    }
+
+    #[cfg(feature = "array_impl")]
+    #[test]
+    fn test_array_slice() {
+        assert_ulps_eq!([1.0f32, 2.0], [1.0f32, 2.0][..]);
+        assert_ulps_ne!([1.0f32][..], [1.0f32, 2.0]);
+    }
+}
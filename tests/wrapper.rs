@@ -0,0 +1,169 @@
+//! Tests for the `Approx` wrapper
+#![no_std]
+
+extern crate approxim;
+
+use approxim::{AbsDiff, Approx, Relative, UlpsMode};
+
+mod test_abs_diff {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Approx::abs_diff(1.0f64), 1.0);
+        assert_eq!(Approx::abs_diff(1.0f64), 1.0 + f64::EPSILON);
+        assert_ne!(Approx::abs_diff(1.0f64), 1.1);
+        assert_eq!(Approx::abs_diff(1.0f64).epsilon(0.2), 1.1);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_ne!(Approx::abs_diff(f32::NAN), f32::NAN);
+        assert_eq!(Approx::abs_diff(f32::NAN).nan_equal(true), f32::NAN);
+    }
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(Approx::abs_diff(10u32).epsilon(2), 12);
+        assert_ne!(Approx::abs_diff(10u32).epsilon(2), 13);
+    }
+}
+
+mod test_relative {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Approx::relative(0.1f64 + 0.2), 0.3);
+        assert_ne!(Approx::relative(1.0f64), 1.0 + 1e-9);
+        assert_eq!(Approx::relative(1.0f64).max_relative(1e-8), 1.0 + 1e-9);
+        assert_eq!(Approx::relative(1e-10f64).epsilon(1e-9), 0.0);
+    }
+
+    #[test]
+    fn test_nan() {
+        assert_eq!(Approx::relative(f64::NAN).nan_equal(true), f64::NAN);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_relative: 1e-9")]
+    fn test_assert_eq() {
+        assert_eq!(Approx::relative(1.0f64).max_relative(1e-9), 1.1);
+    }
+}
+
+mod test_ulps {
+    use super::*;
+
+    #[test]
+    fn test_eq() {
+        let a = f64::from_bits(1.0f64.to_bits() + 4);
+        assert_eq!(Approx::ulps(1.0f64), a);
+        assert_ne!(Approx::ulps(1.0f64).max_ulps(3), a);
+    }
+
+    #[test]
+    fn test_policy() {
+        let tiny = f32::from_bits(1);
+        assert_ne!(Approx::ulps(tiny).epsilon(0.0), -tiny);
+        assert_eq!(
+            Approx::ulps(tiny)
+                .epsilon(0.0)
+                .ulps_mode(UlpsMode::Monotonic),
+            -tiny
+        );
+        assert_ne!(Approx::ulps(0.0f64).distinct_zeros(true), -0.0);
+        assert_eq!(Approx::ulps(f64::NAN).nan_equal(true), f64::NAN);
+    }
+}
+
+mod test_partial_eq {
+    use super::*;
+
+    #[test]
+    fn test_wrappers() {
+        assert_eq!(Approx::relative(1.0f64), Approx::relative(1.0 + 1e-16));
+        assert_ne!(Approx::relative(1.0f64), Approx::relative(1.1));
+    }
+
+    #[test]
+    fn test_both_tolerances() {
+        let loose = Approx::abs_diff(1.0f64).epsilon(0.5);
+        let strict = Approx::abs_diff(1.25f64).epsilon(0.0);
+        assert_ne!(loose, strict);
+        assert_ne!(strict, loose);
+        let close = Approx::abs_diff(1.25f64).epsilon(0.25);
+        assert_eq!(loose, close);
+        assert_eq!(close, loose);
+    }
+
+    #[test]
+    fn test_symmetric_nan() {
+        let equal = Approx::ulps(f64::NAN).nan_equal(true);
+        let unequal = Approx::ulps(f64::NAN);
+        assert_eq!(equal, equal);
+        assert_ne!(equal, unequal);
+        assert_ne!(unequal, equal);
+    }
+
+    #[test]
+    fn test_contains() {
+        let values = [0.1f64, 0.2, 0.3].map(Approx::relative);
+        assert!(values.contains(&Approx::relative(0.1 + 0.2)));
+        assert!(!values.contains(&Approx::relative(0.4)));
+    }
+
+    #[test]
+    fn test_position() {
+        let values = [0.1f64, 0.2, 0.3];
+        let position = values
+            .iter()
+            .position(|&x| Approx::relative(0.1 + 0.2) == x);
+        assert_eq!(position, Some(2));
+    }
+
+    #[test]
+    fn test_new() {
+        let approx = Approx::new(1.0f64, Relative::default().max_relative(0.1));
+        assert_eq!(approx, 1.05);
+        assert_eq!(approx.into_inner(), 1.0);
+    }
+
+    #[test]
+    fn test_slice() {
+        let a = [1.0f64, 2.0];
+        let approx = Approx::new(&a[..], AbsDiff::default().epsilon(0.1));
+        assert_eq!(approx, &[1.05, 2.0][..]);
+        assert_ne!(approx, &[1.05][..]);
+    }
+}
+
+#[cfg(feature = "std")]
+mod test_debug {
+    extern crate std;
+
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn test_tolerance() {
+        assert_eq!(
+            format!("{:?}", Approx::abs_diff(1.0f64).epsilon(0.5)),
+            "Approx { value: 1.0, tolerance: AbsDiff { epsilon: 0.5, policy: Policy { \
+             nan: Unequal, ulps: SignMagnitude, distinct_zeros: false } } }"
+        );
+    }
+
+    #[test]
+    fn test_builders() {
+        assert_eq!(
+            format!("{:?}", approxim::Ulps::<f32>::default().max_ulps(2)),
+            "Ulps { epsilon: 1.1920929e-7, max_ulps: 2, policy: Policy { nan: Unequal, \
+             ulps: SignMagnitude, distinct_zeros: false } }"
+        );
+        assert_eq!(
+            format!("{:?}", approxim::MathIsClose::<f64>::default()),
+            "MathIsClose { rel_tol: 1e-9, abs_tol: 0.0 }"
+        );
+    }
+}